@@ -27,6 +27,24 @@ Composition_EquivalentCarbonNumber_Monospecific = Mono, ECN
 Composition_EquivalentCarbonNumber_Stereospecific = Stereo, ECN
     .abbreviation = SNC
     .hover = Equivalent carbon number stereospecific composition.
+Composition_CarbonNumber_Monospecific = Mono, CN
+    .abbreviation = MCC
+    .hover = Carbon number non-stereospecific composition (agregation), e.g. C52, C54.
+Composition_CarbonNumber_Stereospecific = Stereo, CN
+    .abbreviation = SCC
+    .hover = Carbon number stereospecific composition.
+Composition_CarbonAndDoubleBondNumber_Monospecific = Mono, CN:DB
+    .abbreviation = MDC
+    .hover = Carbon number and double bond number non-stereospecific composition (agregation), e.g. 54:3.
+Composition_CarbonAndDoubleBondNumber_Stereospecific = Stereo, CN:DB
+    .abbreviation = SDC
+    .hover = Carbon number and double bond number stereospecific composition.
+Composition_PartitionNumber_Monospecific = Mono, PN
+    .abbreviation = MPC
+    .hover = Partition number (CN - 2DB) non-stereospecific composition (agregation).
+Composition_PartitionNumber_Stereospecific = Stereo, PN
+    .abbreviation = SPC
+    .hover = Partition number (CN - 2DB) stereospecific composition.
 Composition_Species_Monospecific = Mono, species
     .abbreviation = MSC
     .hover = Species non-stereospecific composition (permutation).
@@ -57,6 +75,17 @@ DeltaDegreesOfFreedom = Delta Degrees of Freedom
     .hover = The divisor used in calculations is N - DDOF, where N represents the number of elements. By default DDOF is zero.
     .info = Different values of the argument ddof are useful in different contexts.
 Statistics = Statistics
+Dispersion = Dispersion
+    .hover = Statistic displayed next to the mean.
+Dispersion_StandardDeviation = Standard deviation
+    .abbreviation = SD
+    .hover = Sample standard deviation of the replicates (N - DDOF divisor).
+Dispersion_StandardError = Standard error of the mean
+    .abbreviation = SEM
+    .hover = Standard deviation divided by the square root of the number of replicates.
+Dispersion_ConfidenceInterval = Confidence interval 95%
+    .abbreviation = CI95
+    .hover = Half-width of the two-sided 95% confidence interval of the mean (Student's t, N - 1 degrees of freedom).
 
 Filter = Filter
     .hover = Filter rows by set operation.
@@ -84,6 +113,25 @@ Chaddock = Chaddock
 Moments = Moments
 Bias = Bias
     .hover = If False, then the calculations are corrected for statistical bias.
+Moments_Distribution = Distribution moments
+    .hover = Moments of the composition distribution: weighted mean, variance, skewness and kurtosis of the composition value (ECN, mass, ...) with the abundance as the weight. Calculated for every replicate and shown as the mean ± dispersion over the replicates.
+    .unavailable = Only for the non-stereospecific carbon number, equivalent carbon number, mass, partition number and unsaturation compositions.
+Moments_Values = Value moments
+    .hover = Moments of the abundance values themselves: every composition row is one observation of the mean value.
+Expected = Expected
+    .hover = Observed composition compared with the composition expected under the random distribution model of the joined fatty acids with the same name.
+Observed = Observed
+Ratio = Ratio
+    .hover = Observed to expected ratio.
+LogRatio = Log ratio
+    .hover = Natural logarithm of the observed to expected ratio.
+Discrepancy = Discrepancy
+    .hover = Descriptive discrepancy of the observed and expected fractions: Σ(O - E)² / E. The fractions are not counts, so it is not a chi-square statistic. Species not expected under the model are skipped.
+Hierarchy = Hierarchy
+    .hover = Composition levels drill-down, each node is the sum of its species.
+Hierarchy_Level = Level
+    .add = Add level
+    .hover = Composition of the hierarchy level.
 StandardDeviation = Standard deviation
 FattyAcid = Fatty acid
 Display = Display
@@ -101,6 +149,20 @@ SelectivityFactor = Selectivity factor
     .hover = Selectivity factor.
 NormalizeFactor = Normalize factor
     .hover = Normalize enrichment factor and selectivity factor.
+Prediction = Prediction
+    .hover = Predict triacylglycerol species composition from the fatty acid composition and add it to the triacylglycerols data.
+Decomposition = Decomposition
+    .hover = Calculate the fatty acid composition (SN-1,2,3, SN-1,3 and SN-2) implied by the triacylglycerol species on the mol basis and add it to the fatty acids data. The species are converted to moles by their molecular masses, every position is closed to one.
+Model = Model
+    .hover = Random distribution model of the fatty acids between the stereospecific positions.
+Model_OneTwoThreeRandom = 1,2,3-random
+    .hover = All positions are filled at random from the SN-1,2,3 fatty acid composition.
+Model_OneThreeRandomTwoRandom = 1,3-random-2-random
+    .hover = SN-1 and SN-3 are filled at random from the SN-1,3 composition, SN-2 from the SN-2 composition.
+Model_OneThreeCalculatedTwoRandom = 1,3-calculated-2-random
+    .hover = SN-2 is filled at random from the SN-2 composition, SN-1 and SN-3 from the SN-1,3 composition calculated as (3 × SN-1,2,3 - SN-2) / 2. It is not the 1-random-2-random-3-random model, which needs the SN-1 composition. The negative calculated values are clipped to zero, the clipped mass is shown under the model.
+Clipping = Clipping
+    .hover = Mass of the calculated SN-1,3 composition clipped to zero, percent, the largest of the replicates. A large mass means that the SN-2 composition is inconsistent with the SN-1,2,3 composition.
 Sample = Sample
     .hover = Sample.
 Significant = Significant
@@ -121,6 +183,7 @@ Threshold_Sort = Sort
 
 # Metrics
 
+AitchisonDistance = Aitchison distance
 BhattacharyyaDistance = Bhattacharyya distance
 ChebyshevDistance = Chebyshev distance
 CosineDistance = Cosine distance
@@ -132,3 +195,220 @@ ManhattanDistance = Manhattan distance
 OverlapDistance = Overlap distance
 PearsonCorrelation = Pearson correlation
 SpearmanRankCorrelation = Spearman rank correlation
+Diversity = Diversity
+    .hover = Diversity indices of the composition, calculated for every replicate.
+Diversity_Shannon = Shannon entropy
+    .hover = H = -Σ p ln p. Zero for a single species, grows with the number and evenness of the species.
+Diversity_Simpson = Simpson index
+    .hover = 1 - Σ p², the probability that two random molecules belong to different species.
+Diversity_Pielou = Pielou evenness
+    .hover = J = H / ln S, one for the evenly distributed species, close to zero for a dominant species. Undefined for a single species.
+Undefined = Undefined
+    .hover = The index is undefined for the sample, e.g. the evenness of a single species.
+Diversity_Hill0 = Hill number (q = 0)
+    .hover = Species richness, the number of the species present.
+Diversity_Hill1 = Hill number (q = 1)
+    .hover = exp(H), the effective number of the species.
+Diversity_Hill2 = Hill number (q = 2)
+    .hover = 1 / Σ p², the effective number of the dominant species.
+Comparison = Comparison
+    .hover = Comparison of two samples: log2 fold change, Welch's t-test p-value and presence of every species.
+    .scroll = Scroll the table to the row.
+FoldChange = log₂ fold change
+    .hover = Binary logarithm of the ratio of the second sample mean to the first one. Undefined for the species present in only one sample.
+PValue = p-value
+    .hover = Two-sided p-value of the Welch's t-test on the replicates.
+    .logarithm = -log₁₀ p-value
+Presence = Presence in the sample.
+Volcano_Significant = Significant
+    .hover = p-value below 0.05 and fold change above two.
+Volcano_Insignificant = Insignificant
+Membership = Membership
+    .hover = Number of the species present above the threshold in every combination of the samples, and only in it.
+    .limit = Too many samples, the set membership is available for the number of samples up to
+    .filter = Filter the table to the intersection.
+    .reset = Reset the table filter.
+Count = Count
+Reference = Reference
+    .hover = Reference sample, every other sample is expressed relative to it.
+    .select = Right-click a sample column heading to make it the reference.
+    .set = Set as reference
+    .reset = Reset the reference
+Replicates = n = { $n }
+Relation = Relation
+    .hover = Relation of the sample to the reference sample. The dispersion is propagated from the both samples.
+Relation_Difference = Difference
+    .hover = x - r, the dispersion is √(s² + sᵣ²).
+Relation_Ratio = Ratio
+    .hover = x / r, the relative dispersion is √((s / x)² + (sᵣ / r)²).
+Relation_LogRatio = Log-ratio
+    .hover = ln(x / r), the dispersion is √((s / x)² + (sᵣ / r)²).
+Groups = Groups
+    .hover = Sample groups: the group mean and the between-sample dispersion of the member sample means.
+Groups_Rule = Rule
+    .hover = Metadata key, its value is the group name of the sample.
+    .apply = Assign the groups by the rule.
+Groups_Clear = Clear the groups.
+Groups_Sample = Sample
+Groups_Group = Group
+    .hover = Group name, the empty name leaves the sample ungrouped.
+View = View
+    .hover = Show the samples, the groups or both.
+View_Samples = Samples
+    .hover = Individual samples only.
+View_Groups = Groups
+    .hover = Groups instead of the grouped samples, the ungrouped samples are kept.
+View_Both = Both
+    .hover = Groups next to the individual samples.
+Significance = Significance
+    .hover = Difference of the groups: PERMANOVA and ANOSIM on the replicate-level distance matrix of the selected metric. The ungrouped sample is its own group.
+    .unavailable = At least two groups are required.
+    .p_value = Permutation p-value.
+Statistic = Statistic
+    .hover = PERMANOVA pseudo-F or ANOSIM R.
+RSquared = R²
+    .hover = Share of the total sum of the squared distances explained by the groups.
+Permutations = Permutations
+    .hover = Number of the permutations of the permutation tests.
+Permutations_Seed = Seed
+    .hover = Seed of the permutations, the same seed reproduces the same p-value.
+Ordination = Ordination
+    .hover = Replicates in two dimensions from the replicate-level distance matrix of the selected metric, colored by the group.
+    .unavailable = At least three replicates with nonzero distances are required.
+    .axis = Axis
+Ordination_PrincipalCoordinates = PCoA
+    .hover = Principal coordinates analysis: the axes keep the distances as closely as possible, the share of the variance explained by every axis is shown.
+Ordination_NonMetricMultidimensionalScaling = NMDS
+    .hover = Non-metric multidimensional scaling: the axes keep the rank order of the distances.
+Stress = Stress
+    .hover = Kruskal's stress-1: below 0.05 is excellent, below 0.1 is good, above 0.2 is poor.
+Clusters = Clusters
+    .hover = Partition of the samples into the clusters with the silhouette width of every cluster count.
+    .unavailable = At least three distinct samples are required.
+Clusters_Count = Clusters
+    .hover = Number of the clusters.
+Clusters_Table = Table
+    .hover = Color the sample columns of the table by the cluster.
+Clustering_KMeans = k-means
+    .hover = k-means on the mean composition of the samples.
+Clustering_KMedoids = k-medoids
+    .hover = k-medoids (PAM) on the distance matrix of the selected metric.
+Cluster = Cluster
+Silhouette = Silhouette
+    .hover = Silhouette width: how much closer the sample is to its own cluster than to the nearest other one, from -1 to 1. The count with the largest mean width fits best.
+Transform = Transform
+    .hover = Compositional transform of the sample profiles before the metrics, the significance, the ordination and the clusters. The Aitchison distance always uses CLR, the metrics other than the Euclidean, Chebyshev and Manhattan distances use the closure instead of the log-ratios.
+    .unavailable = Only for the Euclidean, Chebyshev, Manhattan and Aitchison distances: the other metrics are undefined for the negative log-ratios.
+Transform_None = None
+    .hover = Raw means.
+Transform_Closure = Closure
+    .hover = x / Σx, the parts sum to one.
+Transform_CenteredLogRatio = CLR
+    .hover = Centered log-ratio: ln(x / g(x)), g(x) is the geometric mean of the parts.
+Transform_IsometricLogRatio = ILR
+    .hover = Isometric log-ratio: the orthonormal pivot balances of the parts in the table order.
+Transform_AdditiveLogRatio = ALR
+    .hover = Additive log-ratio: ln(x / xᵣ) against the reference part.
+Transform_Reference = Reference part
+    .hover = Denominator part of the additive log-ratio, the last row by default.
+    .select = Right-click a row label to make it the reference part.
+    .set = Set as log-ratio reference
+    .reset = Reset the log-ratio reference
+DetectionLimit = Detection limit
+    .hover = Multiplicative replacement of the zeros before the log-ratio transforms: every zero becomes 65% of the detection limit, the other parts are shrunk to keep the sum.
+Detection = Detection
+    .hover = Limits of detection and quantification of the fatty acid samples. The triacylglycerols are filtered by the threshold only.
+Detection_Sample = Sample
+LimitOfDetection = Limit of detection
+    .hover = Limit of detection (LOD): the species with the mean below it is not detected, its replicate values below it are substituted.
+LimitOfQuantification = Limit of quantification
+    .hover = Limit of quantification (LOQ): the species with the mean below it is detected, but not quantified.
+Limit = Limit
+    .detection = Below the limit of detection, the substituted mean:
+    .quantification = Below the limit of quantification, the mean:
+Substitution = Substitution
+    .hover = Substitution of the replicate values below the limit of detection.
+Substitution_Zero = 0
+    .hover = Zero.
+Substitution_HalfLimit = LOD/2
+    .hover = Half of the limit of detection.
+Substitution_RootTwoLimit = LOD/√2
+    .hover = Limit of detection divided by the square root of two.
+Substitution_Exclusion = Exclusion
+    .hover = The values are excluded from the statistics.
+Bootstrap = Bootstrap
+    .hover = 95% bootstrap intervals of the indices, the factors and the metrics: the replicates are resampled with replacement within every sample.
+Bootstrap_Interval = Interval
+    .hover = Interval from the resampled estimates.
+Bootstrap_Resamples = Resamples
+    .hover = Number of the bootstrap resamples.
+Bootstrap_Seed = Seed
+    .hover = Seed of the resamples, the same seed reproduces the same interval.
+Interval_Percentile = Percentile
+    .hover = 2.5% and 97.5% quantiles of the resampled estimates.
+Interval_BiasCorrectedAccelerated = BCa
+    .hover = Bias-corrected and accelerated: the percentiles are adjusted for the median bias of the resampled estimates and for the skewness of the jackknife estimates.
+Properties = Properties
+    .hover = Chemical properties of the oil calculated from the fatty acid composition for every replicate.
+Standard_En14214 = EN 14214
+    .hover = European standard of the fatty acid methyl esters for diesel engines.
+Standard_AstmD6751 = ASTM D6751
+    .hover = American standard of the biodiesel fuel blend stock (B100).
+Nutrition = Nutrition
+    .hover = Fatty acids per 100 grams of the food and the percent of the reference intake.
+Nutrition_Fat = Total fat
+    .hover = Total fat, grams per 100 grams of the food.
+Nutrition_Conversion = Conversion factor
+    .hover = Lipid conversion factor, grams of the fatty acids per gram of the fat (0.956 for the vegetable oils, Weihrauch et al., 1977).
+Nutrition_Basis = Basis
+    .hover = Basis of the composition, converted to the mass fractions of the fatty acids.
+Basis_MoleFractions = Mole fractions
+    .hover = Mole fractions of the fatty acids, weighted by the Mᵢ(FA) masses.
+Basis_FattyAcidMassFractions = Fatty acid mass fractions
+    .hover = Mass fractions of the fatty acids, taken as they are.
+Basis_MethylEsterMassFractions = FAME mass fractions
+    .hover = Mass fractions of the fatty acid methyl esters, converted to the fatty acids by the Mᵢ(FA) / Mᵢ(FAME) factors.
+ReferenceIntake = Reference intake
+    .hover = Percent of the reference intake of the adult (Regulation (EU) No 1169/2011): 70 g of the fat, 20 g of the saturates.
+Fat = Fat
+    .hover = Total fat, grams per 100 grams of the food.
+FattyAcids = Fatty acids
+    .hover = Total fatty acids, grams per 100 grams of the food.
+Melting = Melting
+    .hover = Physical behaviour of the fat estimated from the triacylglycerols: the positional type sums, the melting range and the solid fat content, calculated for every replicate.
+SSS = SSS
+    .hover = Trisaturated triacylglycerols, percent.
+SSU = SSU
+    .hover = Disaturated triacylglycerols with the unsaturated fatty acid in sn-1 or sn-3, percent.
+SUS = SUS
+    .hover = Disaturated triacylglycerols with the unsaturated fatty acid in sn-2, percent.
+SUU = SUU
+    .hover = Monosaturated triacylglycerols with the saturated fatty acid in sn-1 or sn-3, percent.
+USU = USU
+    .hover = Monosaturated triacylglycerols with the saturated fatty acid in sn-2, percent.
+UUU = UUU
+    .hover = Triunsaturated triacylglycerols, percent.
+MeltingRangeStart = Melting range start
+    .hover = Temperature below which 5% of the fat is melted, °C. The melting points of the positional types (PPP 66, PPO 35, POP 37, POO 19, OPO 21, OOO 5 °C) are lowered by 5 °C per double bond above one per unsaturated fatty acid.
+MeltingRangeEnd = Melting range end
+    .hover = Temperature below which 95% of the fat is melted, °C.
+SolidFatContent10 = SFC 10 °C
+    .hover = Solid fat content at 10 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent15 = SFC 15 °C
+    .hover = Solid fat content at 15 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent20 = SFC 20 °C
+    .hover = Solid fat content at 20 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent25 = SFC 25 °C
+    .hover = Solid fat content at 25 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent30 = SFC 30 °C
+    .hover = Solid fat content at 30 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent35 = SFC 35 °C
+    .hover = Solid fat content at 35 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+SolidFatContent40 = SFC 40 °C
+    .hover = Solid fat content at 40 °C of the additive model, percent. Every triacylglycerol melts linearly over 5 °C below its melting point.
+Blend = Blend
+    .hover = Mix the selected samples with the weight fractions and add the virtual sample of the blend to the data. The compositions of the samples are normalized and averaged over their own replicates before mixing, the weight fractions are converted to the mole fractions by the mean molar masses of the triacylglycerols of the samples. The blend is a single replicate.
+Mixing_Simple = Simple mixing
+    .hover = The triacylglycerol species of the samples are kept, the blend is the weighted sum of the species.
+Mixing_Interesterification = Chemical interesterification
+    .hover = The fatty acids of the blend are randomly redistributed over all positions (1,2,3-random).
@@ -19,9 +19,13 @@ Unsaturated = Unsaturated
     .abbreviation = UFA
     .hover = Sum of unsaturated fatty acids.
 Unsaturated-9 = Omega -9
+    .hover = Sum of omega -9 fatty acids.
 Unsaturated-6 = Omega -6
+    .hover = Sum of omega -6 fatty acids.
 Unsaturated-3 = Omega -3
+    .hover = Sum of omega -3 fatty acids.
 Unsaturated9 = Delta 9
+    .hover = Sum of fatty acids with the delta 9 double bond.
 EicosapentaenoicAndDocosahexaenoic = Eicosapentaenoic and Docosahexaenoic
     .hover = Sum of Eicosapentaenoic and Docosahexaenoic.
 FishLipidQuality = Fish Lipid Quality
@@ -51,34 +55,96 @@ PolyunsaturatedToSaturated = Polyunsaturated to Saturated
 UnsaturationIndex = Unsaturation Index
     .abbreviation = UI
     .hover = Unsaturation Index.
+StearoylCoenzymeADesaturase16 = Stearoyl-CoA desaturase 16
+    .abbreviation = SCD-16
+    .hover = Δ9 desaturase activity on palmitic acid: 16:1 / 16:0.
+StearoylCoenzymeADesaturase18 = Stearoyl-CoA desaturase 18
+    .abbreviation = SCD-18
+    .hover = Δ9 desaturase activity on stearic acid: 18:1 / 18:0.
+Delta12Desaturase = Δ12 desaturase
+    .abbreviation = FAD2
+    .hover = Δ12 desaturase activity: 18:2 n-6 / 18:1 n-9.
+Delta15Desaturase = Δ15 desaturase
+    .abbreviation = FAD3
+    .hover = Δ15 desaturase activity: 18:3 n-3 / 18:2 n-6.
+Delta6Desaturase = Δ6 desaturase
+    .abbreviation = D6D
+    .hover = Δ6 desaturase activity: 18:3 n-6 / 18:2 n-6.
+Delta5Desaturase = Δ5 desaturase
+    .abbreviation = D5D
+    .hover = Δ5 desaturase activity: 20:4 n-6 / 20:3 n-6.
+Elongase = Elongase
+    .abbreviation = ELOVL6
+    .hover = Elongase activity: 18:0 / 16:0.
+AverageChainLength = Average chain length
+    .abbreviation = ACL
+    .hover = Mean carbon number of the fatty acids: Σ(Cᵢ·xᵢ) / Σxᵢ.
+DoubleBondIndex = Double bond index
+    .abbreviation = DBI
+    .hover = Mean number of the double bonds of the fatty acids: Σ(DBᵢ·xᵢ) / Σxᵢ.
+PeroxidizabilityIndex = Peroxidizability index
+    .abbreviation = PI
+    .hover = Susceptibility to peroxidation (Witting, 1965): 0.025·monoenoic + 1·dienoic + 2·trienoic + 4·tetraenoic + 6·pentaenoic + 8·hexaenoic.
 
+## Chemical properties
+
+MeanMolecularWeight = Mean molecular weight
+    .abbreviation = MW
+    .hover = Mean relative molecular mass of the fatty acids: Σ(Mᵢ·xᵢ) / Σxᵢ.
+SaponificationValue = Saponification value
+    .abbreviation = SV
+    .hover = Milligrams of KOH per gram of the oil: 3·56.106·1000 / (3·M̄ + 38.049).
 IodineValue = Iodine value
     .abbreviation = IV
-    .hover = Iodine value
+    .hover = Grams of I₂ per 100 grams of the oil: 3·253.809·100·Σ(DBᵢ·xᵢ) / Σxᵢ / (3·M̄ + 38.049).
     .doi = { $Source ->
         *[Aocs] [AOCS recommended practice Cd 1c-85](https://library.aocs.org/Cd-1c-85/1)
         [Wang2012] [Wang et al. (2012)](https://doi.org/10.1016/S1872-5813(12)60018-8)
     }
+CalculatedOxidizability = Calculated oxidizability
+    .abbreviation = Cox
+    .hover = Fatemi and Hammond (1980): (18:1 + 10.3·18:2 + 21.6·18:3) / 100.
+OxidativeStabilityIndex = Oxidative stability index
+    .abbreviation = OSI
+    .hover = Theoretical induction period in hours (Park et al., 2008): 117.9295 / X + 2.5905, X is the mass percent of 18:2 and 18:3.
 
 ## Biodiesel properties
 
 BiodieselProperties = Biodiesel properties
-    .hover = Biodiesel properties.
+    .hover = Properties of the fatty acid methyl esters predicted from the composition, checked against the EN 14214 and ASTM D6751 limits.
 CetaneNumber = Cetane number
     .abbreviation = CN
-    .hover = Cetane number
+    .hover = Krisnangkura (1986): 46.3 + 5458 / SV - 0.225·IV.
+CloudPoint = Cloud point
+    .abbreviation = CP
+    .hover = °C, Sarin et al. (2009): 0.526·C16:0 - 4.992.
 ColdFilterPluggingPoint = Cold filter plugging point
     .abbreviation = CFPP
-    .hover = Cold filter plugging point.
+    .hover = °C, Ramos et al. (2009): 3.1417·LCSF - 16.477.
+Density = Density
+    .abbreviation = ρ
+    .hover = g/cm³ at 15 °C, Ramírez-Verduzco et al. (2012) at 20 °C: Σwᵢ(0.8463 + 4.9 / Mᵢ + 0.0118·Nᵢ), + 0.00072 g/cm³ per °C (Tat and Van Gerpen, 2000).
 DegreeOfUnsaturation = Degree of unsaturation
     .abbreviation = DU
-    .hover = Degree of unsaturation.
+    .hover = Ramos et al. (2009): MUFA + 2·PUFA, mass percents.
+HigherHeatingValue = Higher heating value
+    .abbreviation = HHV
+    .hover = MJ/kg, Ramírez-Verduzco et al. (2012): Σwᵢ(46.19 - 1794 / Mᵢ - 0.21·Nᵢ).
 IodineValue = Iodine value
     .abbreviation = IV
     .hover = Iodine value
+KinematicViscosity = Kinematic viscosity
+    .abbreviation = ν
+    .hover = mm²/s at 40 °C, Ramírez-Verduzco et al. (2012): ln ν = Σwᵢ(-12.503 + 2.496·ln Mᵢ - 0.178·Nᵢ).
+LinolenicAcidMethylEster = Linolenic acid methyl ester
+    .abbreviation = C18:3
+    .hover = Mass percent of the 18:3 methyl esters.
 LongChainSaturatedFactor = Long Chain Saturated factor
     .abbreviation = LCSF
-    .hover = Long Chain Saturated factor.
+    .hover = Ramos et al. (2009): 0.1·C16:0 + 0.5·C18:0 + 1·C20:0 + 1.5·C22:0 + 2·C24:0, mass percents.
+PolyunsaturatedMethylEsters = Polyunsaturated methyl esters
+    .abbreviation = PUFAME
+    .hover = Mass percent of the methyl esters with at least four double bonds.
 OxidationStability = Oxidation stability
     .abbreviation = OS
     .hover = Oxidation stability
@@ -0,0 +1,280 @@
+Composition_CarbonNumber_Monospecific = Моно, CN
+    .abbreviation = MCC
+    .hover = Нестереоспецифичный состав по числу атомов углерода (агрегация), например C52, C54.
+Composition_CarbonNumber_Stereospecific = Стерео, CN
+    .abbreviation = SCC
+    .hover = Стереоспецифичный состав по числу атомов углерода.
+Composition_CarbonAndDoubleBondNumber_Monospecific = Моно, CN:DB
+    .abbreviation = MDC
+    .hover = Нестереоспецифичный состав по числу атомов углерода и двойных связей (агрегация), например 54:3.
+Composition_CarbonAndDoubleBondNumber_Stereospecific = Стерео, CN:DB
+    .abbreviation = SDC
+    .hover = Стереоспецифичный состав по числу атомов углерода и двойных связей.
+Composition_PartitionNumber_Monospecific = Моно, PN
+    .abbreviation = MPC
+    .hover = Нестереоспецифичный состав по числу разделения (CN - 2DB) (агрегация).
+Composition_PartitionNumber_Stereospecific = Стерео, PN
+    .abbreviation = SPC
+    .hover = Стереоспецифичный состав по числу разделения (CN - 2DB).
+Dispersion = Разброс
+    .hover = Статистика, показываемая рядом со средним.
+Dispersion_StandardDeviation = Стандартное отклонение
+    .abbreviation = SD
+    .hover = Выборочное стандартное отклонение повторностей (делитель N - DDOF).
+Dispersion_StandardError = Стандартная ошибка среднего
+    .abbreviation = SEM
+    .hover = Стандартное отклонение, деленное на квадратный корень из числа повторностей.
+Dispersion_ConfidenceInterval = Доверительный интервал 95%
+    .abbreviation = CI95
+    .hover = Полуширина двустороннего 95% доверительного интервала среднего (t Стьюдента, N - 1 степеней свободы).
+Moments_Distribution = Моменты распределения
+    .hover = Моменты распределения состава: взвешенные среднее, дисперсия, асимметрия и эксцесс значения состава (ECN, масса, ...) с содержанием в качестве веса. Вычисляются для каждой повторности и показываются как среднее ± разброс по повторностям.
+    .unavailable = Только для нестереоспецифичных составов по числу атомов углерода, эквивалентному числу атомов углерода, массе, числу разделения и ненасыщенности.
+Moments_Values = Моменты значений
+    .hover = Моменты самих значений содержания: каждая строка состава является одним наблюдением среднего значения.
+Expected = Ожидаемое
+    .hover = Наблюдаемый состав в сравнении с составом, ожидаемым по модели случайного распределения присоединенных жирных кислот с тем же названием.
+Observed = Наблюдаемое
+Ratio = Отношение
+    .hover = Отношение наблюдаемого к ожидаемому.
+LogRatio = Логарифм отношения
+    .hover = Натуральный логарифм отношения наблюдаемого к ожидаемому.
+Discrepancy = Расхождение
+    .hover = Описательное расхождение наблюдаемых и ожидаемых долей: Σ(O - E)² / E. Доли не являются счетами, поэтому это не статистика хи-квадрат. Виды, не ожидаемые по модели, пропускаются.
+Hierarchy = Иерархия
+    .hover = Детализация по уровням состава, каждый узел является суммой своих видов.
+Hierarchy_Level = Уровень
+    .add = Добавить уровень
+    .hover = Состав уровня иерархии.
+Prediction = Предсказание
+    .hover = Предсказать видовой состав триацилглицеринов по составу жирных кислот и добавить его к данным триацилглицеринов.
+Decomposition = Разложение
+    .hover = Вычислить состав жирных кислот (SN-1,2,3, SN-1,3 и SN-2), соответствующий видам триацилглицеринов, в мольной основе и добавить его к данным жирных кислот. Виды переводятся в моли по их молекулярным массам, каждое положение нормируется к единице.
+Model = Модель
+    .hover = Модель случайного распределения жирных кислот между стереоспецифичными положениями.
+Model_OneTwoThreeRandom = 1,2,3-случайное
+    .hover = Все положения заполняются случайно из состава жирных кислот SN-1,2,3.
+Model_OneThreeRandomTwoRandom = 1,3-случайное-2-случайное
+    .hover = SN-1 и SN-3 заполняются случайно из состава SN-1,3, SN-2 из состава SN-2.
+Model_OneThreeCalculatedTwoRandom = 1,3-вычисленное-2-случайное
+    .hover = SN-2 заполняется случайно из состава SN-2, SN-1 и SN-3 из состава SN-1,3, вычисленного как (3 × SN-1,2,3 - SN-2) / 2. Это не модель 1-случайное-2-случайное-3-случайное, для которой нужен состав SN-1. Отрицательные вычисленные значения обрезаются до нуля, обрезанная масса показывается под моделью.
+Clipping = Обрезка
+    .hover = Масса вычисленного состава SN-1,3, обрезанная до нуля, в процентах, наибольшая из повторностей. Большая масса означает, что состав SN-2 не согласуется с составом SN-1,2,3.
+AitchisonDistance = Расстояние Эйчисона
+Diversity = Разнообразие
+    .hover = Индексы разнообразия состава, вычисленные для каждой повторности.
+Diversity_Shannon = Энтропия Шеннона
+    .hover = H = -Σ p ln p. Ноль для одного вида, растет с числом и выравненностью видов.
+Diversity_Simpson = Индекс Симпсона
+    .hover = 1 - Σ p², вероятность того, что две случайные молекулы принадлежат разным видам.
+Diversity_Pielou = Выравненность Пиелу
+    .hover = J = H / ln S, единица для равномерно распределенных видов, близка к нулю для доминирующего вида. Не определена для одного вида.
+Undefined = Не определено
+    .hover = Индекс не определен для образца, например выравненность одного вида.
+Diversity_Hill0 = Число Хилла (q = 0)
+    .hover = Видовое богатство, число присутствующих видов.
+Diversity_Hill1 = Число Хилла (q = 1)
+    .hover = exp(H), эффективное число видов.
+Diversity_Hill2 = Число Хилла (q = 2)
+    .hover = 1 / Σ p², эффективное число доминирующих видов.
+Comparison = Сравнение
+    .hover = Сравнение двух образцов: log2 кратности изменения, p-значение t-критерия Уэлча и присутствие каждого вида.
+    .scroll = Прокрутить таблицу к строке.
+FoldChange = log₂ кратности изменения
+    .hover = Двоичный логарифм отношения среднего второго образца к первому. Не определен для видов, присутствующих только в одном образце.
+PValue = p-значение
+    .hover = Двустороннее p-значение t-критерия Уэлча по повторностям.
+    .logarithm = -log₁₀ p-значения
+Presence = Присутствие в образце.
+Volcano_Significant = Значимое
+    .hover = p-значение ниже 0.05 и кратность изменения больше двух.
+Volcano_Insignificant = Незначимое
+Membership = Принадлежность
+    .hover = Число видов, присутствующих выше порога в каждой комбинации образцов и только в ней.
+    .limit = Слишком много образцов, принадлежность доступна для числа образцов до
+    .filter = Отфильтровать таблицу по пересечению.
+    .reset = Сбросить фильтр таблицы.
+Count = Количество
+Reference = Опорный
+    .hover = Опорный образец, каждый другой образец выражается относительно него.
+    .select = Щелкните правой кнопкой по заголовку столбца образца, чтобы сделать его опорным.
+    .set = Сделать опорным
+    .reset = Сбросить опорный образец
+Replicates = n = { $n }
+Relation = Соотношение
+    .hover = Соотношение образца с опорным образцом. Разброс распространяется от обоих образцов.
+Relation_Difference = Разность
+    .hover = x - r, разброс равен √(s² + sᵣ²).
+Relation_Ratio = Отношение
+    .hover = x / r, относительный разброс равен √((s / x)² + (sᵣ / r)²).
+Relation_LogRatio = Логарифм отношения
+    .hover = ln(x / r), разброс равен √((s / x)² + (sᵣ / r)²).
+Groups = Группы
+    .hover = Группы образцов: среднее группы и межобразцовый разброс средних образцов-членов.
+Groups_Rule = Правило
+    .hover = Ключ метаданных, его значение является названием группы образца.
+    .apply = Назначить группы по правилу.
+Groups_Clear = Очистить группы.
+Groups_Sample = Образец
+Groups_Group = Группа
+    .hover = Название группы, пустое название оставляет образец без группы.
+View = Вид
+    .hover = Показать образцы, группы или и то, и другое.
+View_Samples = Образцы
+    .hover = Только отдельные образцы.
+View_Groups = Группы
+    .hover = Группы вместо сгруппированных образцов, образцы без группы сохраняются.
+View_Both = Оба
+    .hover = Группы рядом с отдельными образцами.
+Significance = Значимость
+    .hover = Различие групп: PERMANOVA и ANOSIM по матрице расстояний выбранной метрики на уровне повторностей. Образец без группы является своей собственной группой.
+    .unavailable = Требуется не менее двух групп.
+    .p_value = Перестановочное p-значение.
+Statistic = Статистика
+    .hover = Псевдо-F PERMANOVA или R ANOSIM.
+RSquared = R²
+    .hover = Доля общей суммы квадратов расстояний, объясненная группами.
+Permutations = Перестановки
+    .hover = Число перестановок перестановочных тестов.
+Permutations_Seed = Зерно
+    .hover = Зерно перестановок, то же зерно воспроизводит то же p-значение.
+Ordination = Ординация
+    .hover = Повторности в двух измерениях по матрице расстояний выбранной метрики на уровне повторностей, окрашенные по группе.
+    .unavailable = Требуется не менее трех повторностей с ненулевыми расстояниями.
+    .axis = Ось
+Ordination_PrincipalCoordinates = PCoA
+    .hover = Анализ главных координат: оси сохраняют расстояния как можно точнее, показывается доля дисперсии, объясненная каждой осью.
+Ordination_NonMetricMultidimensionalScaling = NMDS
+    .hover = Неметрическое многомерное шкалирование: оси сохраняют ранговый порядок расстояний.
+Stress = Стресс
+    .hover = Стресс-1 Краскела: ниже 0.05 отлично, ниже 0.1 хорошо, выше 0.2 плохо.
+Clusters = Кластеры
+    .hover = Разбиение образцов на кластеры с шириной силуэта для каждого числа кластеров.
+    .unavailable = Требуется не менее трех различных образцов.
+Clusters_Count = Кластеры
+    .hover = Число кластеров.
+Clusters_Table = Таблица
+    .hover = Окрасить столбцы образцов таблицы по кластеру.
+Clustering_KMeans = k-средних
+    .hover = k-средних по среднему составу образцов.
+Clustering_KMedoids = k-медоид
+    .hover = k-медоид (PAM) по матрице расстояний выбранной метрики.
+Cluster = Кластер
+Silhouette = Силуэт
+    .hover = Ширина силуэта: насколько образец ближе к своему кластеру, чем к ближайшему другому, от -1 до 1. Число кластеров с наибольшей средней шириной подходит лучше всего.
+Transform = Преобразование
+    .hover = Композиционное преобразование профилей образцов перед метриками, значимостью, ординацией и кластерами. Расстояние Эйчисона всегда использует CLR, метрики, кроме евклидова расстояния, расстояний Чебышева и Манхэттена, используют замыкание вместо логарифмов отношений.
+    .unavailable = Только для евклидова расстояния, расстояний Чебышева, Манхэттена и Эйчисона: другие метрики не определены для отрицательных логарифмов отношений.
+Transform_None = Нет
+    .hover = Исходные средние.
+Transform_Closure = Замыкание
+    .hover = x / Σx, сумма частей равна единице.
+Transform_CenteredLogRatio = CLR
+    .hover = Центрированный логарифм отношения: ln(x / g(x)), g(x) является средним геометрическим частей.
+Transform_IsometricLogRatio = ILR
+    .hover = Изометрический логарифм отношения: ортонормированные опорные балансы частей в порядке таблицы.
+Transform_AdditiveLogRatio = ALR
+    .hover = Аддитивный логарифм отношения: ln(x / xᵣ) относительно опорной части.
+Transform_Reference = Опорная часть
+    .hover = Часть-знаменатель аддитивного логарифма отношения, по умолчанию последняя строка.
+    .select = Щелкните правой кнопкой по названию строки, чтобы сделать ее опорной частью.
+    .set = Сделать опорной частью логарифма отношения
+    .reset = Сбросить опорную часть логарифма отношения
+DetectionLimit = Предел обнаружения
+    .hover = Мультипликативная замена нулей перед преобразованиями логарифмов отношений: каждый ноль становится 65% предела обнаружения, другие части уменьшаются, чтобы сохранить сумму.
+Detection = Обнаружение
+    .hover = Пределы обнаружения и количественного определения образцов жирных кислот. Триацилглицерины фильтруются только по порогу.
+Detection_Sample = Образец
+LimitOfDetection = Предел обнаружения
+    .hover = Предел обнаружения (LOD): вид со средним ниже него не обнаружен, значения его повторностей ниже него замещаются.
+LimitOfQuantification = Предел количественного определения
+    .hover = Предел количественного определения (LOQ): вид со средним ниже него обнаружен, но не определен количественно.
+Limit = Предел
+    .detection = Ниже предела обнаружения, замещенное среднее:
+    .quantification = Ниже предела количественного определения, среднее:
+Substitution = Замещение
+    .hover = Замещение значений повторностей ниже предела обнаружения.
+Substitution_Zero = 0
+    .hover = Ноль.
+Substitution_HalfLimit = LOD/2
+    .hover = Половина предела обнаружения.
+Substitution_RootTwoLimit = LOD/√2
+    .hover = Предел обнаружения, деленный на квадратный корень из двух.
+Substitution_Exclusion = Исключение
+    .hover = Значения исключаются из статистики.
+Bootstrap = Бутстрэп
+    .hover = 95% бутстрэп-интервалы индексов, факторов и метрик: повторности выбираются с возвращением внутри каждого образца.
+Bootstrap_Interval = Интервал
+    .hover = Интервал по оценкам на повторных выборках.
+Bootstrap_Resamples = Повторные выборки
+    .hover = Число бутстрэп-выборок.
+Bootstrap_Seed = Зерно
+    .hover = Зерно повторных выборок, то же зерно воспроизводит тот же интервал.
+Interval_Percentile = Процентильный
+    .hover = Квантили 2.5% и 97.5% оценок на повторных выборках.
+Interval_BiasCorrectedAccelerated = BCa
+    .hover = С поправкой на смещение и ускорением: процентили корректируются на смещение медианы оценок на повторных выборках и на асимметрию оценок складного ножа.
+Properties = Свойства
+    .hover = Химические свойства масла, вычисленные по составу жирных кислот для каждой повторности.
+Standard_En14214 = EN 14214
+    .hover = Европейский стандарт метиловых эфиров жирных кислот для дизельных двигателей.
+Standard_AstmD6751 = ASTM D6751
+    .hover = Американский стандарт базового компонента биодизельного топлива (B100).
+Nutrition = Пищевая ценность
+    .hover = Жирные кислоты на 100 граммов продукта и процент от референсного потребления.
+Nutrition_Fat = Общий жир
+    .hover = Общий жир, граммы на 100 граммов продукта.
+Nutrition_Conversion = Коэффициент пересчета
+    .hover = Коэффициент пересчета липидов, граммы жирных кислот на грамм жира (0.956 для растительных масел, Weihrauch et al., 1977).
+Nutrition_Basis = Основа
+    .hover = Основа состава, переводится в массовые доли жирных кислот.
+Basis_MoleFractions = Мольные доли
+    .hover = Мольные доли жирных кислот, взвешенные по массам Mᵢ(FA).
+Basis_FattyAcidMassFractions = Массовые доли жирных кислот
+    .hover = Массовые доли жирных кислот, берутся как есть.
+Basis_MethylEsterMassFractions = Массовые доли FAME
+    .hover = Массовые доли метиловых эфиров жирных кислот, переводятся в жирные кислоты по коэффициентам Mᵢ(FA) / Mᵢ(FAME).
+ReferenceIntake = Референсное потребление
+    .hover = Процент от референсного потребления взрослого (Регламент (ЕС) № 1169/2011): 70 г жира, 20 г насыщенных жиров.
+Fat = Жир
+    .hover = Общий жир, граммы на 100 граммов продукта.
+FattyAcids = Жирные кислоты
+    .hover = Всего жирных кислот, граммы на 100 граммов продукта.
+Melting = Плавление
+    .hover = Физическое поведение жира, оцененное по триацилглицеринам: суммы позиционных типов, интервал плавления и содержание твердого жира, вычисленные для каждой повторности.
+SSS = SSS
+    .hover = Тринасыщенные триацилглицерины, проценты.
+SSU = SSU
+    .hover = Динасыщенные триацилглицерины с ненасыщенной жирной кислотой в sn-1 или sn-3, проценты.
+SUS = SUS
+    .hover = Динасыщенные триацилглицерины с ненасыщенной жирной кислотой в sn-2, проценты.
+SUU = SUU
+    .hover = Мононасыщенные триацилглицерины с насыщенной жирной кислотой в sn-1 или sn-3, проценты.
+USU = USU
+    .hover = Мононасыщенные триацилглицерины с насыщенной жирной кислотой в sn-2, проценты.
+UUU = UUU
+    .hover = Триненасыщенные триацилглицерины, проценты.
+MeltingRangeStart = Начало интервала плавления
+    .hover = Температура, ниже которой расплавлено 5% жира, °C. Температуры плавления позиционных типов (PPP 66, PPO 35, POP 37, POO 19, OPO 21, OOO 5 °C) понижаются на 5 °C на каждую двойную связь сверх одной на ненасыщенную жирную кислоту.
+MeltingRangeEnd = Конец интервала плавления
+    .hover = Температура, ниже которой расплавлено 95% жира, °C.
+SolidFatContent10 = SFC 10 °C
+    .hover = Содержание твердого жира при 10 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent15 = SFC 15 °C
+    .hover = Содержание твердого жира при 15 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent20 = SFC 20 °C
+    .hover = Содержание твердого жира при 20 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent25 = SFC 25 °C
+    .hover = Содержание твердого жира при 25 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent30 = SFC 30 °C
+    .hover = Содержание твердого жира при 30 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent35 = SFC 35 °C
+    .hover = Содержание твердого жира при 35 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+SolidFatContent40 = SFC 40 °C
+    .hover = Содержание твердого жира при 40 °C по аддитивной модели, проценты. Каждый триацилглицерин плавится линейно в интервале 5 °C ниже своей температуры плавления.
+Blend = Смесь
+    .hover = Смешать выбранные образцы с массовыми долями и добавить виртуальный образец смеси к данным. Составы образцов нормируются и усредняются по их собственным повторностям перед смешиванием, массовые доли переводятся в мольные доли по средним молярным массам триацилглицеринов образцов. Смесь является одной повторностью.
+Mixing_Simple = Простое смешивание
+    .hover = Виды триацилглицеринов образцов сохраняются, смесь является взвешенной суммой видов.
+Mixing_Interesterification = Химическая переэтерификация
+    .hover = Жирные кислоты смеси случайно перераспределяются по всем положениям (1,2,3-случайное).
@@ -79,3 +79,97 @@ value = значение
    *[nominative] жирная кислота
     [genitive] жирной кислоты
 }
+
+# Indices
+
+Unsaturated-9 = Омега -9
+    .hover = Сумма омега -9 жирных кислот.
+Unsaturated-6 = Омега -6
+    .hover = Сумма омега -6 жирных кислот.
+Unsaturated-3 = Омега -3
+    .hover = Сумма омега -3 жирных кислот.
+Unsaturated9 = Дельта 9
+    .hover = Сумма жирных кислот с двойной связью дельта 9.
+StearoylCoenzymeADesaturase16 = Стеароил-КоА-десатураза 16
+    .abbreviation = SCD-16
+    .hover = Активность Δ9-десатуразы по пальмитиновой кислоте: 16:1 / 16:0.
+StearoylCoenzymeADesaturase18 = Стеароил-КоА-десатураза 18
+    .abbreviation = SCD-18
+    .hover = Активность Δ9-десатуразы по стеариновой кислоте: 18:1 / 18:0.
+Delta12Desaturase = Δ12-десатураза
+    .abbreviation = FAD2
+    .hover = Активность Δ12-десатуразы: 18:2 n-6 / 18:1 n-9.
+Delta15Desaturase = Δ15-десатураза
+    .abbreviation = FAD3
+    .hover = Активность Δ15-десатуразы: 18:3 n-3 / 18:2 n-6.
+Delta6Desaturase = Δ6-десатураза
+    .abbreviation = D6D
+    .hover = Активность Δ6-десатуразы: 18:3 n-6 / 18:2 n-6.
+Delta5Desaturase = Δ5-десатураза
+    .abbreviation = D5D
+    .hover = Активность Δ5-десатуразы: 20:4 n-6 / 20:3 n-6.
+Elongase = Элонгаза
+    .abbreviation = ELOVL6
+    .hover = Активность элонгазы: 18:0 / 16:0.
+AverageChainLength = Средняя длина цепи
+    .abbreviation = ACL
+    .hover = Среднее число атомов углерода жирных кислот: Σ(Cᵢ·xᵢ) / Σxᵢ.
+DoubleBondIndex = Индекс двойных связей
+    .abbreviation = DBI
+    .hover = Среднее число двойных связей жирных кислот: Σ(DBᵢ·xᵢ) / Σxᵢ.
+PeroxidizabilityIndex = Индекс пероксидируемости
+    .abbreviation = PI
+    .hover = Подверженность перекисному окислению (Witting, 1965): 0.025·моноеновые + 1·диеновые + 2·триеновые + 4·тетраеновые + 6·пентаеновые + 8·гексаеновые.
+
+## Chemical properties
+
+MeanMolecularWeight = Средняя молекулярная масса
+    .abbreviation = MW
+    .hover = Средняя относительная молекулярная масса жирных кислот: Σ(Mᵢ·xᵢ) / Σxᵢ.
+SaponificationValue = Число омыления
+    .abbreviation = SV
+    .hover = Миллиграммы KOH на грамм масла: 3·56.106·1000 / (3·M̄ + 38.049).
+IodineValue = Йодное число
+    .abbreviation = IV
+    .hover = Граммы I₂ на 100 граммов масла: 3·253.809·100·Σ(DBᵢ·xᵢ) / Σxᵢ / (3·M̄ + 38.049).
+CalculatedOxidizability = Расчетная окисляемость
+    .abbreviation = Cox
+    .hover = Fatemi и Hammond (1980): (18:1 + 10.3·18:2 + 21.6·18:3) / 100.
+OxidativeStabilityIndex = Индекс окислительной стабильности
+    .abbreviation = OSI
+    .hover = Теоретический индукционный период в часах (Park et al., 2008): 117.9295 / X + 2.5905, X является массовым процентом 18:2 и 18:3.
+
+## Biodiesel properties
+
+BiodieselProperties = Свойства биодизеля
+    .hover = Свойства метиловых эфиров жирных кислот, предсказанные по составу и проверенные по пределам EN 14214 и ASTM D6751.
+CetaneNumber = Цетановое число
+    .abbreviation = CN
+    .hover = Krisnangkura (1986): 46.3 + 5458 / SV - 0.225·IV.
+CloudPoint = Температура помутнения
+    .abbreviation = CP
+    .hover = °C, Sarin et al. (2009): 0.526·C16:0 - 4.992.
+ColdFilterPluggingPoint = Предельная температура фильтруемости
+    .abbreviation = CFPP
+    .hover = °C, Ramos et al. (2009): 3.1417·LCSF - 16.477.
+Density = Плотность
+    .abbreviation = ρ
+    .hover = г/см³ при 15 °C, Ramírez-Verduzco et al. (2012) при 20 °C: Σwᵢ(0.8463 + 4.9 / Mᵢ + 0.0118·Nᵢ), + 0.00072 г/см³ на °C (Tat и Van Gerpen, 2000).
+DegreeOfUnsaturation = Степень ненасыщенности
+    .abbreviation = DU
+    .hover = Ramos et al. (2009): MUFA + 2·PUFA, массовые проценты.
+HigherHeatingValue = Высшая теплота сгорания
+    .abbreviation = HHV
+    .hover = МДж/кг, Ramírez-Verduzco et al. (2012): Σwᵢ(46.19 - 1794 / Mᵢ - 0.21·Nᵢ).
+KinematicViscosity = Кинематическая вязкость
+    .abbreviation = ν
+    .hover = мм²/с при 40 °C, Ramírez-Verduzco et al. (2012): ln ν = Σwᵢ(-12.503 + 2.496·ln Mᵢ - 0.178·Nᵢ).
+LinolenicAcidMethylEster = Метиловый эфир линоленовой кислоты
+    .abbreviation = C18:3
+    .hover = Массовый процент метиловых эфиров 18:3.
+LongChainSaturatedFactor = Фактор длинноцепочечных насыщенных
+    .abbreviation = LCSF
+    .hover = Ramos et al. (2009): 0.1·C16:0 + 0.5·C18:0 + 1·C20:0 + 1.5·C22:0 + 2·C24:0, массовые проценты.
+PolyunsaturatedMethylEsters = Полиненасыщенные метиловые эфиры
+    .abbreviation = PUFAME
+    .hover = Массовый процент метиловых эфиров с не менее чем четырьмя двойными связями.
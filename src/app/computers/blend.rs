@@ -0,0 +1,292 @@
+use crate::{
+    app::{
+        computers::{
+            fatty_acids::prediction::{Key as PredictionKey, compute as predict},
+            triacylglycerols::decomposition::{Key as DecompositionKey, compute as decompose},
+        },
+        states::fatty_acids::settings::Model,
+    },
+    r#const::VALUE,
+    utils::{HashedDataFrame, HashedMetaDataFrame, polars::width},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use ordered_float::OrderedFloat;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Mixings
+pub(crate) const MIXINGS: [Mixing; 2] = [Mixing::Simple, Mixing::Interesterification];
+
+/// Glycerol backbone of the triacylglycerol: C₃H₈O₃ - 3·H₂O
+const GLYCEROL: f64 = 38.049;
+
+/// Blend computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Blend computer
+///
+/// Calculates the virtual sample of the blend of the samples with the weight
+/// fractions, every sample is the mean of its own replicates. The weight fractions are converted to the mole fractions by the
+/// mean molar masses of the triacylglycerols of the samples. The fatty acids
+/// are the weighted sum of every stereospecific number, the triacylglycerols
+/// are the weighted sum of the species or the 1,2,3-random interesterification
+/// of the blended fatty acids.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frames.is_empty() {
+            return Ok(HashedDataFrame::EMPTY);
+        }
+        let schema = key.frames[0].data.schema();
+        if schema.contains(FATTY_ACID) {
+            // The triacylglycerol of the three fatty acids
+            let molar_mass = lit(3.0)
+                * col(FATTY_ACID)
+                    .fatty_acid()
+                    .relative_atomic_mass(None)
+                    .cast(DataType::Float64)
+                + lit(GLYCEROL);
+            let data_frame =
+                blend(key, &[LABEL, FATTY_ACID], &FATTY_ACIDS, molar_mass)?.collect()?;
+            return HashedDataFrame::new(data_frame);
+        }
+        let molar_mass = col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .relative_atomic_mass(None)
+            .cast(DataType::Float64);
+        let mut data_frame =
+            blend(key, &[LABEL, TRIACYLGLYCEROL], &[VALUE], molar_mass)?.collect()?;
+        if key.mixing == Mixing::Interesterification {
+            let triacylglycerols = HashedDataFrame::new(data_frame)?;
+            let fatty_acids = HashedDataFrame::new(
+                decompose(DecompositionKey::new(&triacylglycerols))?.collect()?,
+            )?;
+            data_frame =
+                predict(PredictionKey::new(&fatty_acids, Model::OneTwoThreeRandom))?.collect()?;
+        }
+        HashedDataFrame::new(data_frame)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Blend key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) fractions: &'a [OrderedFloat<f64>],
+    pub(crate) mixing: Mixing,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frames: &'a [HashedMetaDataFrame],
+        fractions: &'a [OrderedFloat<f64>],
+        mixing: Mixing,
+    ) -> Self {
+        Self {
+            frames,
+            fractions,
+            mixing,
+        }
+    }
+}
+
+/// Blend value
+type Value = HashedDataFrame;
+
+/// Mixing of the triacylglycerols
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) enum Mixing {
+    /// The triacylglycerols of the samples are kept
+    #[default]
+    Simple,
+    /// The fatty acids of the blend are randomly redistributed over the
+    /// positions
+    Interesterification,
+}
+
+impl Mixing {
+    pub(crate) fn id(&self) -> &'static str {
+        match self {
+            Self::Simple => "Simple",
+            Self::Interesterification => "Interesterification",
+        }
+    }
+
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Simple => "Mixing_Simple",
+            Self::Interesterification => "Mixing_Interesterification",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Simple => "Mixing_Simple.hover",
+            Self::Interesterification => "Mixing_Interesterification.hover",
+        }
+    }
+}
+
+/// Stereospecific numbers of the fatty acids
+const FATTY_ACIDS: [&str; 3] = [
+    STEREOSPECIFIC_NUMBERS123,
+    STEREOSPECIFIC_NUMBERS13,
+    STEREOSPECIFIC_NUMBERS2,
+];
+
+/// Blend
+///
+/// Every replicate of the sample is normalized, the sample is the mean of its
+/// own non-null replicates normalized again. The sample is multiplied by its
+/// moles: the weight fraction divided by the mean molar mass of the first
+/// names. The species absent in the sample are zero. The blend is the single
+/// normalized replicate.
+fn blend(key: Key, keys: &[&str], names: &[&str], molar_mass: Expr) -> PolarsResult<LazyFrame> {
+    let total = key.fractions.iter().map(|fraction| fraction.0).sum::<f64>();
+    if !total.is_finite() || total <= 0.0 {
+        polars_bail!(ComputeError: "expected the positive sum of the weight fractions");
+    }
+    let normalize = |value: Expr, otherwise: Expr| {
+        let sum = value.clone().sum();
+        when(sum.clone().gt(0))
+            .then(value / sum)
+            .otherwise(otherwise)
+    };
+    let mut lazy_frames = Vec::with_capacity(key.frames.len());
+    for (frame, fraction) in key.frames.iter().zip(key.fractions) {
+        let fraction = fraction.0 / total;
+        let schema = frame.data.schema();
+        // Mean of the normalized replicates, the empty replicates are excluded
+        let sample = |name: &str| -> PolarsResult<Expr> {
+            let replicates = schema
+                .get(name)
+                .and_then(width)
+                .ok_or_else(|| polars_err!(SchemaMismatch: "expected `{name}` to be an array"))?;
+            let values = (0..replicates)
+                .map(|replicate| {
+                    let value = col(name).arr().get(lit(replicate as i64), true);
+                    normalize(value, lit(NULL).cast(DataType::Float64))
+                })
+                .collect();
+            let mean = concat_arr(values)?.arr().mean().fill_null(0);
+            Ok(normalize(mean.clone(), mean))
+        };
+        // Moles of the sample
+        let moles = {
+            let mean = (sample(names[0])? * molar_mass.clone()).sum();
+            when(mean.clone().gt(0))
+                .then(lit(fraction) / mean)
+                .otherwise(lit(0.0))
+        };
+        let mut exprs = keys.iter().map(|&name| col(name)).collect::<Vec<_>>();
+        for &name in names {
+            exprs.push((sample(name)? * moles.clone()).alias(name));
+        }
+        lazy_frames.push(frame.data.data_frame.clone().lazy().select(exprs));
+    }
+    let exprs = names
+        .iter()
+        .map(|&name| Ok(concat_arr(vec![normalize(col(name), col(name))])?.alias(name)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(concat(lazy_frames, UnionArgs::default())?
+        .group_by_stable(keys.iter().map(|&name| col(name)).collect::<Vec<_>>())
+        .agg(
+            names
+                .iter()
+                .map(|&name| col(name).sum())
+                .collect::<Vec<_>>(),
+        )
+        .with_columns(exprs))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use metadata::Metadata;
+
+    /// Single replicate arrays of the rows
+    fn arrays(rows: [f64; 3]) -> Vec<Series> {
+        rows.map(|row| Series::new(PlSmallStr::EMPTY, [row]))
+            .to_vec()
+    }
+
+    /// Values of the species sorted by the label
+    fn values(data_frame: &DataFrame) -> PolarsResult<Vec<f64>> {
+        let label = concat_str(
+            [
+                STEREOSPECIFIC_NUMBERS1,
+                STEREOSPECIFIC_NUMBERS2,
+                STEREOSPECIFIC_NUMBERS3,
+            ]
+            .map(|name| col(LABEL).struct_().field_by_name(name)),
+            ",",
+            true,
+        );
+        let data_frame = data_frame
+            .clone()
+            .lazy()
+            .sort_by_exprs([label], SortMultipleOptions::default())
+            .collect()?;
+        Ok(data_frame[VALUE]
+            .array()?
+            .get_inner()
+            .f64()?
+            .into_no_null_iter()
+            .collect())
+    }
+
+    #[test]
+    fn interesterification() -> PolarsResult<()> {
+        let fatty_acids = df! {
+            LABEL => ["Palmitic", "Oleic", "Linoleic"],
+            FATTY_ACID => [
+                fatty_acid!(C16 {})?,
+                fatty_acid!(C18 {9 => C})?,
+                fatty_acid!(C18 {9 => C, 12 => C})?,
+            ],
+            STEREOSPECIFIC_NUMBERS123 => arrays([0.2, 0.5, 0.3]),
+            STEREOSPECIFIC_NUMBERS13 => arrays([0.3, 0.4, 0.3]),
+            STEREOSPECIFIC_NUMBERS2 => arrays([0.0, 0.7, 0.3]),
+        }?
+        .lazy()
+        .with_columns(
+            [
+                STEREOSPECIFIC_NUMBERS123,
+                STEREOSPECIFIC_NUMBERS13,
+                STEREOSPECIFIC_NUMBERS2,
+            ]
+            .map(|name| col(name).cast(DataType::Array(Box::new(DataType::Float64), 1))),
+        )
+        .collect()?;
+        let fatty_acids = HashedDataFrame::new(fatty_acids)?;
+        let triacylglycerols = HashedDataFrame::new(
+            predict(PredictionKey::new(&fatty_acids, Model::OneTwoThreeRandom))?.collect()?,
+        )?;
+        let expected = values(&triacylglycerols.data_frame)?;
+        let frames = [HashedMetaDataFrame::new(
+            Metadata(Default::default()),
+            triacylglycerols,
+        )];
+        let blend = Computer.try_compute(Key::new(
+            &frames,
+            &[OrderedFloat(1.0)],
+            Mixing::Interesterification,
+        ))?;
+        let actual = values(&blend.data_frame)?;
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(&actual) {
+            assert!((expected - actual).abs() < 1e-9, "{expected} != {actual}");
+        }
+        Ok(())
+    }
+}
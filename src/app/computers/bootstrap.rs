@@ -0,0 +1,152 @@
+use super::multivariate::{distance, observations, transform};
+use crate::{
+    app::states::fatty_acids::settings::{Bootstrap, Interval, Metric, Transformation},
+    r#const::{LOWER, SAMPLE, UPPER, VALUE},
+    utils::bootstrap::{bias_corrected_accelerated, jackknife, percentile, resamples},
+};
+use polars::prelude::*;
+
+/// Bootstrap interval of the statistic
+///
+/// The counts are the replicate counts of the samples, the statistic takes the
+/// replicate indices of every sample. None without the bootstrap.
+pub(crate) fn interval(
+    counts: &[usize],
+    bootstrap: &Bootstrap,
+    statistic: impl Fn(&[Vec<usize>]) -> Option<f64>,
+) -> Option<[f64; 2]> {
+    if !bootstrap.enabled || counts.contains(&0) {
+        return None;
+    }
+    let estimates = resamples(counts, bootstrap.resamples, bootstrap.seed)
+        .iter()
+        .filter_map(|resample| statistic(resample))
+        .collect::<Vec<_>>();
+    match bootstrap.interval {
+        Interval::Percentile => percentile(&estimates),
+        Interval::BiasCorrectedAccelerated => {
+            let whole = counts
+                .iter()
+                .map(|&count| (0..count).collect())
+                .collect::<Vec<_>>();
+            let jackknife = jackknife(counts)
+                .iter()
+                .filter_map(|subsample| statistic(subsample))
+                .collect::<Vec<_>>();
+            bias_corrected_accelerated(statistic(&whole)?, &estimates, &jackknife)
+        }
+    }
+}
+
+/// Bootstrap intervals of the sample means
+///
+/// Every sample struct gains the [`LOWER`] and the [`UPPER`] bounds of the
+/// mean of its replicate array, null without the bootstrap.
+pub(crate) fn intervals(
+    data_frame: &mut DataFrame,
+    names: &[&str],
+    bootstrap: &Bootstrap,
+) -> PolarsResult<()> {
+    for &name in names {
+        let mut fields = data_frame[name].struct_()?.clone().unnest();
+        let samples = fields[SAMPLE].array()?.clone();
+        let mut lower = Vec::with_capacity(fields.height());
+        let mut upper = Vec::with_capacity(fields.height());
+        for row in 0..fields.height() {
+            let values = match samples.get_as_series(row) {
+                Some(series) => series.f64()?.iter().flatten().collect(),
+                None => Vec::new(),
+            };
+            let bounds = interval(&[values.len()], bootstrap, |resample| {
+                let sum = resample[0].iter().map(|&index| values[index]).sum::<f64>();
+                Some(sum / resample[0].len() as f64)
+            });
+            lower.push(bounds.map(|[lower, _]| lower));
+            upper.push(bounds.map(|[_, upper]| upper));
+        }
+        fields.hstack_mut(&[
+            Column::new(LOWER.into(), lower),
+            Column::new(UPPER.into(), upper),
+        ])?;
+        data_frame.with_column(fields.into_struct(name.into()).into_series())?;
+    }
+    Ok(())
+}
+
+/// Bootstrap intervals of the metric between the samples
+///
+/// The replicates of the both samples are resampled, the resampled means are
+/// transformed before the [`distance`]. Rows and columns are the samples.
+pub(crate) fn metric_intervals(
+    data_frame: &DataFrame,
+    names: &[&str],
+    labels: &StringChunked,
+    transformation: &Transformation,
+    metric: Metric,
+    bootstrap: &Bootstrap,
+) -> PolarsResult<Vec<Vec<Option<[f64; 2]>>>> {
+    let observations = observations(data_frame, names)?;
+    let replicates = names
+        .iter()
+        .map(|&name| {
+            observations
+                .iter()
+                .filter(|observation| observation.sample == name)
+                .map(|observation| &observation.values)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mean = |replicates: &[&Vec<f64>], indices: &[usize]| {
+        let mut mean = vec![0.0; data_frame.height()];
+        for &index in indices {
+            for (mean, value) in mean.iter_mut().zip(replicates[index]) {
+                *mean += value / indices.len() as f64;
+            }
+        }
+        mean
+    };
+    Ok(replicates
+        .iter()
+        .map(|left| {
+            replicates
+                .iter()
+                .map(|right| {
+                    interval(&[left.len(), right.len()], bootstrap, |resample| {
+                        let mut profiles = [mean(left, &resample[0]), mean(right, &resample[1])];
+                        transform(&mut profiles, labels, transformation, metric);
+                        let distance = distance(metric, &profiles[0], &profiles[1]);
+                        distance.is_finite().then_some(distance)
+                    })
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Metric matrix with the bootstrap intervals
+///
+/// Every metric column becomes the struct of the [`VALUE`], the [`LOWER`] and
+/// the [`UPPER`] bound, the bounds are null without the bootstrap.
+pub(crate) fn bounds(
+    data_frame: &mut DataFrame,
+    intervals: &[Vec<Option<[f64; 2]>>],
+) -> PolarsResult<()> {
+    let height = data_frame.height();
+    for (column, name) in data_frame.get_column_names_owned().into_iter().enumerate() {
+        let bound = |bound: usize| {
+            (0..height)
+                .map(|row| {
+                    let bounds = intervals.get(row)?.get(column).copied().flatten()?;
+                    Some(bounds[bound])
+                })
+                .collect::<Vec<_>>()
+        };
+        let fields = df! {
+            VALUE => data_frame[name.as_str()].f64()?.iter().collect::<Vec<_>>(),
+            LOWER => bound(0),
+            UPPER => bound(1),
+        }?;
+        data_frame.with_column(fields.into_struct(name).into_series())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use super::indices::{compute, filter, format, species, summary, unnest};
+use crate::{
+    app::states::fatty_acids::settings::{
+        Bootstrap, Dispersion, Filter, Settings, StereospecificNumbers,
+    },
+    r#const::PROPERTY,
+    utils::{HashedDataFrame, polars::eval_arr},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use ordered_float::OrderedFloat;
+use polars::prelude::*;
+use std::{
+    f64::consts::E,
+    fmt::{self, Display, Formatter},
+};
+use tracing::instrument;
+
+/// Biodiesel properties
+pub(crate) const PROPERTIES: [&str; 10] = [
+    "CetaneNumber",
+    "KinematicViscosity",
+    "Density",
+    "HigherHeatingValue",
+    "ColdFilterPluggingPoint",
+    "CloudPoint",
+    "LongChainSaturatedFactor",
+    "DegreeOfUnsaturation",
+    "LinolenicAcidMethylEster",
+    "PolyunsaturatedMethylEsters",
+];
+
+/// Standards
+pub(crate) const STANDARDS: [Standard; 2] = [Standard::En14214, Standard::AstmD6751];
+
+/// Methylene of the methyl ester: CH₃ - H
+const METHYLENE: f64 = 14.027;
+/// Thermal expansion of the density of the methyl esters, g/cm³ per °C (Tat and
+/// Van Gerpen, 2000)
+const DENSITY_TEMPERATURE_COEFFICIENT: f64 = 0.00072;
+
+/// Biodiesel computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Biodiesel computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key.stereospecific_numbers);
+        lazy_frame = filter(lazy_frame, key.frame, key.filter)?;
+        lazy_frame = compute(
+            lazy_frame,
+            key.frame,
+            PROPERTY,
+            &PROPERTIES,
+            |property, sample| {
+                let array = eval_arr(sample, |expr| compute_property(property, expr))?;
+                Ok(summary(array, key.dispersion, key.ddof).to_vec())
+            },
+        )?;
+        format(
+            lazy_frame,
+            PROPERTY,
+            &[],
+            key.bootstrap,
+            key.precision,
+            key.significant,
+        )
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Biodiesel key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) filter: Filter,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) threshold: OrderedFloat<f64>,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            filter: settings.filter,
+            precision: settings.precision,
+            significant: settings.significant,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            threshold: settings.threshold.auto,
+        }
+    }
+}
+
+/// Biodiesel value
+type Value = DataFrame;
+
+/// Standard
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Standard {
+    En14214,
+    AstmD6751,
+}
+
+impl Standard {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::En14214 => "Standard_En14214",
+            Self::AstmD6751 => "Standard_AstmD6751",
+        }
+    }
+
+    /// Limit of the property, none if the standard does not specify it
+    pub(crate) fn limit(&self, property: &str) -> Option<Limit> {
+        let (minimum, maximum) = match (self, property) {
+            (Self::En14214, "CetaneNumber") => (Some(51.0), None),
+            (Self::En14214, "KinematicViscosity") => (Some(3.5), Some(5.0)),
+            (Self::En14214, "Density") => (Some(0.86), Some(0.9)),
+            (Self::En14214, "LinolenicAcidMethylEster") => (None, Some(12.0)),
+            (Self::En14214, "PolyunsaturatedMethylEsters") => (None, Some(1.0)),
+            (Self::AstmD6751, "CetaneNumber") => (Some(47.0), None),
+            (Self::AstmD6751, "KinematicViscosity") => (Some(1.9), Some(6.0)),
+            _ => return None,
+        };
+        Some(Limit { minimum, maximum })
+    }
+}
+
+/// Limit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Limit {
+    pub(crate) minimum: Option<f64>,
+    pub(crate) maximum: Option<f64>,
+}
+
+impl Limit {
+    pub(crate) fn contains(&self, value: f64) -> bool {
+        self.minimum.is_none_or(|minimum| value >= minimum)
+            && self.maximum.is_none_or(|maximum| value <= maximum)
+    }
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match (self.minimum, self.maximum) {
+            (Some(minimum), Some(maximum)) => write!(f, "{minimum}–{maximum}"),
+            (Some(minimum), None) => write!(f, "≥{minimum}"),
+            (None, Some(maximum)) => write!(f, "≤{maximum}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Compute property
+///
+/// The replicate values are the mole fractions of the fatty acids, the
+/// biodiesel is taken as the methyl esters of the fatty acids. The empirical
+/// equations take the mass percents of the methyl esters.
+fn compute_property(property: &str, expr: Expr) -> Expr {
+    let mass = expr * molecular_weight();
+    let percent = lit(100.0) * mass.clone() / mass.sum();
+    let fraction = percent.clone() / lit(100.0);
+    match property {
+        // Krisnangkura (1986): 46.3 + 5458 / SV - 0.225·IV
+        "CetaneNumber" => {
+            let saponification_value = (lit(560.0) * percent.clone() / molecular_weight()).sum();
+            let iodine_value = (lit(254.0) * double_bonds() * percent / molecular_weight()).sum();
+            lit(46.3) + lit(5458.0) / saponification_value - lit(0.225) * iodine_value
+        }
+        // Ramírez-Verduzco et al. (2012): mm²/s at 40 °C,
+        // ln ν = Σwᵢ(-12.503 + 2.496·ln Mᵢ - 0.178·Nᵢ)
+        "KinematicViscosity" => (fraction
+            * (lit(-12.503) + lit(2.496) * molecular_weight().log(lit(E))
+                - lit(0.178) * double_bonds()))
+        .sum()
+        .exp(),
+        // Ramírez-Verduzco et al. (2012): g/cm³ at 20 °C,
+        // ρ = Σwᵢ(0.8463 + 4.9 / Mᵢ + 0.0118·Nᵢ), brought to 15 °C of the
+        // standards
+        "Density" => {
+            (fraction
+                * (lit(0.8463) + lit(4.9) / molecular_weight() + lit(0.0118) * double_bonds()))
+            .sum()
+                + lit(5.0 * DENSITY_TEMPERATURE_COEFFICIENT)
+        }
+        // Ramírez-Verduzco et al. (2012): MJ/kg,
+        // δ = Σwᵢ(46.19 - 1794 / Mᵢ - 0.21·Nᵢ)
+        "HigherHeatingValue" => (fraction
+            * (lit(46.19) - lit(1794.0) / molecular_weight() - lit(0.21) * double_bonds()))
+        .sum(),
+        // Ramos et al. (2009): °C, 3.1417·LCSF - 16.477
+        "ColdFilterPluggingPoint" => {
+            lit(3.1417) * long_chain_saturated_factor(percent) - lit(16.477)
+        }
+        // Sarin et al. (2009): °C, 0.526·C16:0 - 4.992
+        "CloudPoint" => lit(0.526) * species(percent, 16, 0, None) - lit(4.992),
+        "LongChainSaturatedFactor" => long_chain_saturated_factor(percent),
+        // Ramos et al. (2009): MUFA + 2·PUFA
+        "DegreeOfUnsaturation" => {
+            let unsaturation = col(FATTY_ACID).fatty_acid().unsaturation();
+            percent
+                .clone()
+                .filter(unsaturation.clone().eq(lit(1)))
+                .sum()
+                + lit(2.0) * percent.filter(unsaturation.gt(lit(1))).sum()
+        }
+        // EN 14214: mass percent of the 18:3
+        "LinolenicAcidMethylEster" => species(percent, 18, 3, None),
+        // EN 14214: mass percent of the methyl esters with at least four double
+        // bonds
+        "PolyunsaturatedMethylEsters" => percent
+            .filter(col(FATTY_ACID).fatty_acid().unsaturation().gt_eq(lit(4)))
+            .sum(),
+        _ => unreachable!(),
+    }
+}
+
+/// Relative molecular mass of the methyl ester
+fn molecular_weight() -> Expr {
+    col(FATTY_ACID)
+        .fatty_acid()
+        .relative_atomic_mass(None)
+        .cast(DataType::Float64)
+        + lit(METHYLENE)
+}
+
+/// Number of the double bonds
+fn double_bonds() -> Expr {
+    col(FATTY_ACID)
+        .fatty_acid()
+        .unsaturation()
+        .cast(DataType::Float64)
+}
+
+/// Long chain saturated factor (Ramos et al., 2009)
+///
+/// 0.1·C16:0 + 0.5·C18:0 + 1·C20:0 + 1.5·C22:0 + 2·C24:0
+fn long_chain_saturated_factor(percent: Expr) -> Expr {
+    lit(0.1) * species(percent.clone(), 16, 0, None)
+        + lit(0.5) * species(percent.clone(), 18, 0, None)
+        + species(percent.clone(), 20, 0, None)
+        + lit(1.5) * species(percent.clone(), 22, 0, None)
+        + lit(2.0) * species(percent, 24, 0, None)
+}
@@ -0,0 +1,77 @@
+use super::prediction::replicates;
+use crate::utils::HashedDataFrame;
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Clipping computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Clipping computer
+///
+/// Calculates the mass of the SN-1,3 composition calculated as
+/// (3·SN-1,2,3 - SN-2) / 2 that is negative and clipped to zero by the
+/// 1,3-calculated-2-random model, percent.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() || replicates(key.frame)? == 0 {
+            return Ok(None);
+        }
+        let data_frame = key
+            .frame
+            .data_frame
+            .clone()
+            .lazy()
+            .select([compute(key.frame)?])
+            .collect()?;
+        Ok(data_frame[0].f64()?.get(0))
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Clipping key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame) -> Self {
+        Self { frame }
+    }
+}
+
+/// Clipping value
+///
+/// The largest clipped mass of the replicates, percent.
+type Value = Option<f64>;
+
+/// Compute
+fn compute(data_frame: &DataFrame) -> PolarsResult<Expr> {
+    let exprs = (0..replicates(data_frame)?)
+        .map(|replicate| {
+            let normalized = |name: &str| {
+                let value = col(name)
+                    .arr()
+                    .get(lit(replicate as i64), true)
+                    .fill_null(0);
+                value.clone() / value.sum()
+            };
+            let calculated = (lit(3) * normalized(STEREOSPECIFIC_NUMBERS123)
+                - normalized(STEREOSPECIFIC_NUMBERS2))
+                / lit(2);
+            lit(-100.0) * calculated.clip_max(lit(0)).sum()
+        })
+        .collect::<Vec<_>>();
+    max_horizontal(exprs)
+}
@@ -0,0 +1,102 @@
+use crate::{
+    app::{
+        computers::{clusters, fatty_acids::detection, means, transform},
+        states::fatty_acids::settings::{
+            Clustering, Filter, Metric, Settings, StereospecificNumbers, Transformation,
+        },
+    },
+    r#const::THRESHOLD,
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Clusters computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Clusters computer
+///
+/// Partitions the samples into the clusters with k-means on the mean fatty acid
+/// profiles or with k-medoids on their distance matrix.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key);
+        lazy_frame = filter(lazy_frame, key)?;
+        let data_frame = lazy_frame.collect()?;
+        let names = data_frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let mut means = means(&data_frame, &names)?;
+        transform(
+            &mut means,
+            data_frame[LABEL].str()?,
+            key.transformation,
+            key.metric,
+        );
+        clusters(&names, &means, key.clustering, key.metric)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Clusters key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) clustering: Clustering,
+    pub(crate) filter: Filter,
+    pub(crate) metric: Metric,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) transformation: &'a Transformation,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            clustering: settings.clusters.clustering,
+            filter: settings.filter,
+            metric: settings.metric,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            transformation: &settings.transformation,
+        }
+    }
+}
+
+/// Clusters value
+///
+/// Rows are the samples, columns are the cluster counts with the cluster and
+/// the silhouette width of the sample.
+type Value = DataFrame;
+
+/// Unnest
+fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    lazy_frame.with_columns([all()
+        .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
+        .as_expr()
+        .struct_()
+        .field_by_name(key.stereospecific_numbers.id())
+        .name()
+        .keep()])
+}
+
+/// Filter
+fn filter(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    Ok(lazy_frame.filter(detection(&key.frame.data_frame, key.filter)?))
+}
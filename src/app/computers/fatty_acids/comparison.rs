@@ -0,0 +1,125 @@
+use crate::{
+    app::{
+        computers::{compare, fatty_acids::table::Key as TableKey},
+        states::fatty_acids::settings::Settings,
+    },
+    r#const::{ROW, SAMPLE, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use std::hash::{Hash, Hasher};
+use tracing::instrument;
+
+/// Comparison computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Comparison computer
+///
+/// Compares the two samples fatty acid by fatty acid.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let names = key
+            .table
+            .frame
+            .data_frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let &[first, second] = names.as_slice() else {
+            return Ok(DataFrame::empty());
+        };
+        let mut lazy_frame = key.table.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key);
+        lazy_frame = rows(lazy_frame, key);
+        let data_frame = lazy_frame
+            .select([
+                col(ROW),
+                col(LABEL),
+                col(first).struct_().field_by_name(SAMPLE).alias(first),
+                col(second).struct_().field_by_name(SAMPLE).alias(second),
+            ])
+            .collect()?;
+        compare(&data_frame, [first, second])
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Comparison key
+///
+/// The rows are the cached table value of the table key.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) table: TableKey<'a>,
+    pub(crate) rows: &'a DataFrame,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frame: &'a HashedDataFrame,
+        rows: &'a DataFrame,
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            table: TableKey::new(frame, settings),
+            rows,
+        }
+    }
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The rows are determined by the table key
+        self.table.hash(state);
+    }
+}
+
+/// Comparison value
+///
+/// The [`ROW`] is the row of the fatty acid in the table.
+type Value = DataFrame;
+
+/// Unnest
+fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    lazy_frame.with_columns([all()
+        .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
+        .as_expr()
+        .struct_()
+        .field_by_name(key.table.stereospecific_numbers.id())
+        .name()
+        .keep()])
+}
+
+/// Rows
+///
+/// The rows of the table output, the [`ROW`] is the row of the fatty acid in
+/// the table. The last row of the table is the sum.
+fn rows(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    let table = key
+        .rows
+        .clone()
+        .lazy()
+        .with_row_index(ROW, None)
+        .filter(col(LABEL).is_not_null())
+        .select([col(ROW), col(LABEL), col(FATTY_ACID)]);
+    lazy_frame
+        .with_column(col(FATTY_ACID).fatty_acid().format())
+        .join(
+            table,
+            [col(LABEL), col(FATTY_ACID)],
+            [col(LABEL), col(FATTY_ACID)],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort([ROW], SortMultipleOptions::default())
+}
@@ -1,6 +1,11 @@
 use crate::{
-    app::states::fatty_acids::settings::{Factor, Settings, StereospecificNumbers},
-    r#const::{MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
+    app::{
+        computers::{dispersion, intervals},
+        states::fatty_acids::settings::{
+            Bootstrap, Dispersion, Factor, Settings, StereospecificNumbers,
+        },
+    },
+    r#const::{LOWER, MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD, UPPER},
     utils::{HashedDataFrame, polars::sum_arr},
 };
 use egui::util::cache::{ComputerMut, FrameCache};
@@ -19,11 +24,17 @@ pub(crate) struct Computer;
 impl Computer {
     fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
-        println!("Factors0: {}", lazy_frame.clone().collect()?);
         lazy_frame = compute(lazy_frame, key)?;
-        println!("Factors1: {}", lazy_frame.clone().collect()?);
-        let data_frame = lazy_frame.collect()?;
-        Ok(data_frame)
+        let mut data_frame = lazy_frame.collect()?;
+        let names = data_frame
+            .get_column_names_owned()
+            .into_iter()
+            .filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let names = names.iter().map(PlSmallStr::as_str).collect::<Vec<_>>();
+        intervals(&mut data_frame, &names, key.bootstrap)?;
+        lazy_frame = format(data_frame.lazy(), &names, key);
+        lazy_frame.collect()
     }
 }
 
@@ -37,7 +48,9 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash)]
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
     pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     pub(crate) factor: Factor,
     pub(crate) normalize_factor: bool,
     pub(crate) percent: bool,
@@ -48,10 +61,12 @@ pub(crate) struct Key<'a> {
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
-            ddof: 1,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
             factor: settings.factor,
             normalize_factor: settings.normalize_factor,
             percent: settings.percent,
@@ -98,29 +113,9 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
         }
         exprs.push(
             as_struct(vec![
-                factor
-                    .clone()
-                    .arr()
-                    .mean()
-                    .percent(key.percent)
-                    .precision(key.precision, key.significant)
-                    .alias(MEAN),
-                factor
-                    .clone()
-                    .arr()
-                    .std(key.ddof)
-                    .percent(key.percent)
-                    .precision(key.precision + 1, key.significant)
-                    .alias(STANDARD_DEVIATION),
-                factor
-                    .arr()
-                    .eval(
-                        element()
-                            .percent(key.percent)
-                            .precision(key.precision, key.significant),
-                        false,
-                    )
-                    .alias(SAMPLE),
+                factor.clone().arr().mean().alias(MEAN),
+                dispersion(factor.clone(), key.dispersion, key.ddof).alias(STANDARD_DEVIATION),
+                factor.alias(SAMPLE),
             ])
             .alias(name.clone()),
         );
@@ -128,3 +123,31 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     // println!("FF1: {:?}", lazy_frame.clone().collect().unwrap());
     Ok(lazy_frame.with_columns(exprs))
 }
+
+/// Format
+///
+/// The mean, the dispersion, the replicates and the bootstrap interval bounds
+/// of every sample are rounded.
+fn format(lazy_frame: LazyFrame, names: &[&str], key: Key) -> LazyFrame {
+    let exprs = names
+        .iter()
+        .map(|&name| {
+            let field = |field| col(name).struct_().field_by_name(field);
+            let value = |expr: Expr| {
+                expr.percent(key.percent)
+                    .precision(key.precision, key.significant)
+            };
+            as_struct(vec![
+                value(field(MEAN)),
+                field(STANDARD_DEVIATION)
+                    .percent(key.percent)
+                    .precision(key.precision + 1, key.significant),
+                field(SAMPLE).arr().eval(value(element()), false),
+                value(field(LOWER)),
+                value(field(UPPER)),
+            ])
+            .alias(name)
+        })
+        .collect::<Vec<_>>();
+    lazy_frame.with_columns(exprs)
+}
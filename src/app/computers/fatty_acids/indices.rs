@@ -1,6 +1,11 @@
 use crate::{
-    app::states::fatty_acids::settings::{Filter, Index, Indices, Settings, StereospecificNumbers},
-    r#const::{MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
+    app::{
+        computers::{self, fatty_acids::detection, intervals},
+        states::fatty_acids::settings::{
+            Bootstrap, Dispersion, Filter, Indices, Settings, StereospecificNumbers,
+        },
+    },
+    r#const::{LOWER, MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD, UPPER},
     utils::{HashedDataFrame, polars::eval_arr},
 };
 use egui::util::cache::{ComputerMut, FrameCache};
@@ -22,11 +27,25 @@ impl Computer {
     #[instrument(skip(self), err)]
     fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
-        lazy_frame = unnest(lazy_frame, key);
-        lazy_frame = filter(lazy_frame, key)?;
-        lazy_frame = compute(lazy_frame, key)?;
-        let data_frame = lazy_frame.collect()?;
-        Ok(data_frame)
+        lazy_frame = unnest(lazy_frame, key.stereospecific_numbers);
+        lazy_frame = filter(lazy_frame, key.frame, key.filter)?;
+        let indices = key
+            .indices
+            .iter()
+            .filter_map(|index| index.visible.then_some(index.name.as_str()))
+            .collect::<Vec<_>>();
+        lazy_frame = compute(lazy_frame, key.frame, INDEX, &indices, |index, sample| {
+            let array = eval_arr(sample, |expr| compute_index(index, expr))?;
+            Ok(summary(array, key.dispersion, key.ddof).to_vec())
+        })?;
+        format(
+            lazy_frame,
+            INDEX,
+            &[],
+            key.bootstrap,
+            key.precision,
+            key.significant,
+        )
     }
 }
 
@@ -40,7 +59,9 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash)]
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
     pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     pub(crate) filter: Filter,
     pub(crate) indices: &'a Indices,
     pub(crate) precision: usize,
@@ -53,7 +74,9 @@ impl<'a> Key<'a> {
     pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
-            ddof: 1,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
             filter: settings.filter,
             indices: &settings.indices,
             precision: settings.precision,
@@ -68,110 +91,56 @@ impl<'a> Key<'a> {
 type Value = DataFrame;
 
 /// Unnest
-fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+pub(super) fn unnest(
+    lazy_frame: LazyFrame,
+    stereospecific_numbers: StereospecificNumbers,
+) -> LazyFrame {
     lazy_frame.with_columns([all()
         .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
         .as_expr()
         .struct_()
-        .field_by_name(key.stereospecific_numbers.id())
+        .field_by_name(stereospecific_numbers.id())
         .name()
         .keep()])
 }
 
 /// Filter
-fn filter(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let expr = all().exclude_cols([LABEL, FATTY_ACID, THRESHOLD]).as_expr();
-    Ok(lazy_frame.filter(match key.filter {
-        Filter::Intersection => all_horizontal([expr.is_not_null()])?,
-        Filter::Union => any_horizontal([expr.is_not_null()])?,
-        Filter::Difference => any_horizontal([expr.is_null()])?,
-    }))
+pub(super) fn filter(
+    lazy_frame: LazyFrame,
+    frame: &HashedDataFrame,
+    filter: Filter,
+) -> PolarsResult<LazyFrame> {
+    Ok(lazy_frame.filter(detection(&frame.data_frame, filter)?))
 }
 
 /// Compute
-// fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-//     let mut lazy_frames = Vec::with_capacity(key.indices.len());
-//     for index in key.indices.iter().filter(|index| index.visible) {
-//         let mut exprs = vec![lit(Series::new(
-//             PlSmallStr::from_static(INDEX),
-//             [index.name.clone()],
-//         ))];
-//         for name in key
-//             .frame
-//             .schema()
-//             .iter_names()
-//             .filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID | THRESHOLD))
-//         {
-//             let array = eval_arr(col(name.clone()).struct_().field_by_name(SAMPLE), |expr| {
-//                 compute_index(&index.name, expr)
-//             })?;
-//             exprs.push(
-//                 as_struct(vec![
-//                     array
-//                         .clone()
-//                         .arr()
-//                         .mean()
-//                         .precision(key.precision, key.significant)
-//                         .alias(MEAN),
-//                     array
-//                         .clone()
-//                         .arr()
-//                         .std(key.ddof)
-//                         .precision(key.precision + 1, key.significant)
-//                         .alias(STANDARD_DEVIATION),
-//                     array
-//                         .arr()
-//                         .eval(element().precision(key.precision, key.significant), false)
-//                         .alias(SAMPLE),
-//                 ])
-//                 .alias(name.clone()),
-//             );
-//         }
-//         lazy_frames.push(lazy_frame.clone().select(exprs))
-//     }
-//     concat(lazy_frames, Default::default())
-// }
-fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+///
+/// The rows are named in the column, every sample is the struct of the fields
+/// of the row computed on the replicate array of the sample.
+pub(super) fn compute<'a>(
+    lazy_frame: LazyFrame,
+    frame: &HashedDataFrame,
+    column: &'static str,
+    rows: &[&'a str],
+    fields: impl Fn(&'a str, Expr) -> PolarsResult<Vec<Expr>>,
+) -> PolarsResult<LazyFrame> {
     // Names
-    let mut exprs =
-        vec![lit(Series::from_iter(key.indices.iter().filter_map(
-            |index| index.visible.then_some(index.name.as_str()),
-        ))
-        .with_name(PlSmallStr::from_static(INDEX)))];
+    let mut exprs = vec![lit(
+        Series::from_iter(rows.iter().copied()).with_name(PlSmallStr::from_static(column))
+    )];
     // Values
-    for name in key
-        .frame
+    for name in frame
         .schema()
         .iter_names()
         .filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID | THRESHOLD))
     {
         let expr = concat_arr(
-            key.indices
-                .iter()
-                .filter(|index| index.visible)
-                .map(|index| {
-                    let array =
-                        eval_arr(col(name.clone()).struct_().field_by_name(SAMPLE), |expr| {
-                            compute_index(index, expr)
-                        })?;
-                    Ok(as_struct(vec![
-                        array
-                            .clone()
-                            .arr()
-                            .mean()
-                            .precision(key.precision, key.significant)
-                            .alias(MEAN),
-                        array
-                            .clone()
-                            .arr()
-                            .std(key.ddof)
-                            .precision(key.precision + 1, key.significant)
-                            .alias(STANDARD_DEVIATION),
-                        array
-                            .arr()
-                            .eval(element().precision(key.precision, key.significant), false)
-                            .alias(SAMPLE),
-                    ]))
+            rows.iter()
+                .map(|&row| {
+                    Ok(as_struct(fields(
+                        row,
+                        col(name.clone()).struct_().field_by_name(SAMPLE),
+                    )?))
                 })
                 .collect::<PolarsResult<_>>()?,
         )?
@@ -185,8 +154,62 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     Ok(lazy_frame.select(exprs))
 }
 
-fn compute_index(index: &Index, expr: Expr) -> Expr {
-    match &*index.name {
+/// Summary of the replicate array: the mean, the dispersion and the replicates
+pub(super) fn summary(array: Expr, dispersion: Dispersion, ddof: u8) -> [Expr; 3] {
+    [
+        array.clone().arr().mean().alias(MEAN),
+        computers::dispersion(array.clone(), dispersion, ddof).alias(STANDARD_DEVIATION),
+        array.alias(SAMPLE),
+    ]
+}
+
+/// Format
+///
+/// The samples gain the bootstrap intervals. The mean, the dispersion, the
+/// replicates, the interval bounds and the other fields of every sample are
+/// rounded.
+pub(super) fn format(
+    lazy_frame: LazyFrame,
+    column: &str,
+    fields: &[&str],
+    bootstrap: &Bootstrap,
+    precision: usize,
+    significant: bool,
+) -> PolarsResult<DataFrame> {
+    let mut data_frame = lazy_frame.collect()?;
+    let names = data_frame
+        .get_column_names_owned()
+        .into_iter()
+        .filter(|name| name != column)
+        .collect::<Vec<_>>();
+    let names = names.iter().map(PlSmallStr::as_str).collect::<Vec<_>>();
+    intervals(&mut data_frame, &names, bootstrap)?;
+    let exprs = names
+        .iter()
+        .map(|&name| {
+            let field = |field| col(name).struct_().field_by_name(field);
+            let mut exprs = vec![
+                field(MEAN).precision(precision, significant),
+                field(STANDARD_DEVIATION).precision(precision + 1, significant),
+                field(SAMPLE)
+                    .arr()
+                    .eval(element().precision(precision, significant), false),
+                field(LOWER).precision(precision, significant),
+                field(UPPER).precision(precision, significant),
+            ];
+            exprs.extend(
+                fields
+                    .iter()
+                    .map(|&name| field(name).precision(precision, significant)),
+            );
+            as_struct(exprs).alias(name)
+        })
+        .collect::<Vec<_>>();
+    data_frame.lazy().with_columns(exprs).collect()
+}
+
+fn compute_index(index: &str, expr: Expr) -> Expr {
+    match index {
         "Saturated" => col(FATTY_ACID).fatty_acid().saturated(expr),
         "Monounsaturated" => col(FATTY_ACID).fatty_acid().monounsaturated(expr),
         "Polyunsaturated" => col(FATTY_ACID).fatty_acid().polyunsaturated(expr),
@@ -224,6 +247,89 @@ fn compute_index(index: &Index, expr: Expr) -> Expr {
             .fatty_acid()
             .polyunsaturated_to_saturated(expr),
         "UnsaturationIndex" => col(FATTY_ACID).fatty_acid().unsaturation_index(expr),
+        // Desaturase and elongase activities: product to precursor
+        "StearoylCoenzymeADesaturase16" => ratio(
+            species(expr.clone(), 16, 1, None),
+            species(expr, 16, 0, None),
+        ),
+        "StearoylCoenzymeADesaturase18" => ratio(
+            species(expr.clone(), 18, 1, None),
+            species(expr, 18, 0, None),
+        ),
+        "Delta12Desaturase" => ratio(
+            species(expr.clone(), 18, 2, NonZeroI8::new(-6)),
+            species(expr, 18, 1, NonZeroI8::new(-9)),
+        ),
+        "Delta15Desaturase" => ratio(
+            species(expr.clone(), 18, 3, NonZeroI8::new(-3)),
+            species(expr, 18, 2, NonZeroI8::new(-6)),
+        ),
+        "Delta6Desaturase" => ratio(
+            species(expr.clone(), 18, 3, NonZeroI8::new(-6)),
+            species(expr, 18, 2, NonZeroI8::new(-6)),
+        ),
+        "Delta5Desaturase" => ratio(
+            species(expr.clone(), 20, 4, NonZeroI8::new(-6)),
+            species(expr, 20, 3, NonZeroI8::new(-6)),
+        ),
+        "Elongase" => ratio(
+            species(expr.clone(), 18, 0, None),
+            species(expr, 16, 0, None),
+        ),
+        // Chain length and unsaturation
+        "AverageChainLength" => ratio(
+            (col(FATTY_ACID)
+                .fatty_acid()
+                .carbon()
+                .cast(DataType::Float64)
+                * expr.clone())
+            .sum(),
+            expr.sum(),
+        ),
+        "DoubleBondIndex" => ratio(
+            (col(FATTY_ACID)
+                .fatty_acid()
+                .unsaturation()
+                .cast(DataType::Float64)
+                * expr.clone())
+            .sum(),
+            expr.sum(),
+        ),
+        "PeroxidizabilityIndex" => {
+            // Monoenoic 0.025, dienoic 1, trienoic 2, tetraenoic 4,
+            // pentaenoic 6, hexaenoic 8
+            let unsaturation = col(FATTY_ACID).fatty_acid().unsaturation();
+            let weight = when(unsaturation.clone().eq(lit(1)))
+                .then(lit(0.025))
+                .when(unsaturation.clone().eq(lit(2)))
+                .then(lit(1.0))
+                .when(unsaturation.clone().gt(lit(2)))
+                .then((unsaturation.cast(DataType::Float64) - lit(2.0)) * lit(2.0))
+                .otherwise(lit(0.0));
+            (weight * expr).sum()
+        }
         _ => unreachable!(),
     }
 }
+
+/// Ratio of the numerator to the denominator, null for the zero denominator
+fn ratio(numerator: Expr, denominator: Expr) -> Expr {
+    when(denominator.clone().neq(lit(0)))
+        .then(numerator / denominator)
+        .otherwise(lit(NULL).cast(DataType::Float64))
+}
+
+/// Sum of the species with the carbon and the double bond numbers, of the
+/// omega family if any
+pub(super) fn species(expr: Expr, carbon: u8, unsaturation: u8, omega: Option<NonZeroI8>) -> Expr {
+    let mut predicate = col(FATTY_ACID).fatty_acid().carbon().eq(lit(carbon)).and(
+        col(FATTY_ACID)
+            .fatty_acid()
+            .unsaturation()
+            .eq(lit(unsaturation)),
+    );
+    if omega.is_some() {
+        predicate = predicate.and(col(FATTY_ACID).fatty_acid().is_unsaturated(omega));
+    }
+    expr.filter(predicate).sum()
+}
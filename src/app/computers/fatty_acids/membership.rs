@@ -0,0 +1,74 @@
+use super::presence;
+use crate::{
+    app::{
+        computers::intersections,
+        states::fatty_acids::settings::{Settings, StereospecificNumbers},
+    },
+    r#const::THRESHOLD,
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use ordered_float::OrderedFloat;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Membership computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Membership computer
+///
+/// Counts the fatty acids shared by every combination of the samples.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let names = key
+            .frame
+            .data_frame
+            .schema()
+            .iter_names_cloned()
+            .filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let lazy_frame = key.frame.data_frame.clone().lazy().with_columns([all()
+            .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
+            .as_expr()
+            .struct_()
+            .field_by_name(key.stereospecific_numbers.id())
+            .name()
+            .keep()]);
+        intersections(lazy_frame, &names, |name| presence(name, key.threshold)).collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Membership key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) threshold: OrderedFloat<f64>,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+        Self {
+            frame,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            threshold: settings.threshold.auto,
+        }
+    }
+}
+
+/// Membership value
+type Value = DataFrame;
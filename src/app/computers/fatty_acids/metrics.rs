@@ -1,6 +1,11 @@
 use crate::{
-    app::states::fatty_acids::settings::{Filter, Metric, Settings, StereospecificNumbers},
-    r#const::{MEAN, THRESHOLD},
+    app::{
+        computers::{bounds, fatty_acids::detection, means, metric_intervals, transform},
+        states::fatty_acids::settings::{
+            Bootstrap, Filter, Metric, Settings, StereospecificNumbers, Transformation,
+        },
+    },
+    r#const::{LOWER, THRESHOLD, UPPER, VALUE},
     utils::HashedDataFrame,
 };
 use egui::util::cache::{ComputerMut, FrameCache};
@@ -24,10 +29,15 @@ impl Computer {
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
         lazy_frame = unnest(lazy_frame, key);
         lazy_frame = filter(lazy_frame, key)?;
+        let data_frame = lazy_frame.collect()?;
+        let intervals = intervals(&data_frame, key)?;
+        lazy_frame = mean(data_frame, key)?.lazy();
         // println!("Metrics 0: {}", lazy_frame.clone().collect().unwrap());
         lazy_frame = compute(lazy_frame, key)?;
         // println!("Metrics 1: {}", lazy_frame.clone().collect().unwrap());
-        lazy_frame.collect()
+        let mut data_frame = lazy_frame.collect()?;
+        bounds(&mut data_frame, &intervals)?;
+        format(data_frame.lazy(), key)?.collect()
     }
 }
 
@@ -41,6 +51,7 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash)]
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
     pub(crate) ddof: u8,
     pub(crate) filter: Filter,
     pub(crate) metric: Metric,
@@ -48,19 +59,22 @@ pub(crate) struct Key<'a> {
     pub(crate) significant: bool,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
     pub(crate) threshold: OrderedFloat<f64>,
+    pub(crate) transformation: &'a Transformation,
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
-            ddof: 1,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
             filter: settings.filter,
             metric: settings.metric,
             precision: settings.precision,
             significant: settings.significant,
             stereospecific_numbers: settings.stereospecific_numbers,
             threshold: settings.threshold.auto,
+            transformation: &settings.transformation,
         }
     }
 }
@@ -81,12 +95,55 @@ fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
 
 /// Filter
 fn filter(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let expr = all().exclude_cols([LABEL, FATTY_ACID, THRESHOLD]).as_expr();
-    Ok(lazy_frame.filter(match key.filter {
-        Filter::Intersection => all_horizontal([expr.is_not_null()])?,
-        Filter::Union => any_horizontal([expr.is_not_null()])?,
-        Filter::Difference => any_horizontal([expr.is_null()])?,
-    }))
+    Ok(lazy_frame.filter(detection(&key.frame.data_frame, key.filter)?))
+}
+
+/// Intervals
+///
+/// The bootstrap intervals of the metric between the samples.
+fn intervals(data_frame: &DataFrame, key: Key) -> PolarsResult<Vec<Vec<Option<[f64; 2]>>>> {
+    if !key.bootstrap.enabled {
+        return Ok(Vec::new());
+    }
+    let names = data_frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+        .collect::<Vec<_>>();
+    metric_intervals(
+        data_frame,
+        &names,
+        data_frame[LABEL].str()?,
+        key.transformation,
+        key.metric,
+        key.bootstrap,
+    )
+}
+
+/// Mean
+///
+/// The transformed sample means, the absent values are zero.
+fn mean(data_frame: DataFrame, key: Key) -> PolarsResult<DataFrame> {
+    let names = data_frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+        .collect::<Vec<_>>();
+    let mut means = means(&data_frame, &names)?;
+    transform(
+        &mut means,
+        data_frame[LABEL].str()?,
+        key.transformation,
+        key.metric,
+    );
+    let columns = names
+        .into_iter()
+        .zip(means)
+        .map(|(name, means)| Column::new(name.into(), means))
+        .collect::<Vec<_>>();
+    let mut output = data_frame.select([LABEL])?;
+    output.hstack_mut(&columns)?;
+    Ok(output)
 }
 
 /// Compute
@@ -96,16 +153,8 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     for name in names.filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID | THRESHOLD)) {
         // Метрики сравниваем по среднему, потому как сравнивать повторности
         // пришлось бы попарно все пары.
-        let left = col(name.as_str())
-            .struct_()
-            .field_by_name(MEAN)
-            .fill_null(0);
-        let right = all()
-            .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
-            .as_expr()
-            .struct_()
-            .field_by_name(MEAN)
-            .fill_null(0);
+        let left = col(name.as_str());
+        let right = all().exclude_cols([LABEL]).as_expr();
         let metric = match key.metric {
             // Similarity between two discrete probability distributions
             Metric::HellingerDistance => hellinger_distance(left, right),
@@ -115,6 +164,8 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
             Metric::ChebyshevDistance => chebyshev_distance(left, right),
             Metric::EuclideanDistance => euclidean_distance(left, right),
             Metric::ManhattanDistance => manhattan_distance(left, right),
+            // Distance between two compositions
+            Metric::AitchisonDistance => euclidean_distance(left, right),
             // Distance between two series
             Metric::CosineDistance => cosine_distance(left, right),
             Metric::JaccardDistance => jaccard_distance(left, right),
@@ -134,6 +185,26 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     Ok(lazy_frame)
 }
 
+/// Format
+///
+/// The bootstrap interval bounds are rounded as the metric.
+fn format(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    let schema = lazy_frame.collect_schema()?;
+    let exprs = schema
+        .iter_names()
+        .map(|name| {
+            let field = |field| col(name.clone()).struct_().field_by_name(field);
+            as_struct(vec![
+                field(VALUE),
+                field(LOWER).precision(key.precision, key.significant),
+                field(UPPER).precision(key.precision, key.significant),
+            ])
+            .alias(name.clone())
+        })
+        .collect::<Vec<_>>();
+    Ok(lazy_frame.with_columns(exprs))
+}
+
 // fn hierarchical_cluster(data_frame: DataFrame) {
 // use linfa::{Dataset, DatasetBase, dataset::Records, traits::Transformer as _};
 // use linfa_hierarchical::HierarchicalCluster;
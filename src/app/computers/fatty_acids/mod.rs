@@ -1,11 +1,22 @@
 use crate::{
-    app::states::fatty_acids::settings::{Settings, Sort, StereospecificNumbers, Threshold},
-    r#const::{MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
-    utils::{HashedDataFrame, HashedMetaDataFrame},
+    app::{
+        computers::{self, dispersion, group},
+        states::fatty_acids::settings::{
+            Detection, Dispersion, Filter, Groups, Settings, Sort, StereospecificNumbers,
+            Substitution, Threshold,
+        },
+    },
+    r#const::{
+        LIMIT, LIMIT_OF_DETECTION, LIMIT_OF_QUANTIFICATION, MEAN, SAMPLE, STANDARD_DEVIATION,
+        THRESHOLD,
+    },
+    utils::{HashedDataFrame, HashedMetaDataFrame, polars::eval_arr},
 };
 use egui::util::cache::{ComputerMut, FrameCache};
 use lipid::prelude::*;
+use ordered_float::OrderedFloat;
 use polars::prelude::*;
+use std::f64::consts::FRAC_1_SQRT_2;
 use tracing::instrument;
 
 /// Fatty acids computed
@@ -28,7 +39,8 @@ impl Computer {
             return Ok(HashedDataFrame::EMPTY);
         }
         let mut lazy_frame = join(key)?;
-        lazy_frame = values(lazy_frame)?;
+        lazy_frame = values(lazy_frame, key)?;
+        lazy_frame = groups(lazy_frame, key)?;
         lazy_frame = threshold(lazy_frame, key)?;
         lazy_frame = sort(lazy_frame, key);
         let data_frame = lazy_frame.collect()?;
@@ -46,6 +58,10 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash)]
 pub(crate) struct Key<'a> {
     pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) ddof: u8,
+    pub(crate) detection: &'a Detection,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) groups: &'a Groups,
     pub(crate) sort: Option<Sort>,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
     pub(crate) threshold: &'a Threshold,
@@ -55,6 +71,10 @@ impl<'a> Key<'a> {
     pub(crate) fn new(frames: &'a [HashedMetaDataFrame], settings: &'a Settings) -> Self {
         Self {
             frames,
+            ddof: settings.ddof,
+            detection: &settings.detection,
+            dispersion: settings.dispersion,
+            groups: &settings.groups,
             sort: settings.sort,
             stereospecific_numbers: settings.stereospecific_numbers,
             threshold: &settings.threshold,
@@ -96,42 +116,97 @@ fn join(key: Key) -> PolarsResult<LazyFrame> {
 }
 
 /// Values
-fn values(mut lazy_frame: LazyFrame) -> PolarsResult<LazyFrame> {
+///
+/// The replicate values below the limit of detection are substituted, the
+/// [`LIMIT`] is the limit the mean of the measured values is below. Without
+/// the limit of detection the zero mean is absent.
+fn values(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     let schema = lazy_frame.collect_schema()?;
     let exprs = schema
         .iter_names()
         .filter(|name| !matches!(name.as_str(), LABEL | FATTY_ACID))
-        .map(|name| {
-            let field = |stereospecific_numbers: &str| {
-                let expr = col(name.as_str())
+        .map(|name| -> PolarsResult<_> {
+            let limits = key.detection.limits(name);
+            let detection = limits.detection.0;
+            let quantification = limits.quantification.0;
+            let substitute = match key.detection.substitution {
+                Substitution::Zero => lit(0.0),
+                Substitution::HalfLimit => lit(detection / 2.0),
+                Substitution::RootTwoLimit => lit(detection * FRAC_1_SQRT_2),
+                Substitution::Exclusion => lit(NULL).cast(DataType::Float64),
+            };
+            let field = |stereospecific_numbers: &str| -> PolarsResult<Expr> {
+                let measured = col(name.as_str())
                     .struct_()
                     .field_by_name(stereospecific_numbers);
+                let measured_mean = measured.clone().arr().mean();
+                let (expr, present) = if detection > 0.0 {
+                    (
+                        eval_arr(measured.clone(), |value| {
+                            when(value.clone().lt(lit(detection)))
+                                .then(substitute.clone())
+                                .otherwise(value)
+                        })?,
+                        measured.is_not_null(),
+                    )
+                } else {
+                    (measured, measured_mean.clone().neq(0))
+                };
                 let mean = expr.clone().arr().mean();
-                // TODO: DDOF
-                let standard_deviation = expr.clone().arr().std(1);
-                ternary_expr(
-                    mean.clone().neq(0),
+                let standard_deviation = dispersion(expr.clone(), key.dispersion, key.ddof);
+                let limit = when(measured_mean.clone().lt(lit(detection)))
+                    .then(lit(LIMIT_OF_DETECTION))
+                    .when(measured_mean.lt(lit(quantification)))
+                    .then(lit(LIMIT_OF_QUANTIFICATION))
+                    .otherwise(lit(NULL).cast(DataType::String));
+                Ok(ternary_expr(
+                    present,
                     as_struct(vec![
                         mean.alias(MEAN),
                         standard_deviation.alias(STANDARD_DEVIATION),
                         expr.alias(SAMPLE),
+                        limit.alias(LIMIT),
                     ]),
                     lit(NULL),
                 )
-                .alias(stereospecific_numbers)
+                .alias(stereospecific_numbers))
             };
-            as_struct(vec![
-                field(STEREOSPECIFIC_NUMBERS123),
-                field(STEREOSPECIFIC_NUMBERS13),
-                field(STEREOSPECIFIC_NUMBERS2),
+            Ok(as_struct(vec![
+                field(STEREOSPECIFIC_NUMBERS123)?,
+                field(STEREOSPECIFIC_NUMBERS13)?,
+                field(STEREOSPECIFIC_NUMBERS2)?,
             ])
-            .alias(name.clone())
+            .alias(name.clone()))
         })
-        .collect::<Vec<_>>();
+        .collect::<PolarsResult<Vec<_>>>()?;
     lazy_frame = lazy_frame.with_columns(exprs);
     Ok(lazy_frame)
 }
 
+/// Groups
+fn groups(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    computers::groups(lazy_frame, &[LABEL, FATTY_ACID], key.groups, |members| {
+        let field = |stereospecific_numbers: &str| -> PolarsResult<Expr> {
+            let means = members
+                .iter()
+                .map(|&member| {
+                    col(member)
+                        .struct_()
+                        .field_by_name(stereospecific_numbers)
+                        .struct_()
+                        .field_by_name(MEAN)
+                })
+                .collect();
+            Ok(group(means, key.dispersion, key.ddof)?.alias(stereospecific_numbers))
+        };
+        Ok(as_struct(vec![
+            field(STEREOSPECIFIC_NUMBERS123)?,
+            field(STEREOSPECIFIC_NUMBERS13)?,
+            field(STEREOSPECIFIC_NUMBERS2)?,
+        ]))
+    })
+}
+
 /// Threshold
 fn threshold(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     // Значение в одном или более столбцах больше threshold,
@@ -219,7 +294,58 @@ fn sort(mut lazy_frame: LazyFrame, key: Key) -> LazyFrame {
 //     Ok(lazy_frame)
 // }
 
+/// Presence of the unnested sample: detected with the mean above the
+/// threshold
+pub(crate) fn presence(name: &str, threshold: OrderedFloat<f64>) -> Expr {
+    detected(name).and(
+        col(name)
+            .struct_()
+            .field_by_name(MEAN)
+            .fill_null(0)
+            .gt(threshold.0),
+    )
+}
+
+/// Detection of the unnested sample: present and not below the limit of
+/// detection
+pub(crate) fn detected(name: &str) -> Expr {
+    col(name).is_not_null().and(
+        col(name)
+            .struct_()
+            .field_by_name(LIMIT)
+            .neq_missing(lit(LIMIT_OF_DETECTION)),
+    )
+}
+
+/// Filter predicate of the unnested samples by the detection
+pub(crate) fn detection(data_frame: &DataFrame, filter: Filter) -> PolarsResult<Expr> {
+    let detected = data_frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+        .map(detected)
+        .collect::<Vec<_>>();
+    match filter {
+        Filter::Intersection => all_horizontal(detected),
+        Filter::Union => any_horizontal(detected),
+        Filter::Difference => {
+            any_horizontal(detected.into_iter().map(Expr::not).collect::<Vec<_>>())
+        }
+    }
+}
+
+pub(crate) mod biodiesel;
+pub(crate) mod clipping;
+pub(crate) mod clusters;
+pub(crate) mod comparison;
+pub(crate) mod diversity;
 pub(crate) mod factors;
 pub(crate) mod indices;
+pub(crate) mod membership;
 pub(crate) mod metrics;
+pub(crate) mod nutrition;
+pub(crate) mod ordination;
+pub(crate) mod prediction;
+pub(crate) mod properties;
+pub(crate) mod significance;
 pub(crate) mod table;
@@ -0,0 +1,173 @@
+use super::indices::{compute, filter, format, summary, unnest};
+use crate::{
+    app::states::fatty_acids::settings::{
+        Basis, Bootstrap, Dispersion, Filter, Nutrition, Settings, StereospecificNumbers,
+    },
+    r#const::{PROPERTY, REFERENCE_INTAKE},
+    utils::{HashedDataFrame, polars::eval_arr},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use ordered_float::OrderedFloat;
+use polars::prelude::*;
+use std::num::NonZeroI8;
+use tracing::instrument;
+
+/// Nutrients
+///
+/// The nutrients with the reference intake of the adult, grams per day
+/// (Regulation (EU) No 1169/2011, Annex XIII).
+pub(crate) const NUTRIENTS: [(&str, Option<f64>); 9] = [
+    ("Fat", Some(70.0)),
+    ("FattyAcids", None),
+    ("Saturated", Some(20.0)),
+    ("Monounsaturated", None),
+    ("Polyunsaturated", None),
+    ("Trans", None),
+    ("Unsaturated-3", None),
+    ("Unsaturated-6", None),
+    ("Polyunsaturated-6ToPolyunsaturated-3", None),
+];
+
+/// Methylene of the methyl ester: CH₃ - H
+const METHYLENE: f64 = 14.027;
+
+/// Nutrition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Nutrition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key.stereospecific_numbers);
+        lazy_frame = filter(lazy_frame, key.frame, key.filter)?;
+        lazy_frame = compute(
+            lazy_frame,
+            key.frame,
+            PROPERTY,
+            &NUTRIENTS.map(|(nutrient, _)| nutrient),
+            |nutrient, sample| {
+                let array = eval_arr(sample, |expr| {
+                    compute_nutrient(nutrient, expr, key.nutrition)
+                })?;
+                let reference_intake = match reference_intake(nutrient) {
+                    Some(reference_intake) => {
+                        array.clone().arr().mean() / lit(reference_intake) * lit(100.0)
+                    }
+                    None => lit(NULL).cast(DataType::Float64),
+                };
+                let mut fields = summary(array, key.dispersion, key.ddof).to_vec();
+                fields.push(reference_intake.alias(REFERENCE_INTAKE));
+                Ok(fields)
+            },
+        )?;
+        format(
+            lazy_frame,
+            PROPERTY,
+            &[REFERENCE_INTAKE],
+            key.bootstrap,
+            key.precision,
+            key.significant,
+        )
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Nutrition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) filter: Filter,
+    pub(crate) nutrition: Nutrition,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) threshold: OrderedFloat<f64>,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            filter: settings.filter,
+            nutrition: settings.nutrition,
+            precision: settings.precision,
+            significant: settings.significant,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            threshold: settings.threshold.auto,
+        }
+    }
+}
+
+/// Nutrition value
+type Value = DataFrame;
+
+/// Reference intake of the nutrient, none if the regulation does not specify
+/// it
+fn reference_intake(nutrient: &str) -> Option<f64> {
+    NUTRIENTS
+        .into_iter()
+        .find(|&(name, _)| name == nutrient)
+        .and_then(|(_, reference_intake)| reference_intake)
+}
+
+/// Compute nutrient
+///
+/// Grams per 100 grams of the food, the ratio is dimensionless.
+fn compute_nutrient(nutrient: &str, expr: Expr, nutrition: Nutrition) -> Expr {
+    let fatty_acids = lit(nutrition.fat.0 * nutrition.conversion.0);
+    let grams = fatty_acids * mass_fraction(expr, nutrition);
+    match nutrient {
+        "Fat" => lit(nutrition.fat.0),
+        "FattyAcids" => grams.sum(),
+        "Saturated" => col(FATTY_ACID).fatty_acid().saturated(grams),
+        "Monounsaturated" => col(FATTY_ACID).fatty_acid().monounsaturated(grams),
+        "Polyunsaturated" => col(FATTY_ACID).fatty_acid().polyunsaturated(grams),
+        "Trans" => col(FATTY_ACID).fatty_acid().trans(grams),
+        "Unsaturated-3" => col(FATTY_ACID)
+            .fatty_acid()
+            .unsaturated(grams, NonZeroI8::new(-3)),
+        "Unsaturated-6" => col(FATTY_ACID)
+            .fatty_acid()
+            .unsaturated(grams, NonZeroI8::new(-6)),
+        "Polyunsaturated-6ToPolyunsaturated-3" => col(FATTY_ACID)
+            .fatty_acid()
+            .polyunsaturated_6_to_polyunsaturated_3(grams),
+        _ => unreachable!(),
+    }
+}
+
+/// Mass fractions of the fatty acids
+///
+/// The mole fractions are weighted by the masses of the fatty acids, the mass
+/// fractions of the methyl esters are converted by the FAME-to-fatty-acid
+/// factors Mᵢ(FA) / Mᵢ(FAME).
+fn mass_fraction(expr: Expr, nutrition: Nutrition) -> Expr {
+    let fatty_acid = col(FATTY_ACID)
+        .fatty_acid()
+        .relative_atomic_mass(None)
+        .cast(DataType::Float64);
+    let mass = match nutrition.basis {
+        Basis::MoleFractions => expr * fatty_acid,
+        Basis::FattyAcidMassFractions => expr,
+        Basis::MethylEsterMassFractions => {
+            expr * fatty_acid.clone() / (fatty_acid + lit(METHYLENE))
+        }
+    };
+    mass.clone() / mass.sum()
+}
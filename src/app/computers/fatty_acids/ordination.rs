@@ -0,0 +1,106 @@
+use crate::{
+    app::{
+        computers::{fatty_acids::detection, observations, ordination, transform},
+        states::fatty_acids::settings::{
+            Filter, Groups, Metric, Ordination, Settings, StereospecificNumbers, Transformation,
+        },
+    },
+    r#const::THRESHOLD,
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Ordination computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Ordination computer
+///
+/// Places the replicates in two dimensions with PCoA or NMDS of the
+/// replicate-level distance matrix of the fatty acid profiles.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key);
+        lazy_frame = filter(lazy_frame, key)?;
+        let data_frame = lazy_frame.collect()?;
+        let names = data_frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let mut observations = observations(&data_frame, &names)?;
+        transform(
+            observations
+                .iter_mut()
+                .map(|observation| &mut observation.values),
+            data_frame[LABEL].str()?,
+            key.transformation,
+            key.metric,
+        );
+        ordination(&observations, key.groups, key.metric, key.ordination)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Ordination key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) filter: Filter,
+    pub(crate) groups: &'a Groups,
+    pub(crate) metric: Metric,
+    pub(crate) ordination: Ordination,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) transformation: &'a Transformation,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            filter: settings.filter,
+            groups: &settings.groups,
+            metric: settings.metric,
+            ordination: settings.ordination,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            transformation: &settings.transformation,
+        }
+    }
+}
+
+/// Ordination value
+///
+/// Rows are the replicates: the sample, the group, the replicate and the
+/// coordinates.
+type Value = DataFrame;
+
+/// Unnest
+fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    lazy_frame.with_columns([all()
+        .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
+        .as_expr()
+        .struct_()
+        .field_by_name(key.stereospecific_numbers.id())
+        .name()
+        .keep()])
+}
+
+/// Filter
+fn filter(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    Ok(lazy_frame.filter(detection(&key.frame.data_frame, key.filter)?))
+}
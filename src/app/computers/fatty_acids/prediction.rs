@@ -0,0 +1,154 @@
+use crate::{app::states::fatty_acids::settings::Model, r#const::VALUE, utils::HashedDataFrame};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Prediction computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Prediction computer
+///
+/// Predicts the triacylglycerol species composition from the fatty acid
+/// composition.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+// From CACLULATION:
+// ┌───────┬───────────┬──────────────────────────┬─────────────────────────┬────────────────────────┐
+// │ Label ┆ FattyAcid ┆ StereospecificNumbers123 ┆ StereospecificNumbers13 ┆ StereospecificNumbers2 │
+// ┆ ---   ┆ ---       ┆ ---                      ┆ ---                     ┆ ---                    │
+// │ str   ┆ struct[2] ┆ array[f64, n]            ┆ array[f64, n]           ┆ array[f64, n]          │
+// ╞═══════╪═══════════╪══════════════════════════╪═════════════════════════╪════════════════════════╡
+// To COMPOSITION (the values are the mole fractions):
+// ┌───────────┬─────────────────┬───────────────┐
+// │ Label     ┆ Triacylglycerol ┆ Value         │
+// ┆ ---       ┆ ---             ┆ ---           │
+// │ struct[3] ┆ struct[3]       ┆ array[f64, n] │
+// ╞═══════════╪═════════════════╪═══════════════╡
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(HashedDataFrame::EMPTY);
+        }
+        let lazy_frame = compute(key)?;
+        let data_frame = lazy_frame.collect()?;
+        HashedDataFrame::new(data_frame)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Prediction key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) model: Model,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, model: Model) -> Self {
+        Self { frame, model }
+    }
+}
+
+/// Prediction value
+type Value = HashedDataFrame;
+
+pub(crate) fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let replicates = replicates(key.frame)?;
+    let stereospecific_numbers = [
+        STEREOSPECIFIC_NUMBERS1,
+        STEREOSPECIFIC_NUMBERS2,
+        STEREOSPECIFIC_NUMBERS3,
+    ];
+    // Position compositions, one column per replicate
+    let positions = |index: usize| -> Vec<Expr> {
+        let mut exprs = vec![
+            col(LABEL).alias(format!("{LABEL}{index}")),
+            col(FATTY_ACID).alias(format!("{FATTY_ACID}{index}")),
+        ];
+        for replicate in 0..replicates {
+            exprs.push(
+                position(key.model, index, replicate).alias(format!("{VALUE}{index}.{replicate}")),
+            );
+        }
+        exprs
+    };
+    let lazy_frame = key.frame.data_frame.clone().lazy();
+    let mut product = lazy_frame.clone().select(positions(0));
+    for index in 1..stereospecific_numbers.len() {
+        product = product.cross_join(lazy_frame.clone().select(positions(index)), None);
+    }
+    // Replicates are propagated: the species value of each replicate is the
+    // product of the position values of the same replicate
+    let values = (0..replicates)
+        .map(|replicate| {
+            (0..stereospecific_numbers.len())
+                .map(|index| col(format!("{VALUE}{index}.{replicate}")))
+                .reduce(|left, right| left * right)
+                .unwrap()
+        })
+        .collect();
+    Ok(product
+        .select([
+            as_struct(
+                stereospecific_numbers
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| col(format!("{LABEL}{index}")).alias(*name))
+                    .collect(),
+            )
+            .alias(LABEL),
+            as_struct(
+                stereospecific_numbers
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| col(format!("{FATTY_ACID}{index}")).alias(*name))
+                    .collect(),
+            )
+            .alias(TRIACYLGLYCEROL),
+            concat_arr(values)?.alias(VALUE),
+        ])
+        .filter(col(VALUE).arr().sum().gt(0)))
+}
+
+/// Normalized composition of the position (`0` is SN-1, `1` is SN-2, `2` is
+/// SN-3) for the replicate
+fn position(model: Model, index: usize, replicate: usize) -> Expr {
+    let replicate = |name: &str| {
+        col(name)
+            .arr()
+            .get(lit(replicate as i64), true)
+            .fill_null(0)
+    };
+    let expr = match (model, index) {
+        (Model::OneTwoThreeRandom, _) => replicate(STEREOSPECIFIC_NUMBERS123),
+        (Model::OneThreeRandomTwoRandom | Model::OneThreeCalculatedTwoRandom, 1) => {
+            replicate(STEREOSPECIFIC_NUMBERS2)
+        }
+        (Model::OneThreeRandomTwoRandom, _) => replicate(STEREOSPECIFIC_NUMBERS13),
+        // SN-1,3 = (3 * SN-1,2,3 - SN-2) / 2
+        (Model::OneThreeCalculatedTwoRandom, _) => {
+            ((lit(3) * replicate(STEREOSPECIFIC_NUMBERS123) - replicate(STEREOSPECIFIC_NUMBERS2))
+                / lit(2))
+            .clip_min(lit(0))
+        }
+    };
+    expr.clone() / expr.sum()
+}
+
+/// Number of replicates
+pub(super) fn replicates(data_frame: &DataFrame) -> PolarsResult<usize> {
+    match data_frame.schema().get(STEREOSPECIFIC_NUMBERS123) {
+        Some(DataType::Array(_, width)) => Ok(*width),
+        data_type => polars_bail!(
+            SchemaMismatch: "expected `{STEREOSPECIFIC_NUMBERS123}` to be an array, got = `{data_type:?}`"
+        ),
+    }
+}
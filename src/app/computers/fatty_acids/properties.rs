@@ -0,0 +1,162 @@
+use super::indices::{compute, filter, format, species, summary, unnest};
+use crate::{
+    app::states::fatty_acids::settings::{
+        Bootstrap, Dispersion, Filter, Settings, StereospecificNumbers,
+    },
+    r#const::PROPERTY,
+    utils::{HashedDataFrame, polars::eval_arr},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use ordered_float::OrderedFloat;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Properties
+pub(crate) const PROPERTIES: [&str; 5] = [
+    "MeanMolecularWeight",
+    "SaponificationValue",
+    "IodineValue",
+    "CalculatedOxidizability",
+    "OxidativeStabilityIndex",
+];
+
+/// Glycerol backbone of the triacylglycerol: C₃H₈O₃ - 3·H₂O
+const GLYCEROL: f64 = 38.049;
+/// Relative molecular mass of the potassium hydroxide
+const POTASSIUM_HYDROXIDE: f64 = 56.106;
+/// Relative molecular mass of the iodine
+const IODINE: f64 = 253.809;
+
+/// Properties computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Properties computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key.stereospecific_numbers);
+        lazy_frame = filter(lazy_frame, key.frame, key.filter)?;
+        lazy_frame = compute(
+            lazy_frame,
+            key.frame,
+            PROPERTY,
+            &PROPERTIES,
+            |property, sample| {
+                let array = eval_arr(sample, |expr| compute_property(property, expr))?;
+                Ok(summary(array, key.dispersion, key.ddof).to_vec())
+            },
+        )?;
+        format(
+            lazy_frame,
+            PROPERTY,
+            &[],
+            key.bootstrap,
+            key.precision,
+            key.significant,
+        )
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Properties key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) bootstrap: &'a Bootstrap,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) filter: Filter,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) threshold: OrderedFloat<f64>,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            filter: settings.filter,
+            precision: settings.precision,
+            significant: settings.significant,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            threshold: settings.threshold.auto,
+        }
+    }
+}
+
+/// Properties value
+type Value = DataFrame;
+
+/// Compute property
+///
+/// The replicate values are the mole fractions of the fatty acids, the oil is
+/// taken as the triacylglycerols of the fatty acids.
+fn compute_property(property: &str, expr: Expr) -> Expr {
+    match property {
+        "MeanMolecularWeight" => mean_molecular_weight(expr),
+        // Milligrams of the potassium hydroxide per gram of the oil
+        "SaponificationValue" => {
+            lit(3.0 * POTASSIUM_HYDROXIDE * 1000.0) / triacylglycerol_molecular_weight(expr)
+        }
+        // Grams of the iodine per 100 grams of the oil
+        "IodineValue" => {
+            let double_bonds = (col(FATTY_ACID)
+                .fatty_acid()
+                .unsaturation()
+                .cast(DataType::Float64)
+                * expr.clone())
+            .sum()
+                / expr.clone().sum();
+            lit(3.0 * IODINE * 100.0) * double_bonds / triacylglycerol_molecular_weight(expr)
+        }
+        // Fatemi and Hammond (1980): (18:1 + 10.3·18:2 + 21.6·18:3) / 100
+        "CalculatedOxidizability" => {
+            (species(expr.clone(), 18, 1, None)
+                + lit(10.3) * species(expr.clone(), 18, 2, None)
+                + lit(21.6) * species(expr.clone(), 18, 3, None))
+                / expr.sum()
+        }
+        // Park et al. (2008): 117.9295 / X + 2.5905 hours, X is the mass
+        // percent of the 18:2 and 18:3
+        "OxidativeStabilityIndex" => {
+            let mass = expr * molecular_weight();
+            let percent = lit(100.0)
+                * (species(mass.clone(), 18, 2, None) + species(mass.clone(), 18, 3, None))
+                / mass.sum();
+            lit(117.9295) / percent + lit(2.5905)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Relative molecular mass of the fatty acid
+fn molecular_weight() -> Expr {
+    col(FATTY_ACID)
+        .fatty_acid()
+        .relative_atomic_mass(None)
+        .cast(DataType::Float64)
+}
+
+/// Mean relative molecular mass of the fatty acids: Σ(Mᵢ·xᵢ) / Σxᵢ
+fn mean_molecular_weight(expr: Expr) -> Expr {
+    (molecular_weight() * expr.clone()).sum() / expr.sum()
+}
+
+/// Mean relative molecular mass of the triacylglycerols: 3·M̄ + glycerol
+fn triacylglycerol_molecular_weight(expr: Expr) -> Expr {
+    lit(3.0) * mean_molecular_weight(expr) + lit(GLYCEROL)
+}
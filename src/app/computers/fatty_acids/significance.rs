@@ -0,0 +1,105 @@
+use crate::{
+    app::{
+        computers::{fatty_acids::detection, observations, significance, transform},
+        states::fatty_acids::settings::{
+            Filter, Groups, Metric, Permutations, Settings, StereospecificNumbers, Transformation,
+        },
+    },
+    r#const::THRESHOLD,
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Significance computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Significance computer
+///
+/// Tests the difference of the groups with PERMANOVA and ANOSIM on the
+/// replicate-level distance matrix of the fatty acid profiles.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = unnest(lazy_frame, key);
+        lazy_frame = filter(lazy_frame, key)?;
+        let data_frame = lazy_frame.collect()?;
+        let names = data_frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+            .collect::<Vec<_>>();
+        let mut observations = observations(&data_frame, &names)?;
+        transform(
+            observations
+                .iter_mut()
+                .map(|observation| &mut observation.values),
+            data_frame[LABEL].str()?,
+            key.transformation,
+            key.metric,
+        );
+        significance(&observations, key.groups, key.metric, key.permutations)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Significance key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) filter: Filter,
+    pub(crate) groups: &'a Groups,
+    pub(crate) metric: Metric,
+    pub(crate) permutations: Permutations,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) transformation: &'a Transformation,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            filter: settings.filter,
+            groups: &settings.groups,
+            metric: settings.metric,
+            permutations: settings.permutations,
+            stereospecific_numbers: settings.stereospecific_numbers,
+            transformation: &settings.transformation,
+        }
+    }
+}
+
+/// Significance value
+///
+/// Rows are the tests: the statistic, R² and the permutation p-value.
+type Value = DataFrame;
+
+/// Unnest
+fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    lazy_frame.with_columns([all()
+        .exclude_cols([LABEL, FATTY_ACID, THRESHOLD])
+        .as_expr()
+        .struct_()
+        .field_by_name(key.stereospecific_numbers.id())
+        .name()
+        .keep()])
+}
+
+/// Filter
+fn filter(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    Ok(lazy_frame.filter(detection(&key.frame.data_frame, key.filter)?))
+}
@@ -1,12 +1,21 @@
 use crate::{
-    app::states::fatty_acids::settings::{Filter, Settings, StereospecificNumbers},
-    r#const::{MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
-    utils::{HashedDataFrame, polars::eval_arr},
+    app::{
+        computers::{
+            self, TableFormat,
+            fatty_acids::{detection, presence},
+            membership,
+        },
+        states::fatty_acids::settings::{
+            Dispersion, Filter, Reference, Settings, StereospecificNumbers,
+        },
+    },
+    r#const::THRESHOLD,
+    utils::HashedDataFrame,
 };
 use egui::util::cache::{ComputerMut, FrameCache};
 use lipid::prelude::*;
+use ordered_float::OrderedFloat;
 use polars::prelude::*;
-use polars_ext::prelude::*;
 
 /// Table computed
 pub(crate) type Computed = FrameCache<Value, Computer>;
@@ -37,24 +46,31 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
     pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     pub(crate) filter: Filter,
+    pub(crate) membership: Option<u64>,
     pub(crate) percent: bool,
     pub(crate) precision: usize,
+    pub(crate) reference: &'a Reference,
     pub(crate) significant: bool,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
+    pub(crate) threshold: OrderedFloat<f64>,
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
-            // ddof: settings.ddof,
-            ddof: 1,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
             filter: settings.filter,
+            membership: settings.membership,
             percent: settings.percent,
             precision: settings.precision,
+            reference: &settings.reference,
             significant: settings.significant,
             stereospecific_numbers: settings.stereospecific_numbers,
+            threshold: settings.threshold.auto,
         }
     }
 }
@@ -75,83 +91,60 @@ fn unnest(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
 
 /// Filter
 fn filter(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let expr = all().exclude_cols([LABEL, FATTY_ACID, THRESHOLD]).as_expr();
-    lazy_frame = lazy_frame.filter(match key.filter {
-        Filter::Intersection => {
-            // Значения отличные от нуля присутствуют во всех столбцах (AND)
-            all_horizontal([expr.is_not_null()])?
-        }
-        Filter::Union => {
-            // Значения отличные от нуля присутствуют в одном или более столбцах (OR)
-            any_horizontal([expr.is_not_null()])?
-        }
-        Filter::Difference => {
-            // Значения отличные от нуля отсутствуют в одном или более столбцах (XOR)
-            any_horizontal([expr.is_null()])?
-        }
-    });
+    // Значения выше предела обнаружения присутствуют во всех столбцах (AND), в
+    // одном или более столбцах (OR) или отсутствуют в одном или более столбцах
+    // (XOR)
+    lazy_frame = lazy_frame.filter(detection(&key.frame.data_frame, key.filter)?);
+    lazy_frame = intersection(lazy_frame, key);
     Ok(lazy_frame)
 }
 
+/// Intersection
+///
+/// Keeps the rows present (above the threshold) in exactly the samples of the
+/// selected set membership.
+fn intersection(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
+    let Some(mask) = key.membership else {
+        return lazy_frame;
+    };
+    let presences = key
+        .frame
+        .data_frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
+        .map(|name| presence(name, key.threshold))
+        .collect();
+    lazy_frame.filter(membership(presences).eq(lit(mask)))
+}
+
 /// Format
 fn format(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let mut exprs = vec![col(LABEL), col(FATTY_ACID).fatty_acid().format()];
-    let mut sum = Vec::new();
-    for name in key
+    let names = key
         .frame
         .data_frame
         .get_column_names_str()
         .into_iter()
         .filter(|&name| !matches!(name, LABEL | FATTY_ACID | THRESHOLD))
-    {
-        exprs.push(
-            as_struct(vec![
-                format_mean(col(name).struct_().field_by_name(MEAN), key),
-                format_standard_deviation(
-                    col(name).struct_().field_by_name(STANDARD_DEVIATION),
-                    key,
-                ),
-                format_sample(col(name).struct_().field_by_name(SAMPLE), key),
-            ])
-            .alias(name),
-        );
-        let array = eval_arr(col(name).struct_().field_by_name(SAMPLE), |expr| {
-            expr.filter(THRESHOLD).sum()
-        })?;
-        sum.push(
-            as_struct(vec![
-                format_mean(array.clone().arr().mean().alias(MEAN), key),
-                format_standard_deviation(
-                    array.clone().arr().std(key.ddof).alias(STANDARD_DEVIATION),
-                    key,
-                ),
-                format_sample(array.alias(SAMPLE), key),
-            ])
-            .alias(name),
-        );
-    }
+        .collect::<Vec<_>>();
+    let (rows, sums) = computers::table(
+        &lazy_frame,
+        &names,
+        TableFormat {
+            ddof: key.ddof,
+            dispersion: key.dispersion,
+            percent: key.percent,
+            precision: key.precision,
+            reference: key.reference,
+            significant: key.significant,
+        },
+        true,
+    )?;
+    let mut exprs = vec![col(LABEL), col(FATTY_ACID).fatty_acid().format()];
+    exprs.extend(rows);
     exprs.push(col(THRESHOLD));
     concat_lf_diagonal(
-        [lazy_frame.clone().select(exprs), lazy_frame.select(sum)],
+        [lazy_frame.clone().select(exprs), lazy_frame.select(sums)],
         UnionArgs::default(),
     )
 }
-
-fn format_mean(expr: Expr, key: Key) -> Expr {
-    expr.percent(key.percent)
-        .precision(key.precision, key.significant)
-}
-
-fn format_standard_deviation(expr: Expr, key: Key) -> Expr {
-    expr.percent(key.percent)
-        .precision(key.precision + 1, key.significant)
-}
-
-fn format_sample(expr: Expr, key: Key) -> Expr {
-    expr.arr().eval(
-        element()
-            .percent(key.percent)
-            .precision(key.precision, key.significant),
-        false,
-    )
-}
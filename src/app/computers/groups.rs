@@ -0,0 +1,69 @@
+use super::statistics::dispersion;
+use crate::{
+    app::states::fatty_acids::settings::{Dispersion, Groups, View},
+    r#const::{LIMIT, MEAN, SAMPLE, STANDARD_DEVIATION},
+};
+use polars::prelude::*;
+
+/// Sample group
+///
+/// The member sample means are the group sample, the group mean and the
+/// dispersion are the between-sample ones. The absent member is zero, the
+/// group is above the limits.
+pub(crate) fn group(means: Vec<Expr>, dispersion: Dispersion, ddof: u8) -> PolarsResult<Expr> {
+    let present = any_horizontal(
+        means
+            .iter()
+            .map(|mean| mean.clone().is_not_null())
+            .collect::<Vec<_>>(),
+    )?;
+    let array = concat_arr(means.into_iter().map(|mean| mean.fill_null(0)).collect())?;
+    Ok(ternary_expr(
+        present,
+        as_struct(vec![
+            array.clone().arr().mean().alias(MEAN),
+            self::dispersion(array.clone(), dispersion, ddof).alias(STANDARD_DEVIATION),
+            array.alias(SAMPLE),
+            lit(NULL).cast(DataType::String).alias(LIMIT),
+        ]),
+        lit(NULL),
+    ))
+}
+
+/// Sample groups
+///
+/// Appends the groups to the samples or replaces the grouped samples with
+/// them, the ungrouped samples are kept.
+pub(crate) fn groups(
+    mut lazy_frame: LazyFrame,
+    keys: &[&str],
+    groups: &Groups,
+    group: impl Fn(&[&str]) -> PolarsResult<Expr>,
+) -> PolarsResult<LazyFrame> {
+    if groups.view == View::Samples {
+        return Ok(lazy_frame);
+    }
+    let schema = lazy_frame.collect_schema()?;
+    let samples = schema
+        .iter_names()
+        .map(PlSmallStr::as_str)
+        .filter(|name| !keys.contains(name))
+        .collect::<Vec<_>>();
+    let members = groups.members(&samples);
+    if members.is_empty() {
+        return Ok(lazy_frame);
+    }
+    let mut exprs = keys.iter().map(|&key| col(key)).collect::<Vec<_>>();
+    for &sample in &samples {
+        if groups.view == View::Both
+            || !members.iter().any(|(_, members)| members.contains(&sample))
+        {
+            exprs.push(col(sample));
+        }
+    }
+    for (name, members) in &members {
+        exprs.push(group(members)?.alias(*name));
+    }
+    lazy_frame = lazy_frame.select(exprs);
+    Ok(lazy_frame)
+}
@@ -0,0 +1,59 @@
+use crate::r#const::{COUNT, MEMBERSHIP};
+use polars::prelude::*;
+
+/// Maximum number of the samples of the set membership bitmask
+pub(crate) const MEMBERSHIP_LIMIT: usize = 63;
+
+/// Bit of the sample in the set membership bitmask, zero above the
+/// [`MEMBERSHIP_LIMIT`]
+fn bit(index: usize) -> u64 {
+    if index < MEMBERSHIP_LIMIT {
+        1 << index
+    } else {
+        0
+    }
+}
+
+/// Set membership: the bitmask of the samples the row is present in
+///
+/// The samples above the [`MEMBERSHIP_LIMIT`] are not counted.
+pub(crate) fn membership(presences: Vec<Expr>) -> Expr {
+    presences
+        .into_iter()
+        .enumerate()
+        .map(|(index, presence)| presence.cast(DataType::UInt64) * lit(bit(index)))
+        .reduce(|left, right| left + right)
+        .unwrap_or(lit(0u64))
+}
+
+/// Set membership intersections
+///
+/// Rows are the exclusive intersections (as in the UpSet plot) with the
+/// number of rows in them, the samples are the membership flags.
+pub(crate) fn intersections(
+    lazy_frame: LazyFrame,
+    names: &[PlSmallStr],
+    presence: impl Fn(&str) -> Expr,
+) -> LazyFrame {
+    lazy_frame
+        .select([membership(names.iter().map(|name| presence(name)).collect()).alias(MEMBERSHIP)])
+        .filter(col(MEMBERSHIP).gt(0))
+        .group_by([col(MEMBERSHIP)])
+        .agg([len().alias(COUNT)])
+        .sort_by_exprs(
+            [col(COUNT), col(MEMBERSHIP)],
+            SortMultipleOptions::new().with_order_descending_multi([true, false]),
+        )
+        .with_columns(
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    (col(MEMBERSHIP).floor_div(lit(bit(index).max(1))) % lit(2u64))
+                        .eq(lit(1u64))
+                        .and(lit(bit(index) > 0))
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>(),
+        )
+}
@@ -1,2 +1,19 @@
+pub(crate) use self::{
+    bootstrap::{bounds, intervals, metric_intervals},
+    groups::{group, groups},
+    membership::{MEMBERSHIP_LIMIT, intersections, membership},
+    multivariate::{clusters, means, observations, ordination, significance, transform},
+    statistics::{compare, dispersion, diversity, replicates},
+    table::{TableFormat, table},
+};
+
+pub(crate) mod blend;
 pub(crate) mod fatty_acids;
 pub(crate) mod triacylglycerols;
+
+mod bootstrap;
+mod groups;
+mod membership;
+mod multivariate;
+mod statistics;
+mod table;
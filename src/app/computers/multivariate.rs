@@ -0,0 +1,334 @@
+use crate::{
+    app::states::fatty_acids::settings::{
+        Clustering, Groups, Metric, Ordination, Permutations, Transform, Transformation,
+    },
+    r#const::{
+        CLUSTER, EXPLAINED, GROUP, MEAN, P_VALUE, R_SQUARED, REPLICATE, SAMPLE, SILHOUETTE,
+        STATISTIC, STRESS, TEST, X, Y,
+    },
+    utils::{
+        clustering::{kmeans, pam, silhouette},
+        composition::{alr, closure, clr, ilr, replace_zeros},
+        ordination::{nmds, pcoa},
+        statistics::{anosim, permanova},
+    },
+};
+use polars::prelude::*;
+use std::f64::consts::{FRAC_1_SQRT_2, LN_2};
+
+/// Replicate observation
+///
+/// Every replicate of every sample is an observation, the replicate values
+/// over the rows are its coordinates.
+#[derive(Clone, Debug)]
+pub(crate) struct Observation {
+    pub(crate) sample: String,
+    pub(crate) replicate: usize,
+    pub(crate) values: Vec<f64>,
+}
+
+/// Replicate observations of the samples
+///
+/// The samples are the structs with the mean and the replicate array, the
+/// absent values are zero. The replicate values excluded below the limit of
+/// detection are the mean of the other replicates of the sample, zero without
+/// them, as in the [`means`].
+pub(crate) fn observations(
+    data_frame: &DataFrame,
+    names: &[&str],
+) -> PolarsResult<Vec<Observation>> {
+    let mut observations = Vec::new();
+    for &name in names {
+        let sample = data_frame[name].struct_()?;
+        let means = sample.field_by_name(MEAN)?.f64()?.clone();
+        let samples = sample.field_by_name(SAMPLE)?.array()?.clone();
+        let replicates = samples.width();
+        let mut values = vec![vec![0.0; data_frame.height()]; replicates];
+        for row in 0..data_frame.height() {
+            if let Some(series) = samples.get_as_series(row) {
+                let mean = means.get(row).unwrap_or_default();
+                for (replicate, value) in series.f64()?.iter().enumerate() {
+                    values[replicate][row] = value.unwrap_or(mean);
+                }
+            }
+        }
+        observations.extend(values.into_iter().enumerate().map(|(replicate, values)| {
+            Observation {
+                sample: name.to_owned(),
+                replicate,
+                values,
+            }
+        }));
+    }
+    Ok(observations)
+}
+
+/// Means of the samples
+///
+/// The samples are the structs with the mean, the absent values and the
+/// values with every replicate excluded below the limit of detection are zero.
+/// The mean skips the excluded replicates.
+pub(crate) fn means(data_frame: &DataFrame, names: &[&str]) -> PolarsResult<Vec<Vec<f64>>> {
+    names
+        .iter()
+        .map(|&name| {
+            Ok(data_frame[name]
+                .struct_()?
+                .field_by_name(MEAN)?
+                .f64()?
+                .iter()
+                .map(Option::unwrap_or_default)
+                .collect())
+        })
+        .collect()
+}
+
+/// Compositional transform of the profiles
+///
+/// Every profile is the composition over the rows. The additive log-ratio
+/// reference part is found by the row label, the last part is the reference
+/// by default.
+pub(crate) fn transform<'a>(
+    profiles: impl IntoIterator<Item = &'a mut Vec<f64>>,
+    labels: &StringChunked,
+    transformation: &Transformation,
+    metric: Metric,
+) {
+    let reference = transformation
+        .reference
+        .as_deref()
+        .and_then(|reference| labels.iter().position(|label| label == Some(reference)))
+        .unwrap_or(labels.len().saturating_sub(1));
+    let detection_limit = transformation.detection_limit.0;
+    for profile in profiles {
+        match transformation.transform(metric) {
+            Transform::None => {}
+            Transform::Closure => closure(profile),
+            Transform::CenteredLogRatio => {
+                replace_zeros(profile, detection_limit);
+                clr(profile);
+            }
+            Transform::IsometricLogRatio => {
+                replace_zeros(profile, detection_limit);
+                ilr(profile);
+            }
+            Transform::AdditiveLogRatio => {
+                replace_zeros(profile, detection_limit);
+                alr(profile, reference);
+            }
+        }
+    }
+}
+
+/// Distance matrix of the observations
+pub(crate) fn distances(observations: &[Observation], metric: Metric) -> Vec<Vec<f64>> {
+    observations
+        .iter()
+        .map(|left| {
+            observations
+                .iter()
+                .map(|right| distance(metric, &left.values, &right.values))
+                .collect()
+        })
+        .collect()
+}
+
+/// Distance between two observations
+///
+/// The same metrics as the metrics computers compute on the means. The
+/// Aitchison distance is on the centered log-ratios of the [`transform`].
+pub(crate) fn distance(metric: Metric, a: &[f64], b: &[f64]) -> f64 {
+    let pairs = || a.iter().zip(b);
+    let sum = |values: &[f64]| values.iter().sum::<f64>();
+    match metric {
+        Metric::HellingerDistance => {
+            FRAC_1_SQRT_2
+                * pairs()
+                    .map(|(a, b)| (a.sqrt() - b.sqrt()).powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+        }
+        Metric::JensenShannonDistance => {
+            let (a_sum, b_sum) = (sum(a), sum(b));
+            let divergence = |p: f64, m: f64| if p > 0.0 { p * (p / m).ln() } else { 0.0 };
+            let divergence = pairs()
+                .map(|(a, b)| {
+                    let (a, b) = (a / a_sum, b / b_sum);
+                    let m = (a + b) / 2.0;
+                    0.5 * divergence(a, m) + 0.5 * divergence(b, m)
+                })
+                .sum::<f64>();
+            (divergence / LN_2).sqrt()
+        }
+        Metric::BhattacharyyaDistance => -pairs().map(|(a, b)| (a * b).sqrt()).sum::<f64>().ln(),
+        Metric::ChebyshevDistance => pairs().map(|(a, b)| (a - b).abs()).fold(0.0, f64::max),
+        Metric::EuclideanDistance | Metric::AitchisonDistance => {
+            pairs().map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt()
+        }
+        Metric::ManhattanDistance => pairs().map(|(a, b)| (a - b).abs()).sum(),
+        Metric::CosineDistance => {
+            let norm =
+                |values: &[f64]| values.iter().map(|value| value * value).sum::<f64>().sqrt();
+            1.0 - pairs().map(|(a, b)| a * b).sum::<f64>() / (norm(a) * norm(b))
+        }
+        Metric::JaccardDistance => {
+            1.0 - pairs().map(|(a, b)| a.min(*b)).sum::<f64>()
+                / pairs().map(|(a, b)| a.max(*b)).sum::<f64>()
+        }
+        Metric::OverlapDistance => {
+            1.0 - pairs().map(|(a, b)| a.min(*b)).sum::<f64>() / sum(a).min(sum(b))
+        }
+    }
+}
+
+/// Significance of the group differences
+///
+/// PERMANOVA (pseudo-F, R² and p-value) and ANOSIM (R and p-value) on the
+/// distance matrix of the observations, the sample group is the factor.
+pub(crate) fn significance(
+    observations: &[Observation],
+    groups: &Groups,
+    metric: Metric,
+    permutations: Permutations,
+) -> PolarsResult<DataFrame> {
+    let mut levels = Vec::new();
+    let factors = observations
+        .iter()
+        .map(|observation| {
+            let group = groups.group(&observation.sample);
+            match levels.iter().position(|level| *level == group) {
+                Some(index) => index,
+                None => {
+                    levels.push(group);
+                    levels.len() - 1
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if levels.len() < 2 {
+        return Ok(DataFrame::empty());
+    }
+    let distances = distances(observations, metric);
+    let permanova = permanova(&distances, &factors, permutations.count, permutations.seed);
+    let anosim = anosim(&distances, &factors, permutations.count, permutations.seed);
+    df! {
+        TEST => ["PERMANOVA", "ANOSIM"],
+        STATISTIC => [
+            permanova.map(|permanova| permanova.pseudo_f),
+            anosim.map(|anosim| anosim.r),
+        ],
+        R_SQUARED => [permanova.map(|permanova| permanova.r_squared), None],
+        P_VALUE => [
+            permanova.map(|permanova| permanova.p_value),
+            anosim.map(|anosim| anosim.p_value),
+        ],
+    }
+}
+
+/// Ordination of the observations
+///
+/// The coordinates of the replicate observations on the two ordination axes
+/// of the distance matrix. PCoA holds the variance [`EXPLAINED`] by the axes,
+/// NMDS holds the [`STRESS`].
+pub(crate) fn ordination(
+    observations: &[Observation],
+    groups: &Groups,
+    metric: Metric,
+    ordination: Ordination,
+) -> PolarsResult<DataFrame> {
+    let distances = distances(observations, metric);
+    let (coordinates, explained, stress) = match ordination {
+        Ordination::PrincipalCoordinates => match pcoa(&distances) {
+            Some(pcoa) => (pcoa.coordinates, Some(pcoa.explained), None),
+            None => return Ok(DataFrame::empty()),
+        },
+        Ordination::NonMetricMultidimensionalScaling => match nmds(&distances) {
+            Some(nmds) => (nmds.coordinates, None, Some(nmds.stress)),
+            None => return Ok(DataFrame::empty()),
+        },
+    };
+    let value = |value: Option<f64>| value.map_or(lit(NULL).cast(DataType::Float64), lit);
+    df! {
+        SAMPLE => observations
+            .iter()
+            .map(|observation| observation.sample.as_str())
+            .collect::<Vec<_>>(),
+        GROUP => observations
+            .iter()
+            .map(|observation| groups.group(&observation.sample))
+            .collect::<Vec<_>>(),
+        REPLICATE => observations
+            .iter()
+            .map(|observation| observation.replicate as u32 + 1)
+            .collect::<Vec<_>>(),
+        X => coordinates.iter().map(|[x, _]| *x).collect::<Vec<_>>(),
+        Y => coordinates.iter().map(|[_, y]| *y).collect::<Vec<_>>(),
+    }?
+    .lazy()
+    .with_columns([
+        as_struct(vec![
+            value(explained.map(|[x, _]| x)).alias(X),
+            value(explained.map(|[_, y]| y)).alias(Y),
+        ])
+        .alias(EXPLAINED),
+        value(stress).alias(STRESS),
+    ])
+    .collect()
+}
+
+/// Clusters of the samples
+///
+/// Every cluster count from two to one less than the samples is a struct
+/// column named by the count with the [`CLUSTER`] and the [`SILHOUETTE`]
+/// width of every sample. k-means clusters the sample means, k-medoids
+/// clusters the distance matrix of the metric. The silhouette widths are on
+/// the distances the clusters are built on.
+pub(crate) fn clusters(
+    names: &[&str],
+    means: &[Vec<f64>],
+    clustering: Clustering,
+    metric: Metric,
+) -> PolarsResult<DataFrame> {
+    let metric = match clustering {
+        Clustering::KMeans => Metric::EuclideanDistance,
+        Clustering::KMedoids => metric,
+    };
+    let distances = means
+        .iter()
+        .map(|left| {
+            means
+                .iter()
+                .map(|right| distance(metric, left, right))
+                .collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    let mut columns = Vec::new();
+    for count in 2..names.len() {
+        let clusters = match clustering {
+            Clustering::KMeans => kmeans(means, count),
+            Clustering::KMedoids => pam(&distances, count),
+        };
+        let Some(clusters) = clusters else {
+            continue;
+        };
+        let silhouette = silhouette(&distances, &clusters);
+        let data_frame = df! {
+            CLUSTER => clusters.iter().map(|&cluster| cluster as u32).collect::<Vec<_>>(),
+            SILHOUETTE => silhouette,
+        }?;
+        columns.push(
+            data_frame
+                .into_struct(count.to_string().into())
+                .into_series()
+                .into_column(),
+        );
+    }
+    if columns.is_empty() {
+        return Ok(DataFrame::empty());
+    }
+    let mut output = df! {
+        SAMPLE => names,
+    }?;
+    output.hstack_mut(&columns)?;
+    Ok(output)
+}
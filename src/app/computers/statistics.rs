@@ -0,0 +1,119 @@
+use crate::{
+    app::states::fatty_acids::settings::{Dispersion, Diversity},
+    r#const::{FOLD_CHANGE, P_VALUE, ROW, SAMPLE},
+    utils::{
+        polars::{confidence_interval, standard_error},
+        statistics::welch_t_test,
+    },
+};
+use lipid::prelude::*;
+use polars::prelude::*;
+use std::f64::consts::E;
+
+/// Dispersion of the array elements
+pub(crate) fn dispersion(expr: Expr, dispersion: Dispersion, ddof: u8) -> Expr {
+    match dispersion {
+        Dispersion::StandardDeviation => expr.arr().std(ddof),
+        Dispersion::StandardError => standard_error(expr, ddof),
+        Dispersion::ConfidenceInterval => confidence_interval(expr, ddof),
+    }
+}
+
+/// Comparison of two samples
+///
+/// The data frame holds the [`ROW`], the [`LABEL`] and the replicate arrays of
+/// the two samples, the replicate counts may differ and the null replicates
+/// are excluded. Every sample is replaced by its presence flag, the log2
+/// fold change (second to first) and the Welch's t-test p-value are added.
+/// The fold change is undefined for the species present in only one sample
+/// (the [`Filter::Difference`] species).
+///
+/// [`Filter::Difference`]: crate::app::states::fatty_acids::settings::Filter::Difference
+pub(crate) fn compare(data_frame: &DataFrame, names: [&str; 2]) -> PolarsResult<DataFrame> {
+    let values = |name: &str, row: usize| -> PolarsResult<Vec<f64>> {
+        Ok(match data_frame[name].array()?.get_as_series(row) {
+            Some(series) => series.f64()?.iter().flatten().collect(),
+            None => Vec::new(),
+        })
+    };
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len().max(1) as f64;
+    let height = data_frame.height();
+    let mut first_presence = Vec::with_capacity(height);
+    let mut second_presence = Vec::with_capacity(height);
+    let mut fold_changes = Vec::with_capacity(height);
+    let mut p_values = Vec::with_capacity(height);
+    for row in 0..height {
+        let first = values(names[0], row)?;
+        let second = values(names[1], row)?;
+        let first_mean = mean(&first);
+        let second_mean = mean(&second);
+        first_presence.push(first_mean > 0.0);
+        second_presence.push(second_mean > 0.0);
+        fold_changes.push(
+            (first_mean > 0.0 && second_mean > 0.0).then(|| (second_mean / first_mean).log2()),
+        );
+        p_values.push(welch_t_test(&first, &second));
+    }
+    let mut output = data_frame.select([ROW, LABEL])?;
+    output.hstack_mut(&[
+        Column::new(names[0].into(), first_presence),
+        Column::new(names[1].into(), second_presence),
+        Column::new(FOLD_CHANGE.into(), fold_changes),
+        Column::new(P_VALUE.into(), p_values),
+    ])?;
+    Ok(output)
+}
+
+/// Diversity index of every replicate
+///
+/// Rows are the species, the sample is the array of the replicate values.
+pub(crate) fn diversity(
+    sample: Expr,
+    replicates: usize,
+    diversity: Diversity,
+) -> PolarsResult<Expr> {
+    concat_arr(
+        (0..replicates)
+            .map(|replicate| {
+                let value = sample
+                    .clone()
+                    .arr()
+                    .get(lit(replicate as i64), true)
+                    .fill_null(0);
+                let proportion = value.clone() / value.sum();
+                let proportion = proportion.clone().filter(proportion.gt(0));
+                let shannon =
+                    lit(0.0) - (proportion.clone() * proportion.clone().log(lit(E))).sum();
+                let richness = proportion.clone().len().cast(DataType::Float64);
+                let simpson = proportion.pow(2).sum();
+                match diversity {
+                    Diversity::Shannon => shannon,
+                    Diversity::Simpson => lit(1) - simpson,
+                    // Undefined for a single species: ln 1 = 0
+                    Diversity::Pielou => ternary_expr(
+                        richness.clone().gt(1),
+                        shannon / richness.log(lit(E)),
+                        lit(NULL).cast(DataType::Float64),
+                    ),
+                    Diversity::Hill0 => richness,
+                    Diversity::Hill1 => shannon.exp(),
+                    Diversity::Hill2 => lit(1) / simpson,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Number of replicates in the sample array
+pub(crate) fn replicates(lazy_frame: &LazyFrame, sample: Expr) -> PolarsResult<usize> {
+    let schema = lazy_frame
+        .clone()
+        .select([sample.alias(SAMPLE)])
+        .collect_schema()?;
+    match schema.get(SAMPLE) {
+        Some(DataType::Array(_, width)) => Ok(*width),
+        data_type => polars_bail!(
+            SchemaMismatch: "expected sample to be an array, got = `{data_type:?}`"
+        ),
+    }
+}
@@ -0,0 +1,171 @@
+use super::statistics::{dispersion, replicates};
+use crate::{
+    app::states::fatty_acids::settings::{Dispersion, Reference, Relation},
+    r#const::{LIMIT, MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
+    utils::polars::eval_arr,
+};
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use std::f64::consts::E;
+
+/// Relation of the sample to the reference sample
+///
+/// The sample and the reference are the structs of the mean, the dispersion
+/// and the replicates. The dispersion is propagated as for the independent
+/// samples, every replicate is related to the reference mean. The ratios are
+/// undefined for the non-positive values.
+pub(crate) fn relation(
+    sample: Expr,
+    reference: Expr,
+    relation: Relation,
+    replicates: usize,
+) -> PolarsResult<Expr> {
+    let positive = |expr: Expr| when(expr.clone().gt(0)).then(expr).otherwise(lit(NULL));
+    let mean = sample.clone().struct_().field_by_name(MEAN);
+    let deviation = sample.clone().struct_().field_by_name(STANDARD_DEVIATION);
+    let array = sample.struct_().field_by_name(SAMPLE);
+    let reference_mean = reference.clone().struct_().field_by_name(MEAN);
+    let reference_deviation = reference.struct_().field_by_name(STANDARD_DEVIATION);
+    // Every replicate related to the reference mean
+    let related = |f: &dyn Fn(Expr) -> Expr| {
+        concat_arr(
+            (0..replicates)
+                .map(|index| f(array.clone().arr().get(lit(index as i64), true)))
+                .collect(),
+        )
+    };
+    // Relative dispersion of the ratio
+    let relative = || {
+        ((deviation.clone() / positive(mean.clone())).pow(2)
+            + (reference_deviation.clone() / positive(reference_mean.clone())).pow(2))
+        .sqrt()
+    };
+    let (mean, deviation, array) = match relation {
+        Relation::Difference => (
+            mean.clone() - reference_mean.clone(),
+            (deviation.clone().pow(2) + reference_deviation.clone().pow(2)).sqrt(),
+            related(&|value| value - reference_mean.clone())?,
+        ),
+        Relation::Ratio => {
+            let ratio = mean.clone() / positive(reference_mean.clone());
+            (
+                ratio.clone(),
+                ratio.abs() * relative(),
+                related(&|value| value / positive(reference_mean.clone()))?,
+            )
+        }
+        Relation::LogRatio => (
+            (positive(mean.clone()) / positive(reference_mean.clone())).log(lit(E)),
+            relative(),
+            related(&|value| (positive(value) / positive(reference_mean.clone())).log(lit(E)))?,
+        ),
+    };
+    Ok(as_struct(vec![
+        mean.alias(MEAN),
+        deviation.alias(STANDARD_DEVIATION),
+        array.alias(SAMPLE),
+    ]))
+}
+
+/// Table format
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TableFormat<'a> {
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) percent: bool,
+    pub(crate) precision: usize,
+    pub(crate) reference: &'a Reference,
+    pub(crate) significant: bool,
+}
+
+/// Table samples
+///
+/// The formatted rows and the sum row of every sample, the samples other than
+/// the reference sample are related to it. The limit is the field of the
+/// samples with the values below the detection or quantification limit.
+pub(crate) fn table(
+    lazy_frame: &LazyFrame,
+    names: &[&str],
+    format: TableFormat,
+    limit: bool,
+) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+    let reference = format
+        .reference
+        .sample
+        .as_deref()
+        .filter(|reference| names.contains(reference));
+    let sum = |name: &str| -> PolarsResult<Expr> {
+        let array = eval_arr(col(name).struct_().field_by_name(SAMPLE), |expr| {
+            expr.filter(THRESHOLD).sum()
+        })?;
+        Ok(as_struct(vec![
+            array.clone().arr().mean().alias(MEAN),
+            dispersion(array.clone(), format.dispersion, format.ddof).alias(STANDARD_DEVIATION),
+            array.alias(SAMPLE),
+        ]))
+    };
+    let row_limit = |name: &str| limit.then(|| col(name).struct_().field_by_name(LIMIT));
+    let sum_limit = || limit.then(|| lit(NULL).cast(DataType::String));
+    let mut rows = Vec::with_capacity(names.len());
+    let mut sums = Vec::with_capacity(names.len());
+    for &name in names {
+        match reference {
+            Some(reference) if reference != name => {
+                let replicates = replicates(lazy_frame, col(name).struct_().field_by_name(SAMPLE))?;
+                let relation = format.reference.relation;
+                // Percent is meaningful only for the difference
+                let format = TableFormat {
+                    percent: format.percent && relation == Relation::Difference,
+                    ..format
+                };
+                rows.push(
+                    format_struct(
+                        self::relation(col(name), col(reference), relation, replicates)?,
+                        row_limit(name),
+                        format,
+                    )
+                    .alias(name),
+                );
+                sums.push(
+                    format_struct(
+                        self::relation(sum(name)?, sum(reference)?, relation, replicates)?,
+                        sum_limit(),
+                        format,
+                    )
+                    .alias(name),
+                );
+            }
+            _ => {
+                rows.push(format_struct(col(name), row_limit(name), format).alias(name));
+                sums.push(format_struct(sum(name)?, sum_limit(), format).alias(name));
+            }
+        }
+    }
+    Ok((rows, sums))
+}
+
+/// Format of the struct of the mean, the dispersion and the replicates
+fn format_struct(expr: Expr, limit: Option<Expr>, format: TableFormat) -> Expr {
+    let mut fields = vec![
+        expr.clone()
+            .struct_()
+            .field_by_name(MEAN)
+            .percent(format.percent)
+            .precision(format.precision, format.significant),
+        expr.clone()
+            .struct_()
+            .field_by_name(STANDARD_DEVIATION)
+            .percent(format.percent)
+            .precision(format.precision + 1, format.significant),
+        expr.struct_().field_by_name(SAMPLE).arr().eval(
+            element()
+                .percent(format.percent)
+                .precision(format.precision, format.significant),
+            false,
+        ),
+    ];
+    if let Some(limit) = limit {
+        fields.push(limit.alias(LIMIT));
+    }
+    as_struct(fields)
+}
@@ -0,0 +1,82 @@
+use super::table::labels;
+use crate::{
+    app::{
+        computers::{clusters, means, transform},
+        states::{
+            fatty_acids::settings::{Clustering, Metric, Transformation},
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{COMPOSITION, SPECIES, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Clusters computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Clusters computer
+///
+/// Partitions the samples into the clusters with k-means on the mean
+/// composition profiles or with k-medoids on their distance matrix.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let names = key
+            .frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
+            .collect::<Vec<_>>();
+        let mut means = means(key.frame, &names)?;
+        transform(
+            &mut means,
+            &labels(key.frame, key.composition)?,
+            key.transformation,
+            key.metric,
+        );
+        clusters(&names, &means, key.clustering, key.metric)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Clusters key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) clustering: Clustering,
+    pub(crate) metric: Metric,
+    pub(crate) transformation: &'a Transformation,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            composition: settings.composition,
+            clustering: settings.clusters.clustering,
+            metric: settings.metric,
+            transformation: &settings.transformation,
+        }
+    }
+}
+
+/// Clusters value
+///
+/// Rows are the samples, columns are the cluster counts with the cluster and
+/// the silhouette width of the sample.
+type Value = DataFrame;
@@ -0,0 +1,79 @@
+use super::table::label;
+use crate::{
+    app::{
+        computers::compare,
+        states::triacylglycerols::{composition::Composition, settings::Settings},
+    },
+    r#const::{COMPOSITION, ROW, SAMPLE, SPECIES, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Comparison computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Comparison computer
+///
+/// Compares the two samples species by species.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let names = key
+            .frame
+            .data_frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
+            .collect::<Vec<_>>();
+        let &[first, second] = names.as_slice() else {
+            return Ok(DataFrame::empty());
+        };
+        let data_frame = key
+            .frame
+            .data_frame
+            .clone()
+            .lazy()
+            .with_row_index(ROW, None)
+            .select([
+                col(ROW),
+                label(key.composition, col(COMPOSITION))?.alias(LABEL),
+                col(first).struct_().field_by_name(SAMPLE).alias(first),
+                col(second).struct_().field_by_name(SAMPLE).alias(second),
+            ])
+            .collect()?;
+        compare(&data_frame, [first, second])
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Comparison key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+        Self {
+            frame,
+            composition: settings.composition,
+        }
+    }
+}
+
+/// Comparison value
+///
+/// The [`ROW`] is the row of the species in the table.
+type Value = DataFrame;
@@ -0,0 +1,196 @@
+use crate::{
+    r#const::VALUE,
+    utils::{HashedDataFrame, polars::eval_arr},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Decomposition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Decomposition computer
+///
+/// Calculates the fatty acid composition implied by the triacylglycerol
+/// species composition.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+// From COMPOSITION (the values are the mole fractions):
+// ┌───────────┬─────────────────┬───────────────┐
+// │ Label     ┆ Triacylglycerol ┆ Value         │
+// ┆ ---       ┆ ---             ┆ ---           │
+// │ struct[3] ┆ struct[3]       ┆ array[f64, n] │
+// ╞═══════════╪═════════════════╪═══════════════╡
+// To CACLULATION:
+// ┌───────┬───────────┬──────────────────────────┬─────────────────────────┬────────────────────────┐
+// │ Label ┆ FattyAcid ┆ StereospecificNumbers123 ┆ StereospecificNumbers13 ┆ StereospecificNumbers2 │
+// ┆ ---   ┆ ---       ┆ ---                      ┆ ---                     ┆ ---                    │
+// │ str   ┆ struct[2] ┆ array[f64, n]            ┆ array[f64, n]           ┆ array[f64, n]          │
+// ╞═══════╪═══════════╪══════════════════════════╪═════════════════════════╪════════════════════════╡
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(HashedDataFrame::EMPTY);
+        }
+        let lazy_frame = compute(key)?;
+        let data_frame = lazy_frame.collect()?;
+        HashedDataFrame::new(data_frame)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Decomposition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame) -> Self {
+        Self { frame }
+    }
+}
+
+/// Decomposition value
+type Value = HashedDataFrame;
+
+// Mol basis: the values of the COMPOSITION schema are the mole fractions of
+// the triacylglycerols. One mol of triacylglycerol gives one mol of fatty acid
+// at every position, so the SN-1,2,3 value is the sum over the species of the
+// moles multiplied by the share of the positions occupied by the fatty acid.
+// Every position is closed to one.
+pub(crate) fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let lazy_frame = key.frame.data_frame.clone().lazy();
+    // (stereospecific number, SN-1,2,3 weight, SN-1,3 weight, SN-2 weight)
+    let positions = [
+        (STEREOSPECIFIC_NUMBERS1, 1.0 / 3.0, 0.5, 0.0),
+        (STEREOSPECIFIC_NUMBERS2, 1.0 / 3.0, 0.0, 1.0),
+        (STEREOSPECIFIC_NUMBERS3, 1.0 / 3.0, 0.5, 0.0),
+    ];
+    let mut lazy_frames = Vec::with_capacity(positions.len());
+    for (name, sn123, sn13, sn2) in positions {
+        lazy_frames.push(
+            lazy_frame.clone().select([
+                col(LABEL).struct_().field_by_name(name).alias(LABEL),
+                col(TRIACYLGLYCEROL)
+                    .struct_()
+                    .field_by_name(name)
+                    .alias(FATTY_ACID),
+                eval_arr(col(VALUE), |expr| expr * lit(sn123))?.alias(STEREOSPECIFIC_NUMBERS123),
+                eval_arr(col(VALUE), |expr| expr * lit(sn13))?.alias(STEREOSPECIFIC_NUMBERS13),
+                eval_arr(col(VALUE), |expr| expr * lit(sn2))?.alias(STEREOSPECIFIC_NUMBERS2),
+            ]),
+        );
+    }
+    Ok(concat(lazy_frames, UnionArgs::default())?
+        .group_by_stable([col(LABEL), col(FATTY_ACID)])
+        .agg([
+            eval_arr(col(STEREOSPECIFIC_NUMBERS123), |expr| expr.sum())?
+                .alias(STEREOSPECIFIC_NUMBERS123),
+            eval_arr(col(STEREOSPECIFIC_NUMBERS13), |expr| expr.sum())?
+                .alias(STEREOSPECIFIC_NUMBERS13),
+            eval_arr(col(STEREOSPECIFIC_NUMBERS2), |expr| expr.sum())?
+                .alias(STEREOSPECIFIC_NUMBERS2),
+        ])
+        .with_columns([
+            eval_arr(col(STEREOSPECIFIC_NUMBERS123), closure)?.alias(STEREOSPECIFIC_NUMBERS123),
+            eval_arr(col(STEREOSPECIFIC_NUMBERS13), closure)?.alias(STEREOSPECIFIC_NUMBERS13),
+            eval_arr(col(STEREOSPECIFIC_NUMBERS2), closure)?.alias(STEREOSPECIFIC_NUMBERS2),
+        ]))
+}
+
+/// Closure of the replicate to one
+fn closure(expr: Expr) -> Expr {
+    expr.clone() / expr.sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::{
+        computers::fatty_acids::prediction::{Key as PredictionKey, compute as predict},
+        states::fatty_acids::settings::Model,
+    };
+
+    /// Replicate arrays of the rows
+    fn arrays(rows: [[f64; 2]; 3]) -> Vec<Series> {
+        rows.map(|row| Series::new(PlSmallStr::EMPTY, row)).to_vec()
+    }
+
+    /// Values of the rows sorted by the label, row by row
+    fn values(data_frame: &DataFrame, name: &str) -> PolarsResult<Vec<f64>> {
+        let data_frame = data_frame.sort([LABEL], SortMultipleOptions::default())?;
+        Ok(data_frame[name]
+            .array()?
+            .get_inner()
+            .f64()?
+            .into_no_null_iter()
+            .collect())
+    }
+
+    #[test]
+    fn round_trip() -> PolarsResult<()> {
+        // Rows: palmitic, oleic, linoleic; SN-1,2,3 = (2·SN-1,3 + SN-2) / 3
+        let stereospecific_numbers13 = [[0.3, 0.2], [0.5, 0.6], [0.2, 0.2]];
+        let stereospecific_numbers2 = [[0.1, 0.2], [0.5, 0.4], [0.4, 0.4]];
+        let stereospecific_numbers123 = [
+            [0.7 / 3.0, 0.6 / 3.0],
+            [1.5 / 3.0, 1.6 / 3.0],
+            [0.8 / 3.0, 0.8 / 3.0],
+        ];
+        let fatty_acids = df! {
+            LABEL => ["Palmitic", "Oleic", "Linoleic"],
+            FATTY_ACID => [
+                fatty_acid!(C16 {})?,
+                fatty_acid!(C18 {9 => C})?,
+                fatty_acid!(C18 {9 => C, 12 => C})?,
+            ],
+            STEREOSPECIFIC_NUMBERS123 => arrays(stereospecific_numbers123),
+            STEREOSPECIFIC_NUMBERS13 => arrays(stereospecific_numbers13),
+            STEREOSPECIFIC_NUMBERS2 => arrays(stereospecific_numbers2),
+        }?
+        .lazy()
+        .with_columns(
+            [
+                STEREOSPECIFIC_NUMBERS123,
+                STEREOSPECIFIC_NUMBERS13,
+                STEREOSPECIFIC_NUMBERS2,
+            ]
+            .map(|name| col(name).cast(DataType::Array(Box::new(DataType::Float64), 2))),
+        )
+        .collect()?;
+        let fatty_acids = HashedDataFrame::new(fatty_acids)?;
+        let triacylglycerols = HashedDataFrame::new(
+            predict(PredictionKey::new(
+                &fatty_acids,
+                Model::OneThreeRandomTwoRandom,
+            ))?
+            .collect()?,
+        )?;
+        let decomposed = compute(Key::new(&triacylglycerols))?.collect()?;
+        for name in [
+            STEREOSPECIFIC_NUMBERS123,
+            STEREOSPECIFIC_NUMBERS13,
+            STEREOSPECIFIC_NUMBERS2,
+        ] {
+            let expected = values(&fatty_acids, name)?;
+            let actual = values(&decomposed, name)?;
+            assert_eq!(expected.len(), actual.len());
+            for (expected, actual) in expected.iter().zip(&actual) {
+                assert!(
+                    (expected - actual).abs() < 1e-9,
+                    "{name}: {expected} != {actual}"
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    app::{
+        computers::dispersion,
+        states::{
+            fatty_acids::settings::Dispersion,
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{COMPOSITION, MEAN, SAMPLE, SPECIES, STANDARD_DEVIATION, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use tracing::instrument;
+
+/// Distribution computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Distribution computer
+///
+/// Calculates the moments of the composition distribution: the composition
+/// value (ECN, mass, ...) is the variable and the abundance is the weight.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() || !key.composition.is_scalar() {
+            return Ok(DataFrame::empty());
+        }
+        let lazy_frame = compute(key)?;
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Distribution key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+        Self {
+            frame,
+            composition: settings.composition,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            precision: settings.precision,
+            significant: settings.significant,
+        }
+    }
+}
+
+/// Distribution value
+///
+/// Rows are the moments, every sample is a struct of the mean and the
+/// dispersion of the moment over the replicates.
+type Value = DataFrame;
+
+fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let lazy_frame = key
+        .frame
+        .data_frame
+        .clone()
+        .lazy()
+        .filter(col(COMPOSITION).is_not_null());
+    let schema = key.frame.data_frame.schema();
+    let names = schema
+        .iter_names_cloned()
+        .filter(|name| !matches!(name.as_str(), COMPOSITION | SPECIES | THRESHOLD))
+        .collect::<Vec<_>>();
+    let mut lazy_frames = Vec::with_capacity(4);
+    for moment in ["Mean", "Variance", "Skewness", "Kurtosis"] {
+        let mut exprs = vec![lit(moment).alias("Moment")];
+        for name in &names {
+            // One value per replicate
+            let values = (0..replicates(schema, name)?)
+                .map(|replicate| {
+                    let weight = col(name.clone())
+                        .struct_()
+                        .field_by_name(SAMPLE)
+                        .arr()
+                        .get(lit(replicate as i64), true)
+                        .fill_null(0);
+                    weighted(moment, weight)
+                })
+                .collect::<Vec<_>>();
+            let array = concat_arr(values)?;
+            exprs.push(
+                as_struct(vec![
+                    array
+                        .clone()
+                        .arr()
+                        .mean()
+                        .precision(key.precision, key.significant)
+                        .alias(MEAN),
+                    dispersion(array.clone(), key.dispersion, key.ddof)
+                        .precision(key.precision + 1, key.significant)
+                        .alias(STANDARD_DEVIATION),
+                    array
+                        .arr()
+                        .eval(element().precision(key.precision, key.significant), false)
+                        .alias(SAMPLE),
+                ])
+                .alias(name.clone()),
+            );
+        }
+        lazy_frames.push(lazy_frame.clone().select(exprs));
+    }
+    concat(lazy_frames, UnionArgs::default())
+}
+
+/// Weighted moment of the composition value
+///
+/// Variance is the second central moment, skewness and kurtosis (excess) are
+/// the standardized third and fourth central moments.
+fn weighted(moment: &str, weight: Expr) -> Expr {
+    let x = col(COMPOSITION).cast(DataType::Float64);
+    let mean = (weight.clone() * x.clone()).sum() / weight.clone().sum();
+    let central =
+        |k: i32| (weight.clone() * (x.clone() - mean.clone()).pow(k)).sum() / weight.clone().sum();
+    match moment {
+        "Mean" => mean,
+        "Variance" => central(2),
+        "Skewness" => central(3) / central(2).pow(1.5),
+        "Kurtosis" => central(4) / central(2).pow(2) - lit(3),
+        _ => unreachable!(),
+    }
+}
+
+/// Number of replicates of the sample
+fn replicates(schema: &Schema, name: &str) -> PolarsResult<usize> {
+    if let Some(DataType::Struct(fields)) = schema.get(name)
+        && let Some(field) = fields.iter().find(|field| field.name() == SAMPLE)
+        && let DataType::Array(_, width) = field.dtype()
+    {
+        return Ok(*width);
+    }
+    polars_bail!(SchemaMismatch: "expected `{name}` to have a `{SAMPLE}` array field")
+}
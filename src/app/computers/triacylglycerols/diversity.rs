@@ -0,0 +1,109 @@
+use crate::{
+    app::{
+        computers::{dispersion, diversity, replicates},
+        states::{
+            fatty_acids::settings::{DIVERSITIES, Dispersion},
+            triacylglycerols::settings::Settings,
+        },
+    },
+    r#const::{COMPOSITION, DIVERSITY, MEAN, SAMPLE, SPECIES, STANDARD_DEVIATION, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use tracing::instrument;
+
+/// Diversity computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Diversity computer
+///
+/// Calculates the diversity indices of the composition of every sample.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let lazy_frame = compute(key)?;
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Diversity key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+        Self {
+            frame,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            precision: settings.precision,
+            significant: settings.significant,
+        }
+    }
+}
+
+/// Diversity value
+///
+/// Rows are the diversity indices, every sample is a struct of the mean and
+/// the dispersion of the index over the replicates.
+type Value = DataFrame;
+
+fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let lazy_frame = key.frame.data_frame.clone().lazy();
+    let names = key
+        .frame
+        .data_frame
+        .schema()
+        .iter_names_cloned()
+        .filter(|name| !matches!(name.as_str(), COMPOSITION | SPECIES | THRESHOLD))
+        .collect::<Vec<_>>();
+    let mut lazy_frames = Vec::with_capacity(DIVERSITIES.len());
+    for index in DIVERSITIES {
+        let mut exprs = vec![lit(index.text()).alias(DIVERSITY)];
+        for name in &names {
+            let sample = col(name.clone()).struct_().field_by_name(SAMPLE);
+            let replicates = replicates(&lazy_frame, sample.clone())?;
+            let array = diversity(sample, replicates, index)?;
+            exprs.push(
+                as_struct(vec![
+                    array
+                        .clone()
+                        .arr()
+                        .mean()
+                        .precision(key.precision, key.significant)
+                        .alias(MEAN),
+                    dispersion(array.clone(), key.dispersion, key.ddof)
+                        .precision(key.precision + 1, key.significant)
+                        .alias(STANDARD_DEVIATION),
+                    array
+                        .arr()
+                        .eval(element().precision(key.precision, key.significant), false)
+                        .alias(SAMPLE),
+                ])
+                .alias(name.clone()),
+            );
+        }
+        lazy_frames.push(lazy_frame.clone().select(exprs));
+    }
+    concat(lazy_frames, UnionArgs::default())
+}
@@ -0,0 +1,211 @@
+use super::{composition, table::label};
+use crate::{
+    app::{
+        computers::fatty_acids::prediction::{Key as PredictionKey, compute as predict},
+        states::{
+            fatty_acids::settings::Model,
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{COMPOSITION, DISCREPANCY, EXPECTED, OBSERVED, RATIO, VALUE},
+    utils::HashedMetaDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use metadata::NAME;
+use polars::prelude::*;
+use std::f64::consts::E;
+use tracing::instrument;
+
+/// Expected computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Expected computer
+///
+/// Compares the observed composition with the composition expected under the
+/// random distribution model of the paired fatty acids.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frames.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let lazy_frame = compute(key)?;
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Expected key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) fatty_acids: &'a [HashedMetaDataFrame],
+    pub(crate) composition: Composition,
+    pub(crate) log_ratio: bool,
+    pub(crate) model: Model,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frames: &'a [HashedMetaDataFrame],
+        fatty_acids: &'a [HashedMetaDataFrame],
+        settings: &Settings,
+    ) -> Self {
+        Self {
+            frames,
+            fatty_acids,
+            composition: settings.composition,
+            log_ratio: settings.log_ratio,
+            model: settings.model,
+        }
+    }
+}
+
+/// Expected value
+///
+/// The last row is the sum of the rows above it: the total observed and
+/// expected values and the discrepancy of the sample, Σ(O - E)² / E of the
+/// fractions, a descriptive measure of the deviation from the model.
+type Value = DataFrame;
+
+fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let mut lazy_frame: Option<LazyFrame> = None;
+    for frame in key.frames {
+        let fatty_acids = pair(frame, key.fatty_acids)?;
+        let name = frame.meta.format(".").to_string();
+        let observed =
+            values(frame.data.data_frame.clone().lazy(), key)?.rename([VALUE], [OBSERVED], true);
+        let expected = values(
+            predict(PredictionKey::new(&fatty_acids.data, key.model))?,
+            key,
+        )?
+        .rename([VALUE], [EXPECTED], true);
+        let sample = observed
+            .join(
+                expected,
+                [col(COMPOSITION)],
+                [col(COMPOSITION)],
+                JoinArgs {
+                    coalesce: JoinCoalesce::CoalesceColumns,
+                    maintain_order: MaintainOrderJoin::LeftRight,
+                    ..JoinArgs::new(JoinType::Full)
+                },
+            )
+            .select([
+                col(COMPOSITION),
+                as_struct(vec![
+                    col(OBSERVED).fill_null(0),
+                    col(EXPECTED).fill_null(0),
+                    ratio(key),
+                    discrepancy(),
+                ])
+                .alias(name),
+            ]);
+        lazy_frame = Some(match lazy_frame {
+            Some(lazy_frame) => lazy_frame.join(
+                sample,
+                [col(COMPOSITION)],
+                [col(COMPOSITION)],
+                JoinArgs {
+                    coalesce: JoinCoalesce::CoalesceColumns,
+                    maintain_order: MaintainOrderJoin::LeftRight,
+                    ..JoinArgs::new(JoinType::Full)
+                },
+            ),
+            None => sample,
+        });
+    }
+    let Some(mut lazy_frame) = lazy_frame else {
+        return Ok(DataFrame::empty().lazy());
+    };
+    lazy_frame = lazy_frame
+        .sort_by_exprs(
+            [col(COMPOSITION)],
+            SortMultipleOptions::new().with_maintain_order(true),
+        )
+        .with_column(label(key.composition, col(COMPOSITION))?.alias(LABEL));
+    let schema = lazy_frame.collect_schema()?;
+    let sum = schema
+        .iter_names()
+        .filter(|name| !matches!(name.as_str(), COMPOSITION | LABEL))
+        .map(|name| {
+            let field = |field| col(name.as_str()).struct_().field_by_name(field);
+            as_struct(vec![
+                field(OBSERVED).sum(),
+                field(EXPECTED).sum(),
+                lit(NULL).cast(DataType::Float64).alias(RATIO),
+                field(DISCREPANCY).sum(),
+            ])
+            .alias(name.clone())
+        })
+        .collect::<Vec<_>>();
+    concat_lf_diagonal(
+        [
+            lazy_frame.clone().select([
+                col(LABEL),
+                all().exclude_cols([COMPOSITION, LABEL]).as_expr(),
+            ]),
+            lazy_frame.select(sum),
+        ],
+        UnionArgs::default(),
+    )
+}
+
+/// Pair
+///
+/// The fatty acids with the same name as the triacylglycerols.
+pub(crate) fn pair<'a>(
+    frame: &HashedMetaDataFrame,
+    fatty_acids: &'a [HashedMetaDataFrame],
+) -> PolarsResult<&'a HashedMetaDataFrame> {
+    let name = frame.meta.0.get(NAME);
+    fatty_acids
+        .iter()
+        .find(|fatty_acids| name.is_some() && fatty_acids.meta.0.get(NAME) == name)
+        .ok_or_else(
+            || polars_err!(ComputeError: "no fatty acids named `{}`", frame.meta.format(" ")),
+        )
+}
+
+/// Composition values, normalized to one
+fn values(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    Ok(lazy_frame
+        .group_by_stable([composition(key.composition).alias(COMPOSITION)])
+        .agg([col(VALUE).arr().mean().sum()])
+        .with_column(col(VALUE) / col(VALUE).sum()))
+}
+
+/// Observed to expected ratio
+fn ratio(key: Key) -> Expr {
+    let ratio = col(OBSERVED) / col(EXPECTED);
+    if key.log_ratio {
+        ratio.log(lit(E)).alias(RATIO)
+    } else {
+        ratio.alias(RATIO)
+    }
+}
+
+/// Discrepancy term: (O - E)² / E of the fractions
+///
+/// A descriptive measure, the values are the fractions and not the counts, so
+/// the sum is not chi-square distributed. Undefined for the species not
+/// expected under the model.
+fn discrepancy() -> Expr {
+    let observed = col(OBSERVED).fill_null(0);
+    let expected = col(EXPECTED).fill_null(0);
+    ternary_expr(
+        expected.clone().gt(0),
+        (observed - expected.clone()).pow(2) / expected,
+        lit(NULL),
+    )
+    .alias(DISCREPANCY)
+}
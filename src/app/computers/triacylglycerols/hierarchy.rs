@@ -0,0 +1,165 @@
+use super::{join, table::label};
+use crate::{
+    app::{
+        computers::dispersion,
+        states::{
+            fatty_acids::settings::Dispersion,
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{LEVEL, MEAN, SAMPLE, STANDARD_DEVIATION},
+    utils::{HashedMetaDataFrame, polars::eval_arr},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use tracing::instrument;
+
+/// Hierarchy computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Hierarchy computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frames.is_empty() || key.hierarchy.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let lazy_frame = compute(key)?;
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Hierarchy key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) hierarchy: &'a [Composition],
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) percent: bool,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frames: &'a [HashedMetaDataFrame], settings: &'a Settings) -> Self {
+        Self {
+            frames,
+            hierarchy: &settings.hierarchy,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            percent: settings.percent,
+            precision: settings.precision,
+            significant: settings.significant,
+        }
+    }
+}
+
+/// Hierarchy value
+///
+/// Rows are the tree nodes in depth-first order: each node is followed by
+/// its children. The [`LEVEL`] column holds the node depth.
+type Value = DataFrame;
+
+fn compute(key: Key) -> PolarsResult<LazyFrame> {
+    let names = key
+        .frames
+        .iter()
+        .map(|frame| frame.meta.format(".").to_string())
+        .collect::<Vec<_>>();
+    let lazy_frame = join(key.frames)?.with_columns(
+        key.hierarchy
+            .iter()
+            .enumerate()
+            .map(|(level, &composition)| super::composition(composition).alias(level.to_string()))
+            .collect::<Vec<_>>(),
+    );
+    // Each level is grouped by all the compositions above it, so the nodes
+    // of the same name under different parents stay apart
+    let mut levels = Vec::with_capacity(key.hierarchy.len());
+    for level in 0..key.hierarchy.len() {
+        let by = (0..=level)
+            .map(|level| col(level.to_string()))
+            .collect::<Vec<_>>();
+        let mut aggs = Vec::with_capacity(names.len());
+        for name in &names {
+            let array = eval_arr(col(name), |expr| expr.sum())?;
+            aggs.push(
+                as_struct(vec![
+                    array.clone().arr().mean().alias(MEAN),
+                    dispersion(array.clone(), key.dispersion, key.ddof).alias(STANDARD_DEVIATION),
+                    array.alias(SAMPLE),
+                ])
+                .alias(name),
+            );
+        }
+        levels.push(
+            lazy_frame
+                .clone()
+                .group_by_stable(by)
+                .agg(aggs)
+                .with_column(lit(level as u32).alias(LEVEL)),
+        );
+    }
+    // Sorting with nulls first puts every parent right before its children
+    let by = (0..key.hierarchy.len())
+        .map(|level| level.to_string())
+        .collect::<Vec<_>>();
+    let mut lazy_frame = concat_lf_diagonal(levels, UnionArgs::default())?.sort(
+        by,
+        SortMultipleOptions::new()
+            .with_maintain_order(true)
+            .with_nulls_last(false),
+    );
+    // The node label is the label of its deepest composition
+    let mut labels = Vec::with_capacity(key.hierarchy.len());
+    for (level, &composition) in key.hierarchy.iter().enumerate().rev() {
+        labels.push(label(composition, col(level.to_string()))?);
+    }
+    let mut exprs = vec![col(LEVEL), coalesce(&labels).alias(LABEL)];
+    for name in &names {
+        exprs.push(
+            as_struct(vec![
+                format_mean(col(name).struct_().field_by_name(MEAN), key),
+                format_standard_deviation(
+                    col(name).struct_().field_by_name(STANDARD_DEVIATION),
+                    key,
+                ),
+                format_sample(col(name).struct_().field_by_name(SAMPLE), key),
+            ])
+            .alias(name),
+        );
+    }
+    lazy_frame = lazy_frame.select(exprs);
+    Ok(lazy_frame)
+}
+
+fn format_mean(expr: Expr, key: Key) -> Expr {
+    expr.percent(key.percent)
+        .precision(key.precision, key.significant)
+}
+
+fn format_standard_deviation(expr: Expr, key: Key) -> Expr {
+    expr.percent(key.percent)
+        .precision(key.precision + 1, key.significant)
+}
+
+fn format_sample(expr: Expr, key: Key) -> Expr {
+    expr.arr().eval(
+        element()
+            .percent(key.percent)
+            .precision(key.precision, key.significant),
+        false,
+    )
+}
@@ -0,0 +1,361 @@
+use super::composition;
+use crate::{
+    app::{
+        computers::{dispersion, intervals},
+        states::{
+            fatty_acids::settings::{Bootstrap, Dispersion},
+            triacylglycerols::{
+                composition::{CN_STEREO, TYPE_POSITIONAL, TYPE_STEREO},
+                settings::Settings,
+            },
+        },
+    },
+    r#const::{LOWER, MEAN, PROPERTY, SAMPLE, STANDARD_DEVIATION, UPPER, VALUE},
+    utils::HashedMetaDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use tracing::instrument;
+
+/// Positional types: S is saturated, U is unsaturated, the middle is sn-2
+pub(crate) const TYPES: [&str; 6] = ["SSS", "SSU", "SUS", "SUU", "USU", "UUU"];
+
+/// Standard temperatures of the solid fat content, °C
+pub(crate) const TEMPERATURES: [u8; 7] = [10, 15, 20, 25, 30, 35, 40];
+
+/// Melting points of the types with the palmitic acid as S and the oleic acid
+/// as U, °C (β polymorph: PPP, PPO, POP, POO, OPO and OOO)
+const MELTING_POINTS: [f64; 6] = [66.0, 35.0, 37.0, 19.0, 21.0, 5.0];
+
+/// Melting points of the monoacid saturated triacylglycerols from the 8:0 to
+/// the 24:0 by two carbons, °C (β polymorph)
+const SATURATED: [f64; 9] = [10.0, 31.5, 46.5, 57.0, 66.0, 73.0, 78.0, 82.5, 86.0];
+
+/// Carbon number of the saturated fatty acid of the melting points of the
+/// types
+const PALMITIC: f64 = 16.0;
+
+/// Lowering of the melting point per double bond above one per unsaturated
+/// fatty acid, °C
+const DOUBLE_BOND: f64 = 5.0;
+
+/// Melting width of the triacylglycerol below its melting point, °C
+const WIDTH: f64 = 5.0;
+
+/// Weighted quantiles of the melting points bounding the melting range
+const RANGE: [f64; 2] = [0.05, 0.95];
+
+/// Melting computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Melting computer
+///
+/// Estimates the physical behaviour of the fat from the triacylglycerols: the
+/// positional type sums, the melting range and the solid fat content of the
+/// additive model, for every replicate.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frames.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let mut data_frame = compute(key)?;
+        let names = data_frame
+            .get_column_names_owned()
+            .into_iter()
+            .filter(|name| name != PROPERTY)
+            .collect::<Vec<_>>();
+        let names = names.iter().map(PlSmallStr::as_str).collect::<Vec<_>>();
+        data_frame = statistics(data_frame.lazy(), &names, key).collect()?;
+        intervals(&mut data_frame, &names, key.bootstrap)?;
+        format(data_frame.lazy(), &names, key).collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Melting key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) bootstrap: &'a Bootstrap,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frames: &'a [HashedMetaDataFrame], settings: &'a Settings) -> Self {
+        Self {
+            frames,
+            bootstrap: &settings.bootstrap,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
+            precision: settings.precision,
+            significant: settings.significant,
+        }
+    }
+}
+
+/// Melting value
+///
+/// Rows are the properties, every sample is a struct of the mean, the
+/// dispersion, the replicates and the bootstrap interval bounds.
+type Value = DataFrame;
+
+/// Compute
+///
+/// The replicate arrays of the properties of every sample.
+fn compute(key: Key) -> PolarsResult<DataFrame> {
+    let mut properties = TYPES.map(str::to_owned).to_vec();
+    properties.push("MeltingRangeStart".to_owned());
+    properties.push("MeltingRangeEnd".to_owned());
+    properties.extend(
+        TEMPERATURES
+            .iter()
+            .map(|temperature| format!("SolidFatContent{temperature}")),
+    );
+    let mut columns = vec![Column::new(PlSmallStr::from_static(PROPERTY), properties)];
+    for frame in key.frames {
+        let name = frame.meta.format(".").to_string();
+        let species = species(&frame.data.data_frame)?;
+        let values = frame.data.data_frame[VALUE].array()?;
+        let DataType::Array(_, width) = values.dtype() else {
+            polars_bail!(SchemaMismatch: "expected `{VALUE}` to be an array");
+        };
+        let width = *width;
+        // Rows are the replicates, columns are the properties
+        let mut replicates = Vec::with_capacity(width);
+        for replicate in 0..width {
+            let mut weights = Vec::with_capacity(values.len());
+            for row in 0..values.len() {
+                let value = match values.get_as_series(row) {
+                    Some(series) => series.f64()?.get(replicate).unwrap_or_default(),
+                    None => 0.0,
+                };
+                weights.push(value);
+            }
+            replicates.push(properties_of(&species, &weights));
+        }
+        let rows = (0..TYPES.len() + 2 + TEMPERATURES.len())
+            .map(|property| {
+                Series::from_iter(replicates.iter().map(|replicate| replicate[property]))
+            })
+            .collect::<Vec<_>>();
+        columns.push(
+            Series::new(name.into(), rows)
+                .cast(&DataType::Array(Box::new(DataType::Float64), width))?
+                .into_column(),
+        );
+    }
+    DataFrame::new(columns)
+}
+
+/// Statistics
+fn statistics(lazy_frame: LazyFrame, names: &[&str], key: Key) -> LazyFrame {
+    let exprs = names
+        .iter()
+        .map(|&name| {
+            let array = col(name);
+            as_struct(vec![
+                array.clone().arr().mean().alias(MEAN),
+                dispersion(array.clone(), key.dispersion, key.ddof).alias(STANDARD_DEVIATION),
+                array.alias(SAMPLE),
+            ])
+            .alias(name)
+        })
+        .collect::<Vec<_>>();
+    lazy_frame.with_columns(exprs)
+}
+
+/// Format
+///
+/// The mean, the dispersion, the replicates and the bootstrap interval bounds
+/// of every sample are rounded.
+fn format(lazy_frame: LazyFrame, names: &[&str], key: Key) -> LazyFrame {
+    let exprs = names
+        .iter()
+        .map(|&name| {
+            let field = |field| col(name).struct_().field_by_name(field);
+            as_struct(vec![
+                field(MEAN).precision(key.precision, key.significant),
+                field(STANDARD_DEVIATION).precision(key.precision + 1, key.significant),
+                field(SAMPLE)
+                    .arr()
+                    .eval(element().precision(key.precision, key.significant), false),
+                field(LOWER).precision(key.precision, key.significant),
+                field(UPPER).precision(key.precision, key.significant),
+            ])
+            .alias(name)
+        })
+        .collect::<Vec<_>>();
+    lazy_frame.with_columns(exprs)
+}
+
+/// Triacylglycerol species: the positional type and the melting point
+struct Species {
+    r#type: usize,
+    melting_point: f64,
+}
+
+/// Species of the triacylglycerols
+///
+/// The melting point of the positional type is corrected by the chain lengths
+/// of the saturated fatty acids: every saturated fatty acid shifts it by the
+/// third of the difference of the melting points of the monoacid saturated
+/// triacylglycerols (LaLaLa is 46.5 °C, StStSt is 73 °C), and lowered by the
+/// double bonds above one per unsaturated fatty acid.
+fn species(data_frame: &DataFrame) -> PolarsResult<Vec<Species>> {
+    let positional = composition(TYPE_POSITIONAL).triacylglycerol();
+    let stereo = composition(TYPE_STEREO).triacylglycerol();
+    let carbon = composition(CN_STEREO).triacylglycerol();
+    let data_frame = data_frame
+        .clone()
+        .lazy()
+        .select([
+            concat_str(
+                [
+                    positional.clone().stereospecific_number1(),
+                    positional.clone().stereospecific_number2(),
+                    positional.stereospecific_number3(),
+                ]
+                .map(|expr| expr.cast(DataType::String)),
+                "",
+                true,
+            ),
+            stereo
+                .clone()
+                .stereospecific_number1()
+                .cast(DataType::String),
+            stereo
+                .clone()
+                .stereospecific_number2()
+                .cast(DataType::String),
+            stereo.stereospecific_number3().cast(DataType::String),
+            carbon
+                .clone()
+                .stereospecific_number1()
+                .cast(DataType::Float64),
+            carbon
+                .clone()
+                .stereospecific_number2()
+                .cast(DataType::Float64),
+            carbon.stereospecific_number3().cast(DataType::Float64),
+            col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .unsaturation()
+                .cast(DataType::Int32),
+        ])
+        .collect()?;
+    let types = data_frame[0].str()?;
+    let unsaturation = data_frame[7].i32()?;
+    let mut species = Vec::with_capacity(data_frame.height());
+    for row in 0..data_frame.height() {
+        let Some(r#type) = types
+            .get(row)
+            .and_then(|r#type| TYPES.iter().position(|&value| value == r#type))
+        else {
+            polars_bail!(ComputeError: "unexpected positional type `{:?}`", types.get(row));
+        };
+        // Chain length of the saturated fatty acids
+        let mut chain_length = 0.0;
+        for index in 0..3 {
+            if data_frame[1 + index].str()?.get(row) == Some("S")
+                && let Some(carbon) = data_frame[4 + index].f64()?.get(row)
+            {
+                chain_length += (saturated(carbon) - saturated(PALMITIC)) / 3.0;
+            }
+        }
+        // Unsaturated fatty acids
+        let unsaturated = TYPES[r#type].matches('U').count();
+        let excess =
+            (unsaturation.get(row).unwrap_or_default() as f64 - unsaturated as f64).max(0.0);
+        species.push(Species {
+            r#type,
+            melting_point: MELTING_POINTS[r#type] + chain_length - DOUBLE_BOND * excess,
+        });
+    }
+    Ok(species)
+}
+
+/// Melting point of the monoacid saturated triacylglycerol, interpolated
+/// between the even carbon numbers and clamped to the 8:0 and the 24:0
+fn saturated(carbon: f64) -> f64 {
+    let position = ((carbon - 8.0) / 2.0).clamp(0.0, (SATURATED.len() - 1) as f64);
+    let index = (position as usize).min(SATURATED.len() - 2);
+    SATURATED[index] + (position - index as f64) * (SATURATED[index + 1] - SATURATED[index])
+}
+
+/// Properties of the replicate
+///
+/// The type sums and the solid fat content are percents, the melting range is
+/// the weighted quantiles of the melting points.
+fn properties_of(species: &[Species], weights: &[f64]) -> Vec<Option<f64>> {
+    let total = weights.iter().sum::<f64>();
+    if !total.is_finite() || total <= 0.0 {
+        return vec![None; TYPES.len() + 2 + TEMPERATURES.len()];
+    }
+    let mut properties = Vec::with_capacity(TYPES.len() + 2 + TEMPERATURES.len());
+    // Types
+    let mut types = [0.0; TYPES.len()];
+    for (species, weight) in species.iter().zip(weights) {
+        types[species.r#type] += weight;
+    }
+    properties.extend(types.map(|value| Some(100.0 * value / total)));
+    // Melting range
+    let mut melting_points = species
+        .iter()
+        .zip(weights)
+        .filter(|&(_, &weight)| weight > 0.0)
+        .map(|(species, &weight)| (species.melting_point, weight / total))
+        .collect::<Vec<_>>();
+    melting_points.sort_by(|left, right| left.0.total_cmp(&right.0));
+    for quantile in RANGE {
+        let mut cumulative = 0.0;
+        properties.push(
+            melting_points
+                .iter()
+                .find(|&&(_, weight)| {
+                    cumulative += weight;
+                    cumulative >= quantile
+                })
+                .map(|&(melting_point, _)| melting_point),
+        );
+    }
+    // Solid fat content
+    for temperature in TEMPERATURES {
+        let solid = species
+            .iter()
+            .zip(weights)
+            .map(|(species, weight)| {
+                weight * ((species.melting_point - temperature as f64) / WIDTH).clamp(0.0, 1.0)
+            })
+            .sum::<f64>();
+        properties.push(Some(100.0 * solid / total));
+    }
+    properties
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn saturated() {
+        assert_eq!(super::saturated(12.0), 46.5);
+        assert_eq!(super::saturated(PALMITIC), MELTING_POINTS[0]);
+        assert_eq!(super::saturated(17.0), 69.5);
+        assert_eq!(super::saturated(4.0), 10.0);
+        assert_eq!(super::saturated(26.0), 86.0);
+    }
+}
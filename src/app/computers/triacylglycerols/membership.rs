@@ -0,0 +1,64 @@
+use super::presence;
+use crate::{
+    app::{
+        computers::intersections,
+        states::{fatty_acids::settings::Threshold, triacylglycerols::settings::Settings},
+    },
+    r#const::{COMPOSITION, SPECIES, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Membership computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Membership computer
+///
+/// Counts the species shared by every combination of the samples.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let names = key
+            .frame
+            .data_frame
+            .schema()
+            .iter_names_cloned()
+            .filter(|name| !matches!(name.as_str(), COMPOSITION | SPECIES | THRESHOLD))
+            .collect::<Vec<_>>();
+        let lazy_frame = key.frame.data_frame.clone().lazy();
+        intersections(lazy_frame, &names, |name| presence(name, key.threshold)).collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Membership key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Membership value
+type Value = DataFrame;
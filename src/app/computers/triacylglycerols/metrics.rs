@@ -1,6 +1,13 @@
+use super::table::labels;
 use crate::{
-    app::states::{fatty_acids::settings::Metric, triacylglycerols::settings::Settings},
-    r#const::{COMPOSITION, MEAN, SPECIES, THRESHOLD},
+    app::{
+        computers::{bounds, means, metric_intervals, transform},
+        states::{
+            fatty_acids::settings::{Bootstrap, Metric, Transformation},
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{COMPOSITION, SPECIES, THRESHOLD},
     utils::HashedDataFrame,
 };
 use egui::util::cache::{ComputerMut, FrameCache};
@@ -18,49 +25,43 @@ pub(crate) struct Computer;
 impl Computer {
     #[instrument(skip(self), err)]
     fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
-        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        let mut lazy_frame = mean(key)?.lazy();
         // println!("Metrics 0: {}", lazy_frame.clone().collect().unwrap());
-        lazy_frame = lazy_frame.select([all()
-            .exclude_cols([COMPOSITION, SPECIES, THRESHOLD])
-            .as_expr()]);
         let schema = lazy_frame.collect_schema()?;
         // Метрики сравниваем по среднему, потому как сравнивать повторности
         // пришлось бы попарно все пары.
-        let mean = |expr: Expr| expr.struct_().field_by_name(MEAN).fill_null(0);
         let exprs = schema
             .iter_names_cloned()
             .map(|left| -> PolarsResult<_> {
                 Ok(concat_arr(vec![match key.metric {
                     // Similarity between two discrete probability distributions
                     Metric::HellingerDistance => {
-                        hellinger_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        hellinger_distance(col(left.clone()), all().as_expr())
                     }
                     Metric::JensenShannonDistance => {
-                        jensen_shannon_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        jensen_shannon_distance(col(left.clone()), all().as_expr())
                     }
                     Metric::BhattacharyyaDistance => {
-                        bhattacharyya_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        bhattacharyya_distance(col(left.clone()), all().as_expr())
                     }
                     // Distance between two points
                     Metric::ChebyshevDistance => {
-                        chebyshev_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        chebyshev_distance(col(left.clone()), all().as_expr())
                     }
                     Metric::EuclideanDistance => {
-                        euclidean_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        euclidean_distance(col(left.clone()), all().as_expr())
                     }
                     Metric::ManhattanDistance => {
-                        manhattan_distance(mean(col(left.clone())), mean(all().as_expr()))
+                        manhattan_distance(col(left.clone()), all().as_expr())
                     }
-                    // Distance between two series
-                    Metric::CosineDistance => {
-                        cosine_distance(mean(col(left.clone())), mean(all().as_expr()))
-                    }
-                    Metric::JaccardDistance => {
-                        jaccard_distance(mean(col(left.clone())), mean(all().as_expr()))
-                    }
-                    Metric::OverlapDistance => {
-                        overlap_distance(mean(col(left.clone())), mean(all().as_expr()))
+                    // Distance between two compositions
+                    Metric::AitchisonDistance => {
+                        euclidean_distance(col(left.clone()), all().as_expr())
                     }
+                    // Distance between two series
+                    Metric::CosineDistance => cosine_distance(col(left.clone()), all().as_expr()),
+                    Metric::JaccardDistance => jaccard_distance(col(left.clone()), all().as_expr()),
+                    Metric::OverlapDistance => overlap_distance(col(left.clone()), all().as_expr()),
                 }])?
                 .alias(left))
             })
@@ -105,7 +106,9 @@ impl Computer {
         //     ]);
 
         // println!("Metrics 1: {}", lazy_frame.clone().collect().unwrap());
-        lazy_frame.collect()
+        let mut data_frame = lazy_frame.collect()?;
+        bounds(&mut data_frame, &intervals(key)?)?;
+        Ok(data_frame)
     }
 }
 
@@ -119,14 +122,20 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash)]
 pub(crate) struct Key<'a> {
     frame: &'a HashedDataFrame,
+    bootstrap: &'a Bootstrap,
+    composition: Composition,
     metric: Metric,
+    transformation: &'a Transformation,
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
+            bootstrap: &settings.bootstrap,
+            composition: settings.composition,
             metric: settings.metric,
+            transformation: &settings.transformation,
         }
     }
 }
@@ -134,6 +143,56 @@ impl<'a> Key<'a> {
 /// Metrics value
 type Value = DataFrame;
 
+/// Intervals
+///
+/// The bootstrap intervals of the metric between the samples.
+fn intervals(key: Key) -> PolarsResult<Vec<Vec<Option<[f64; 2]>>>> {
+    if !key.bootstrap.enabled {
+        return Ok(Vec::new());
+    }
+    let names = key
+        .frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
+        .collect::<Vec<_>>();
+    metric_intervals(
+        key.frame,
+        &names,
+        &labels(key.frame, key.composition)?,
+        key.transformation,
+        key.metric,
+        key.bootstrap,
+    )
+}
+
+/// Mean
+///
+/// The transformed sample means, the absent values are zero.
+fn mean(key: Key) -> PolarsResult<DataFrame> {
+    let names = key
+        .frame
+        .get_column_names_str()
+        .into_iter()
+        .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
+        .collect::<Vec<_>>();
+    let mut means = means(key.frame, &names)?;
+    transform(
+        &mut means,
+        &labels(key.frame, key.composition)?,
+        key.transformation,
+        key.metric,
+    );
+    let columns = names
+        .into_iter()
+        .zip(means)
+        .map(|(name, means)| Column::new(name.into(), means))
+        .collect::<Vec<_>>();
+    let mut output = key.frame.select([COMPOSITION])?;
+    output.hstack_mut(&columns)?;
+    Ok(output.drop(COMPOSITION)?)
+}
+
 // fn hierarchical_cluster(data_frame: DataFrame) {
 // use linfa::{Dataset, DatasetBase, dataset::Records, traits::Transformer as _};
 // use linfa_hierarchical::HierarchicalCluster;
@@ -1,16 +1,23 @@
 use crate::{
-    app::states::{
-        fatty_acids::settings::{Filter, Sort, Threshold},
-        triacylglycerols::{
-            composition::{
-                Composition, ECN_MONO, ECN_STEREO, MASS_MONO, MASS_STEREO, SPECIES_MONO,
-                SPECIES_POSITIONAL, SPECIES_STEREO, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO,
-                UNSATURATION_MONO, UNSATURATION_STEREO,
+    app::{
+        computers::{self, dispersion, group, membership},
+        states::{
+            fatty_acids::settings::{Dispersion, Filter, Groups, Sort, Threshold},
+            triacylglycerols::{
+                composition::{
+                    CN_MONO, CN_STEREO, CNDB_MONO, CNDB_STEREO, Composition, ECN_MONO, ECN_STEREO,
+                    MASS_MONO, MASS_STEREO, PN_MONO, PN_STEREO, SPECIES_MONO, SPECIES_POSITIONAL,
+                    SPECIES_STEREO, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO,
+                    UNSATURATION_STEREO,
+                },
+                settings::Settings,
             },
-            settings::Settings,
         },
     },
-    r#const::{COMPOSITION, MEAN, SAMPLE, SPECIES, STANDARD_DEVIATION, THRESHOLD, VALUE},
+    r#const::{
+        CARBON_NUMBER, COMPOSITION, DOUBLE_BOND_NUMBER, MEAN, SAMPLE, SPECIES, STANDARD_DEVIATION,
+        THRESHOLD, VALUE,
+    },
     utils::{HashedDataFrame, HashedMetaDataFrame, polars::eval_arr},
 };
 use egui::util::cache::{ComputerMut, FrameCache};
@@ -52,7 +59,10 @@ pub(crate) struct Key<'a> {
     pub(crate) frames: &'a [HashedMetaDataFrame],
     pub(crate) composition: Composition,
     pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     pub(crate) filter: Filter,
+    pub(crate) groups: &'a Groups,
+    pub(crate) membership: Option<u64>,
     pub(crate) sort: Option<Sort>,
     pub(crate) threshold: &'a Threshold,
 }
@@ -63,7 +73,10 @@ impl<'a> Key<'a> {
             frames,
             composition: settings.composition,
             ddof: settings.ddof,
+            dispersion: settings.dispersion,
             filter: settings.filter,
+            groups: &settings.groups,
+            membership: settings.membership,
             sort: settings.sort,
             threshold: &settings.threshold,
         }
@@ -74,16 +87,18 @@ impl<'a> Key<'a> {
 type Value = HashedDataFrame;
 
 fn compute(key: Key) -> PolarsResult<LazyFrame> {
-    let mut lazy_frame = join(key)?;
+    let mut lazy_frame = join(key.frames)?;
     lazy_frame = compose(lazy_frame, key)?;
+    lazy_frame = groups(lazy_frame, key)?;
     lazy_frame = filter(lazy_frame, key)?;
     lazy_frame = threshold(lazy_frame, key)?;
+    lazy_frame = intersection(lazy_frame, key)?;
     lazy_frame = sort(lazy_frame, key);
     Ok(lazy_frame)
 }
 
 /// Join
-fn join(key: Key) -> PolarsResult<LazyFrame> {
+fn join(frames: &[HashedMetaDataFrame]) -> PolarsResult<LazyFrame> {
     let compute = |frame: &HashedMetaDataFrame| -> PolarsResult<LazyFrame> {
         Ok(frame.data.data_frame.clone().lazy().select([
             col(LABEL),
@@ -91,8 +106,8 @@ fn join(key: Key) -> PolarsResult<LazyFrame> {
             col(VALUE).alias(frame.meta.format(".").to_string()),
         ]))
     };
-    let mut lazy_frame = compute(&key.frames[0])?;
-    for frame in &key.frames[1..] {
+    let mut lazy_frame = compute(&frames[0])?;
+    for frame in &frames[1..] {
         lazy_frame = lazy_frame.join(
             compute(frame)?,
             [col(LABEL), col(TRIACYLGLYCEROL)],
@@ -109,8 +124,63 @@ fn join(key: Key) -> PolarsResult<LazyFrame> {
 
 /// Compose
 fn compose(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    println!("GGG!!! 0: {}", lazy_frame.clone().collect()?);
-    let by = [match key.composition {
+    let by = [composition(key.composition).alias(COMPOSITION)];
+    let mut aggs = vec![
+        as_struct(vec![
+            col(LABEL),
+            col(TRIACYLGLYCEROL),
+            concat_list([all()
+                .exclude_cols([LABEL, TRIACYLGLYCEROL])
+                .as_expr()
+                .arr()
+                .mean()])?
+            .alias("Values"),
+        ])
+        .alias(SPECIES),
+    ];
+    for frame in key.frames {
+        let name = frame.meta.format(".").to_string();
+        // TODO SAMPLE
+        let array = eval_arr(col(&name), |expr| expr.sum())?;
+        aggs.push(
+            as_struct(vec![
+                array.clone().arr().mean().alias(MEAN),
+                dispersion(array.clone(), key.dispersion, key.ddof).alias(STANDARD_DEVIATION),
+                array.alias(SAMPLE),
+            ])
+            .alias(name),
+        );
+    }
+    lazy_frame = lazy_frame.group_by_stable(by).agg(aggs);
+    Ok(lazy_frame)
+}
+
+/// Partition number (PN = CN - 2DB)
+fn partition_number(expr: Expr) -> Expr {
+    expr.clone().fatty_acid().carbon().cast(DataType::Int32)
+        - lit(2) * expr.fatty_acid().unsaturation().cast(DataType::Int32)
+}
+
+/// Sum of the stereospecific numbers values
+fn sum_stereospecific_numbers(expr: Expr) -> Expr {
+    expr.clone()
+        .triacylglycerol()
+        .stereospecific_number1()
+        .cast(DataType::Int32)
+        + expr
+            .clone()
+            .triacylglycerol()
+            .stereospecific_number2()
+            .cast(DataType::Int32)
+        + expr
+            .triacylglycerol()
+            .stereospecific_number3()
+            .cast(DataType::Int32)
+}
+
+/// Composition
+pub(super) fn composition(composition: Composition) -> Expr {
+    match composition {
         MASS_MONO => col(TRIACYLGLYCEROL)
             .triacylglycerol()
             .relative_atomic_mass(None)
@@ -126,6 +196,36 @@ fn compose(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
         ECN_STEREO => col(TRIACYLGLYCEROL)
             .triacylglycerol()
             .map(|expr| expr.fatty_acid().equivalent_carbon_number()),
+        CN_MONO => sum_stereospecific_numbers(
+            col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .map(|expr| expr.fatty_acid().carbon()),
+        ),
+        CN_STEREO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().carbon()),
+        CNDB_MONO => as_struct(vec![
+            sum_stereospecific_numbers(
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .map(|expr| expr.fatty_acid().carbon()),
+            )
+            .alias(CARBON_NUMBER),
+            col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .unsaturation()
+                .alias(DOUBLE_BOND_NUMBER),
+        ]),
+        CNDB_STEREO => col(TRIACYLGLYCEROL).triacylglycerol().map(|expr| {
+            as_struct(vec![
+                expr.clone().fatty_acid().carbon().alias(CARBON_NUMBER),
+                expr.fatty_acid().unsaturation().alias(DOUBLE_BOND_NUMBER),
+            ])
+        }),
+        PN_MONO => {
+            sum_stereospecific_numbers(col(TRIACYLGLYCEROL).triacylglycerol().map(partition_number))
+        }
+        PN_STEREO => col(TRIACYLGLYCEROL).triacylglycerol().map(partition_number),
         SPECIES_MONO => col(LABEL).triacylglycerol().non_stereospecific(identity),
         SPECIES_POSITIONAL => col(LABEL).triacylglycerol().positional(identity),
         SPECIES_STEREO => col(LABEL),
@@ -147,36 +247,17 @@ fn compose(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
             .triacylglycerol()
             .map(|expr| expr.fatty_acid().unsaturation()),
     }
-    .alias(COMPOSITION)];
-    let mut aggs = vec![
-        as_struct(vec![
-            col(LABEL),
-            col(TRIACYLGLYCEROL),
-            concat_list([all()
-                .exclude_cols([LABEL, TRIACYLGLYCEROL])
-                .as_expr()
-                .arr()
-                .mean()])?
-            .alias("Values"),
-        ])
-        .alias(SPECIES),
-    ];
-    for frame in key.frames {
-        let name = frame.meta.format(".").to_string();
-        // TODO SAMPLE
-        let array = eval_arr(col(&name), |expr| expr.sum())?;
-        aggs.push(
-            as_struct(vec![
-                array.clone().arr().mean().alias(MEAN),
-                array.clone().arr().std(key.ddof).alias(STANDARD_DEVIATION),
-                array.alias(SAMPLE),
-            ])
-            .alias(name),
-        );
-    }
-    lazy_frame = lazy_frame.group_by_stable(by).agg(aggs);
-    println!("GGG!!! 1: {}", lazy_frame.clone().collect()?);
-    Ok(lazy_frame)
+}
+
+/// Groups
+fn groups(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    computers::groups(lazy_frame, &[COMPOSITION, SPECIES], key.groups, |members| {
+        let means = members
+            .iter()
+            .map(|&member| col(member).struct_().field_by_name(MEAN))
+            .collect();
+        group(means, key.dispersion, key.ddof)
+    })
 }
 
 /// Filter
@@ -256,12 +337,40 @@ fn threshold(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
 //     Ok(lazy_frame)
 // }
 
+/// Intersection
+///
+/// Keeps the rows present (above the threshold) in exactly the samples of the
+/// selected set membership.
+fn intersection(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    let Some(mask) = key.membership else {
+        return Ok(lazy_frame);
+    };
+    let presences = lazy_frame
+        .collect_schema()?
+        .iter_names()
+        .filter(|name| !matches!(name.as_str(), COMPOSITION | SPECIES | THRESHOLD))
+        .map(|name| presence(name, key.threshold))
+        .collect();
+    Ok(lazy_frame.filter(membership(presences).eq(lit(mask))))
+}
+
+/// Presence of the sample: the mean above the threshold
+///
+/// The absent species is zero, the fatty acid limits of detection do not
+/// apply to the triacylglycerols.
+pub(crate) fn presence(name: &str, threshold: &Threshold) -> Expr {
+    col(name)
+        .struct_()
+        .field_by_name(MEAN)
+        .fill_null(0)
+        .gt(threshold.auto.0)
+}
+
 /// Sort
 fn sort(mut lazy_frame: LazyFrame, key: Key) -> LazyFrame {
     if let Some(sort) = key.sort {
         match sort {
             Sort::Key => {
-                println!("GGG!!! Sort0: {}", lazy_frame.clone().collect().unwrap());
                 lazy_frame = lazy_frame.sort_by_exprs(
                     // [col(COMPOSITION).over([col(THRESHOLD)])],
                     [col(COMPOSITION)],
@@ -285,6 +394,17 @@ fn sort(mut lazy_frame: LazyFrame, key: Key) -> LazyFrame {
     lazy_frame
 }
 
+pub(crate) mod clusters;
+pub(crate) mod comparison;
+pub(crate) mod decomposition;
+pub(crate) mod distribution;
+pub(crate) mod diversity;
+pub(crate) mod expected;
+pub(crate) mod hierarchy;
+pub(crate) mod melting;
+pub(crate) mod membership;
 pub(crate) mod metrics;
 pub(crate) mod moments;
+pub(crate) mod ordination;
+pub(crate) mod significance;
 pub(crate) mod table;
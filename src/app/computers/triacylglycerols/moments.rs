@@ -1,4 +1,5 @@
 use crate::{
+    app::states::triacylglycerols::settings::Settings,
     r#const::{COMPOSITION, MEAN, SPECIES, THRESHOLD},
     utils::HashedDataFrame,
 };
@@ -18,9 +19,7 @@ impl Computer {
     #[instrument(skip(self), err)]
     fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
-        println!("Moments 0: {}", lazy_frame.clone().collect().unwrap());
         lazy_frame = compute(lazy_frame, key)?;
-        println!("Moments 2: {}", lazy_frame.clone().collect().unwrap());
         lazy_frame.collect()
     }
 }
@@ -38,6 +37,15 @@ pub(crate) struct Key<'a> {
     pub(crate) bias: bool,
 }
 
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+        Self {
+            frame,
+            bias: settings.bias,
+        }
+    }
+}
+
 /// Moments value
 type Value = DataFrame;
 
@@ -67,7 +75,6 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     //         },
     //         |_, field| Ok(field.clone()),
     //     )]);
-    println!("Moments x: {}", lazy_frame.clone().collect().unwrap());
     let schema = lazy_frame.collect_schema()?;
     let skewness = lazy_frame.clone().select(
         once(lit("Skewness").alias("Moment"))
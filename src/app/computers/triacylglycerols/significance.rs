@@ -0,0 +1,85 @@
+use super::table::labels;
+use crate::{
+    app::{
+        computers::{observations, significance, transform},
+        states::{
+            fatty_acids::settings::{Groups, Metric, Permutations, Transformation},
+            triacylglycerols::{composition::Composition, settings::Settings},
+        },
+    },
+    r#const::{COMPOSITION, SPECIES, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Significance computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Significance computer
+///
+/// Tests the difference of the groups with PERMANOVA and ANOSIM on the
+/// replicate-level distance matrix of the composition profiles.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        if key.frame.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let names = key
+            .frame
+            .get_column_names_str()
+            .into_iter()
+            .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
+            .collect::<Vec<_>>();
+        let mut observations = observations(key.frame, &names)?;
+        transform(
+            observations
+                .iter_mut()
+                .map(|observation| &mut observation.values),
+            &labels(key.frame, key.composition)?,
+            key.transformation,
+            key.metric,
+        );
+        significance(&observations, key.groups, key.metric, key.permutations)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Significance key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) groups: &'a Groups,
+    pub(crate) metric: Metric,
+    pub(crate) permutations: Permutations,
+    pub(crate) transformation: &'a Transformation,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            composition: settings.composition,
+            groups: &settings.groups,
+            metric: settings.metric,
+            permutations: settings.permutations,
+            transformation: &settings.transformation,
+        }
+    }
+}
+
+/// Significance value
+///
+/// Rows are the tests: the statistic, R² and the permutation p-value.
+type Value = DataFrame;
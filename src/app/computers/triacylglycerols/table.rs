@@ -1,14 +1,21 @@
 use crate::{
-    app::states::triacylglycerols::{
-        composition::{
-            Composition, ECN_MONO, ECN_STEREO, MASS_MONO, MASS_STEREO, SPECIES_MONO,
-            SPECIES_POSITIONAL, SPECIES_STEREO, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO,
-            UNSATURATION_MONO, UNSATURATION_STEREO,
+    app::{
+        computers::{self, TableFormat},
+        states::{
+            fatty_acids::settings::{Dispersion, Reference},
+            triacylglycerols::{
+                composition::{
+                    CN_MONO, CN_STEREO, CNDB_MONO, CNDB_STEREO, Composition, ECN_MONO, ECN_STEREO,
+                    MASS_MONO, MASS_STEREO, PN_MONO, PN_STEREO, SPECIES_MONO, SPECIES_POSITIONAL,
+                    SPECIES_STEREO, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO,
+                    UNSATURATION_STEREO,
+                },
+                settings::Settings,
+            },
         },
-        settings::Settings,
     },
-    r#const::{COMPOSITION, EM_DASH, MEAN, SAMPLE, SPECIES, STANDARD_DEVIATION, THRESHOLD},
-    utils::{HashedDataFrame, polars::eval_arr},
+    r#const::{CARBON_NUMBER, COMPOSITION, DOUBLE_BOND_NUMBER, EM_DASH, SPECIES, THRESHOLD},
+    utils::HashedDataFrame,
 };
 use egui::util::cache::{ComputerMut, FrameCache};
 use lipid::prelude::*;
@@ -43,19 +50,23 @@ pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
     pub(crate) composition: Composition,
     pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     pub(crate) percent: bool,
     pub(crate) precision: usize,
+    pub(crate) reference: &'a Reference,
     pub(crate) significant: bool,
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &Settings) -> Self {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
             composition: settings.composition,
-            ddof: 1,
+            ddof: settings.ddof,
+            dispersion: settings.dispersion,
             percent: settings.percent,
             precision: settings.precision,
+            reference: &settings.reference,
             significant: settings.significant,
         }
     }
@@ -65,94 +76,105 @@ impl<'a> Key<'a> {
 type Value = DataFrame;
 
 fn format(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let mut exprs = vec![label(key)?, species(key)?];
-    let mut sum = Vec::new();
-    for name in key
+    let names = key
         .frame
         .get_column_names_str()
         .into_iter()
         .filter(|&name| !matches!(name, COMPOSITION | SPECIES | THRESHOLD))
-    {
-        exprs.push(
-            as_struct(vec![
-                format_mean(col(name).struct_().field_by_name(MEAN), key),
-                format_standard_deviation(
-                    col(name).struct_().field_by_name(STANDARD_DEVIATION),
-                    key,
-                ),
-                format_sample(col(name).struct_().field_by_name(SAMPLE), key),
-            ])
-            .alias(name),
-        );
-        let array = eval_arr(col(name).struct_().field_by_name(SAMPLE), |expr| {
-            expr.filter(THRESHOLD).sum()
-        })?;
-        sum.push(
-            as_struct(vec![
-                format_mean(array.clone().arr().mean(), key).alias(MEAN),
-                format_standard_deviation(array.clone().arr().std(key.ddof), key)
-                    .alias(STANDARD_DEVIATION),
-                format_sample(array, key).alias(SAMPLE),
-            ])
-            .alias(name),
-        );
-    }
+        .collect::<Vec<_>>();
+    let (rows, sums) = computers::table(
+        &lazy_frame,
+        &names,
+        TableFormat {
+            ddof: key.ddof,
+            dispersion: key.dispersion,
+            percent: key.percent,
+            precision: key.precision,
+            reference: key.reference,
+            significant: key.significant,
+        },
+        false,
+    )?;
+    let mut exprs = vec![
+        label(key.composition, col(COMPOSITION))?.alias(LABEL),
+        species(key)?,
+    ];
+    exprs.extend(rows);
     concat_lf_diagonal(
-        [lazy_frame.clone().select(exprs), lazy_frame.select(sum)],
+        [lazy_frame.clone().select(exprs), lazy_frame.select(sums)],
         UnionArgs::default(),
     )
 }
 
-fn label(key: Key) -> PolarsResult<Expr> {
-    Ok(match key.composition {
-        ECN_MONO | MASS_MONO | UNSATURATION_MONO => format_str("({})", [col(COMPOSITION)])?,
-        SPECIES_MONO | TYPE_MONO => format_str(
-            "[{}/3;{}/3;{}/3]",
+/// Composition labels of the rows
+pub(super) fn labels(
+    data_frame: &DataFrame,
+    composition: Composition,
+) -> PolarsResult<StringChunked> {
+    let labels = data_frame
+        .clone()
+        .lazy()
+        .select([label(composition, col(COMPOSITION))?.alias(LABEL)])
+        .collect()?;
+    Ok(labels[LABEL].str()?.clone())
+}
+
+/// Composition label
+pub(super) fn label(composition: Composition, expr: Expr) -> PolarsResult<Expr> {
+    Ok(match composition {
+        CN_MONO | ECN_MONO | MASS_MONO | PN_MONO | UNSATURATION_MONO => {
+            format_str("({})", [expr.clone()])?
+        }
+        CNDB_MONO => format_str(
+            "({}:{})",
             [
-                col(COMPOSITION).triacylglycerol().stereospecific_number1(),
-                col(COMPOSITION).triacylglycerol().stereospecific_number2(),
-                col(COMPOSITION).triacylglycerol().stereospecific_number3(),
+                expr.clone().struct_().field_by_name(CARBON_NUMBER),
+                expr.clone().struct_().field_by_name(DOUBLE_BOND_NUMBER),
             ],
         )?,
-        ECN_STEREO | MASS_STEREO | SPECIES_STEREO | TYPE_STEREO | UNSATURATION_STEREO => {
+        CNDB_STEREO => {
+            let number = |expr: Expr| {
+                [
+                    expr.clone().struct_().field_by_name(CARBON_NUMBER),
+                    expr.struct_().field_by_name(DOUBLE_BOND_NUMBER),
+                ]
+            };
             format_str(
-                "[{};{};{}]",
+                "[{}:{};{}:{};{}:{}]",
                 [
-                    col(COMPOSITION).triacylglycerol().stereospecific_number1(),
-                    col(COMPOSITION).triacylglycerol().stereospecific_number2(),
-                    col(COMPOSITION).triacylglycerol().stereospecific_number3(),
-                ],
+                    number(expr.clone().triacylglycerol().stereospecific_number1()),
+                    number(expr.clone().triacylglycerol().stereospecific_number2()),
+                    number(expr.clone().triacylglycerol().stereospecific_number3()),
+                ]
+                .concat(),
             )?
         }
+        SPECIES_MONO | TYPE_MONO => format_str(
+            "[{}/3;{}/3;{}/3]",
+            [
+                expr.clone().triacylglycerol().stereospecific_number1(),
+                expr.clone().triacylglycerol().stereospecific_number2(),
+                expr.clone().triacylglycerol().stereospecific_number3(),
+            ],
+        )?,
+        CN_STEREO | ECN_STEREO | MASS_STEREO | PN_STEREO | SPECIES_STEREO | TYPE_STEREO
+        | UNSATURATION_STEREO => format_str(
+            "[{};{};{}]",
+            [
+                expr.clone().triacylglycerol().stereospecific_number1(),
+                expr.clone().triacylglycerol().stereospecific_number2(),
+                expr.clone().triacylglycerol().stereospecific_number3(),
+            ],
+        )?,
         SPECIES_POSITIONAL | TYPE_POSITIONAL => format_str(
             "[{}/2;{};{}/2]",
             [
-                col(COMPOSITION).triacylglycerol().stereospecific_number1(),
-                col(COMPOSITION).triacylglycerol().stereospecific_number2(),
-                col(COMPOSITION).triacylglycerol().stereospecific_number3(),
+                expr.clone().triacylglycerol().stereospecific_number1(),
+                expr.clone().triacylglycerol().stereospecific_number2(),
+                expr.clone().triacylglycerol().stereospecific_number3(),
             ],
         )?,
-    }
-    .alias(LABEL))
-}
-
-fn format_mean(expr: Expr, key: Key) -> Expr {
-    expr.percent(key.percent)
-        .precision(key.precision, key.significant)
-}
-
-fn format_standard_deviation(expr: Expr, key: Key) -> Expr {
-    expr.percent(key.percent)
-        .precision(key.precision + 1, key.significant)
-}
-
-fn format_sample(expr: Expr, key: Key) -> Expr {
-    expr.arr().eval(
-        element()
-            .percent(key.percent)
-            .precision(key.precision, key.significant),
-        false,
-    )
+    })
 }
 
 fn species(key: Key) -> PolarsResult<Expr> {
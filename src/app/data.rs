@@ -1,10 +1,23 @@
-use crate::utils::HashedMetaDataFrame;
-use egui::{Frame, Id, Label, MenuBar, RichText, ScrollArea, TopBottomPanel, Ui};
+use crate::{
+    app::computers::blend::{Computed as BlendComputed, Key as BlendKey, MIXINGS, Mixing},
+    utils::HashedMetaDataFrame,
+};
+use egui::{
+    DragValue, Frame, Grid, Id, Label, MenuBar, RichText, ScrollArea, TopBottomPanel, Ui,
+    util::hash,
+};
 use egui_dnd::dnd;
-use egui_phosphor::regular::{CHECK, DOTS_SIX_VERTICAL, INTERSECT_THREE, PLUS, TRASH};
-use metadata::egui::MetadataWidget;
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::{BLENDER, CHECK, DOTS_SIX_VERTICAL, INTERSECT_THREE, PLUS, TRASH};
+use lipid::prelude::*;
+use maplit::btreemap;
+use metadata::{DESCRIPTION, Metadata, NAME, egui::MetadataWidget};
+use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 
 /// Data
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -62,6 +75,8 @@ impl Data {
 pub struct VecAndHashSet {
     pub frames: Vec<HashedMetaDataFrame>,
     pub selected: HashSet<HashedMetaDataFrame>,
+    #[serde(skip)]
+    blend: Blend,
 }
 
 impl VecAndHashSet {
@@ -71,7 +86,7 @@ impl VecAndHashSet {
         }
     }
 
-    fn selected(&self) -> Vec<HashedMetaDataFrame> {
+    pub fn selected(&self) -> Vec<HashedMetaDataFrame> {
         self.frames
             .iter()
             .filter_map(|frame| self.selected.contains(frame).then_some(frame.clone()))
@@ -85,6 +100,8 @@ impl VecAndHashSet {
         ui.separator();
         self.join(ui, id);
         ui.separator();
+        self.blend(ui);
+        ui.separator();
     }
 
     fn check(&mut self, ui: &mut Ui) {
@@ -127,6 +144,94 @@ impl VecAndHashSet {
         });
     }
 
+    fn blend(&mut self, ui: &mut Ui) {
+        let frames = self.selected();
+        ui.add_enabled_ui(frames.len() > 1, |ui| {
+            ui.menu_button(RichText::new(BLENDER).heading(), |ui| {
+                ui.heading(ui.localize("Blend"))
+                    .on_hover_localized("Blend.hover");
+                ui.separator();
+                // Weight fractions, percents
+                let ids = frames.iter().map(hash).collect::<HashSet<_>>();
+                self.blend.fractions.retain(|id, _| ids.contains(id));
+                let default = 100.0 / frames.len() as f64;
+                Grid::new(ui.auto_id_with("Blend")).show(ui, |ui| {
+                    for frame in &frames {
+                        let fraction = self.blend.fractions.entry(hash(frame)).or_insert(default);
+                        ui.add(
+                            DragValue::new(fraction)
+                                .range(0.0..=100.0)
+                                .speed(0.1)
+                                .suffix("%"),
+                        );
+                        ui.label(frame.meta.format(" ").to_string());
+                        ui.end_row();
+                    }
+                });
+                // Mixing of the triacylglycerols
+                let triacylglycerols = frames[0].data.schema().contains(TRIACYLGLYCEROL);
+                if triacylglycerols {
+                    ui.separator();
+                    for mixing in MIXINGS {
+                        ui.radio_value(&mut self.blend.mixing, mixing, ui.localize(mixing.text()))
+                            .on_hover_localized(mixing.hover_text());
+                    }
+                }
+                ui.separator();
+                if ui.button((BLENDER, ui.localize("Blend"))).clicked() {
+                    let mixing = if triacylglycerols {
+                        self.blend.mixing
+                    } else {
+                        Mixing::Simple
+                    };
+                    self.add_blend(ui, &frames, mixing);
+                }
+            });
+        });
+    }
+
+    /// Adds the blend of the selected frames to the data
+    fn add_blend(&self, ui: &mut Ui, frames: &[HashedMetaDataFrame], mixing: Mixing) {
+        let fractions = frames
+            .iter()
+            .map(|frame| OrderedFloat(self.blend.fractions[&hash(frame)]))
+            .collect::<Vec<_>>();
+        let total = fractions.iter().map(|fraction| fraction.0).sum::<f64>();
+        if !total.is_finite() || total <= 0.0 {
+            return;
+        }
+        let data = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<BlendComputed>()
+                .get(BlendKey::new(frames, &fractions, mixing))
+        });
+        if data.is_empty() {
+            return;
+        }
+        // Recipe: 70% К-2233 + 30% К-3110
+        let components = frames
+            .iter()
+            .zip(&fractions)
+            .map(|(frame, fraction)| {
+                let percent = (fraction.0 / total * 1000.0).round() / 10.0;
+                format!("{percent}% {}", frame.meta.format(" "))
+            })
+            .collect::<Vec<_>>();
+        let mut name = components.join(" + ");
+        let mut description = components.join("\n");
+        if mixing == Mixing::Interesterification {
+            name.push_str(&format!(" ({})", mixing.id()));
+            description.push_str(&format!("\n{}", mixing.id()));
+        }
+        let meta = Metadata(btreemap! {
+            DESCRIPTION.to_owned() => description,
+            NAME.to_owned() => name,
+        });
+        let frame = HashedMetaDataFrame::new(meta, data);
+        ui.data_mut(|data| data.insert_temp(Id::new("Data"), vec![frame]));
+    }
+
     fn central(&mut self, ui: &mut Ui, id: impl Hash) {
         dnd(ui, ui.auto_id_with(id)).show_vec(&mut self.frames, |ui, frame, handle, _state| {
             ui.horizontal(|ui| {
@@ -148,3 +253,13 @@ impl VecAndHashSet {
         });
     }
 }
+
+/// Blend
+///
+/// The weight fractions of the selected samples by the hashes of the samples,
+/// percents, and the mixing of the triacylglycerols.
+#[derive(Clone, Debug, Default)]
+struct Blend {
+    fractions: HashMap<u64, f64>,
+    mixing: Mixing,
+}
@@ -50,6 +50,19 @@ fn custom_style(ctx: &Context) {
     ctx.set_style(style);
 }
 
+/// Schema with the zero array widths
+///
+/// The replicate counts of the samples differ, the expected schemas do not
+/// fix them.
+fn unsized_arrays(schema: &Schema) -> Schema {
+    Schema::from_iter(schema.iter_fields().map(|field| match field.dtype() {
+        DataType::Array(inner, _) => {
+            Field::new(field.name().clone(), DataType::Array(inner.clone(), 0))
+        }
+        _ => field,
+    }))
+}
+
 fn custom_visuals<T: BorrowMut<Visuals>>(mut visuals: T) -> T {
     visuals.borrow_mut().collapsing_header_frame = true;
     visuals
@@ -352,7 +365,7 @@ impl App {
             ctx.data_mut(|data| data.remove_temp::<Vec<HashedMetaDataFrame>>(Id::new("Data")))
         {
             for frame in frames {
-                let schema = frame.data.schema();
+                let schema = &unsized_arrays(frame.data.schema());
                 if COMPOSITION.matches_schema(schema).is_ok_and(|cast| !cast) {
                     info!("COMPOSITION");
                     self.data.triacylglycerols.add(frame);
@@ -378,8 +391,10 @@ impl App {
         } else if let Some(frames) = ctx.data_mut(|data| {
             data.remove_temp::<Vec<HashedMetaDataFrame>>(Id::new("Join").with("Triacylglycerols"))
         }) {
+            // The selected fatty acids are joined to the triacylglycerols
+            let fatty_acids = self.data.fatty_acids.selected();
             self.tree
-                .insert_pane::<VERTICAL>(Pane::triacylglycerols(frames));
+                .insert_pane::<VERTICAL>(Pane::triacylglycerols(frames, fatty_acids));
         }
     }
 
@@ -3,9 +3,9 @@ use crate::{
         panes::MARGIN,
         states::fatty_acids::{ID_SOURCE, settings::Settings},
     },
-    r#const::{EM_DASH, MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
+    r#const::{EM_DASH, LOWER, MEAN, SAMPLE, STANDARD_DEVIATION, THRESHOLD, UPPER},
 };
-use egui::{Id, TextStyle, TextWrapMode, Ui, WidgetText};
+use egui::{Id, TextStyle, TextWrapMode, Ui};
 use egui_extras::{Column, TableBuilder};
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::HASH;
@@ -89,23 +89,40 @@ impl<'a> Factors<'a> {
                     .struct_()?
                     .field_by_name(STANDARD_DEVIATION)?;
                 let standard_deviation = standard_deviation_series.f64()?.get(row);
-                let text = match mean {
+                let lower_series = self.data_frame[column].struct_()?.field_by_name(LOWER)?;
+                let upper_series = self.data_frame[column].struct_()?.field_by_name(UPPER)?;
+                let interval = lower_series
+                    .f64()?
+                    .get(row)
+                    .zip(upper_series.f64()?.get(row));
+                let mut text = match mean {
                     Some(mean)
                         if self.settings.standard_deviation
                             && let Some(standard_deviation) = standard_deviation =>
                     {
-                        WidgetText::from(format!("{mean} ±{standard_deviation}"))
+                        format!("{mean} ±{standard_deviation}")
                     }
-                    Some(mean) => WidgetText::from(mean.to_string()),
-                    None => WidgetText::from(EM_DASH),
+                    Some(mean) => mean.to_string(),
+                    None => EM_DASH.to_owned(),
                 };
+                if let Some((lower, upper)) = interval {
+                    text = format!("{text} [{lower}, {upper}]");
+                }
                 let mut response = ui.label(text);
                 if response.hovered() {
+                    // Bootstrap interval
+                    if let Some((lower, upper)) = interval {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(self.settings.bootstrap.interval.text()));
+                            ui.label(format!("[{lower}, {upper}]"));
+                        });
+                    }
                     // Standard deviation
                     if let Some(standard_deviation) = standard_deviation {
                         response = response.on_hover_ui(|ui| {
                             ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                            ui.heading(ui.localize(STANDARD_DEVIATION));
+                            ui.heading(ui.localize(self.settings.dispersion.text()));
                             ui.label(format!("±{standard_deviation}"));
                         });
                     }
@@ -1,21 +1,31 @@
 use crate::{
     app::{
+        computers::fatty_acids::biodiesel::Standard,
         panes::MARGIN,
         states::fatty_acids::{ID_SOURCE, settings::Settings},
     },
-    r#const::{EM_DASH, MEAN, SAMPLE, STANDARD_DEVIATION},
+    r#const::{EM_DASH, LOWER, MEAN, REFERENCE_INTAKE, SAMPLE, STANDARD_DEVIATION, UPPER},
 };
-use egui::{Id, TextStyle, TextWrapMode, Ui, WidgetText};
+use egui::{Id, RichText, TextStyle, TextWrapMode, Ui};
 use egui_extras::{Column, TableBuilder};
 use egui_l20n::prelude::*;
+use egui_phosphor::regular::{CHECK, X};
 use polars::prelude::*;
 use polars_utils::format_list;
 use tracing::instrument;
 
 /// Indices widget
+///
+/// The table of the named rows of the indices, the properties and the like,
+/// every sample cell is the mean with the dispersion and the bootstrap
+/// interval. The limits of the standards are the columns after the names, the
+/// means out of the limits are highlighted. The percent of the reference
+/// intake, if any, is in the hover.
 pub(crate) struct Indices<'a> {
     pub data_frame: &'a DataFrame,
     pub settings: &'a Settings,
+    pub id_salt: Id,
+    pub standards: &'a [Standard],
 }
 
 impl<'a> Indices<'a> {
@@ -23,15 +33,25 @@ impl<'a> Indices<'a> {
         Self {
             data_frame,
             settings,
+            id_salt: Id::new(ID_SOURCE).with("Indices"),
+            standards: &[],
         }
     }
 
+    pub(super) fn with_id_salt(self, id_salt: Id) -> Self {
+        Self { id_salt, ..self }
+    }
+
+    pub(super) fn with_standards(self, standards: &'a [Standard]) -> Self {
+        Self { standards, ..self }
+    }
+
     #[instrument(skip_all, err)]
     pub(crate) fn show(mut self, ui: &mut Ui) -> PolarsResult<()> {
-        let id_salt = Id::new(ID_SOURCE).with("Indices");
+        let id_salt = self.id_salt;
         let height = ui.text_style_height(&TextStyle::Heading);
         let rows = self.data_frame.height();
-        let columns = self.data_frame.width();
+        let columns = self.data_frame.width() + self.standards.len();
         ui.style_mut().wrap_mode = if self.settings.truncate {
             Some(TextWrapMode::Truncate)
         } else {
@@ -43,7 +63,18 @@ impl<'a> Indices<'a> {
             .resizable(true)
             .columns(Column::auto(), columns)
             .header(height + 2.0 * MARGIN.y, |mut row| {
-                for name in self.data_frame.schema().iter_names() {
+                let mut names = self.data_frame.schema().iter_names();
+                if let Some(name) = names.next() {
+                    row.col(|ui| {
+                        ui.heading(name.as_str());
+                    });
+                }
+                for standard in self.standards {
+                    row.col(|ui| {
+                        ui.heading(ui.localize(standard.text()));
+                    });
+                }
+                for name in names {
                     row.col(|ui| {
                         ui.heading(name.as_str());
                     });
@@ -65,34 +96,102 @@ impl<'a> Indices<'a> {
 
     #[instrument(skip(self, ui), err)]
     fn body_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
+        let name = self.data_frame[0].get(row)?.str_value();
         match column {
             0 => {
-                ui.label(ui.localize(&self.data_frame[0].get(row)?.str_value()));
+                ui.label(ui.localize(&name)).on_hover_ui(|ui| {
+                    ui.label(ui.localize(&format!("{name}.hover")));
+                });
+            }
+            column if column <= self.standards.len() => {
+                match self.standards[column - 1].limit(&name) {
+                    Some(limit) => ui.label(limit.to_string()),
+                    None => ui.label(EM_DASH),
+                };
             }
             column => {
+                let column = column - self.standards.len();
                 let mean_series = self.data_frame[column].struct_()?.field_by_name(MEAN)?;
                 let mean = mean_series.f64()?.get(row);
                 let standard_deviation_series = self.data_frame[column]
                     .struct_()?
                     .field_by_name(STANDARD_DEVIATION)?;
                 let standard_deviation = standard_deviation_series.f64()?.get(row);
-                let text = match mean {
+                let lower_series = self.data_frame[column].struct_()?.field_by_name(LOWER)?;
+                let upper_series = self.data_frame[column].struct_()?.field_by_name(UPPER)?;
+                let interval = lower_series
+                    .f64()?
+                    .get(row)
+                    .zip(upper_series.f64()?.get(row));
+                let reference_intake = match self.data_frame[column]
+                    .struct_()?
+                    .field_by_name(REFERENCE_INTAKE)
+                {
+                    Ok(reference_intake_series) => reference_intake_series.f64()?.get(row),
+                    Err(_) => None,
+                };
+                // Pass or fail of the standards with the limit
+                let checks = self
+                    .standards
+                    .iter()
+                    .filter_map(|standard| {
+                        let limit = standard.limit(&name)?;
+                        Some((standard, limit, limit.contains(mean?)))
+                    })
+                    .collect::<Vec<_>>();
+                let mut text = match mean {
                     Some(mean)
                         if self.settings.standard_deviation
                             && let Some(standard_deviation) = standard_deviation =>
                     {
-                        WidgetText::from(format!("{mean} ±{standard_deviation}"))
+                        format!("{mean} ±{standard_deviation}")
                     }
-                    Some(mean) => WidgetText::from(mean.to_string()),
-                    None => WidgetText::from(EM_DASH),
+                    Some(mean) => mean.to_string(),
+                    None => EM_DASH.to_owned(),
                 };
+                if let Some((lower, upper)) = interval {
+                    text = format!("{text} [{lower}, {upper}]");
+                }
+                let mut text = RichText::new(text);
+                if checks.iter().any(|&(_, _, pass)| !pass) {
+                    text = text.color(ui.visuals().error_fg_color);
+                }
                 let mut response = ui.label(text);
                 if response.hovered() {
+                    // Standards
+                    if !checks.is_empty() {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            for (standard, limit, pass) in &checks {
+                                ui.label(format!(
+                                    "{} {} {limit}",
+                                    if *pass { CHECK } else { X },
+                                    ui.localize(standard.text()),
+                                ));
+                            }
+                        });
+                    }
+                    // Reference intake
+                    if let Some(reference_intake) = reference_intake {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(REFERENCE_INTAKE));
+                            ui.label(format!("{reference_intake}%"));
+                        });
+                    }
+                    // Bootstrap interval
+                    if let Some((lower, upper)) = interval {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(self.settings.bootstrap.interval.text()));
+                            ui.label(format!("[{lower}, {upper}]"));
+                        });
+                    }
                     // Standard deviation
                     if let Some(standard_deviation) = standard_deviation {
                         response = response.on_hover_ui(|ui| {
                             ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                            ui.heading(ui.localize(STANDARD_DEVIATION));
+                            ui.heading(ui.localize(self.settings.dispersion.text()));
                             ui.label(format!("±{standard_deviation}"));
                         });
                     }
@@ -3,7 +3,7 @@ use crate::{
         panes::{MARGIN, metrics::Sign},
         states::fatty_acids::{ID_SOURCE, settings::Settings},
     },
-    r#const::EM_DASH,
+    r#const::{EM_DASH, LOWER, UPPER, VALUE},
 };
 use egui::{Id, TextStyle, TextWrapMode, Ui, WidgetText};
 use egui_extras::{Column, TableBuilder};
@@ -72,7 +72,14 @@ impl Metrics<'_> {
 
     #[instrument(skip(self, ui), err)]
     fn body_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
-        let text = match self.data_frame[column].f64()?.get(row) {
+        let metric_series = self.data_frame[column].struct_()?.field_by_name(VALUE)?;
+        let lower_series = self.data_frame[column].struct_()?.field_by_name(LOWER)?;
+        let upper_series = self.data_frame[column].struct_()?.field_by_name(UPPER)?;
+        let interval = lower_series
+            .f64()?
+            .get(row)
+            .zip(upper_series.f64()?.get(row));
+        let text = match metric_series.f64()?.get(row) {
             Some(metric) => {
                 let sign = Sign::from(metric);
                 let mut color = ui.style().visuals.text_color();
@@ -83,11 +90,22 @@ impl Metrics<'_> {
                         color = sign.color(color);
                     }
                 }
-                WidgetText::from(metric.to_string()).color(color)
+                let text = match interval {
+                    Some((lower, upper)) => format!("{metric} [{lower}, {upper}]"),
+                    None => metric.to_string(),
+                };
+                WidgetText::from(text).color(color)
             }
             None => WidgetText::from(EM_DASH),
         };
-        ui.label(text);
+        let response = ui.label(text);
+        if let Some((lower, upper)) = interval {
+            response.on_hover_ui(|ui| {
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                ui.heading(ui.localize(self.settings.bootstrap.interval.text()));
+                ui.label(format!("[{lower}, {upper}]"));
+            });
+        }
         Ok(())
     }
 }
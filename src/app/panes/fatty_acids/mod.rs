@@ -4,13 +4,33 @@ use crate::{
     app::{
         computers::fatty_acids::{
             Computed as FattyAcidsComputed, Key as FattyAcidsKey,
+            biodiesel::{Computed as BiodieselComputed, Key as BiodieselKey, STANDARDS},
+            clipping::{Computed as ClippingComputed, Key as ClippingKey},
+            clusters::{Computed as ClustersComputed, Key as ClustersKey},
+            comparison::{Computed as ComparisonComputed, Key as ComparisonKey},
+            diversity::{Computed as DiversityComputed, Key as DiversityKey},
             factors::{Computed as FactorsComputed, Key as FactorsKey},
             indices::{Computed as IndicesComputed, Key as IndicesKey},
+            membership::{Computed as MembershipComputed, Key as MembershipKey},
             metrics::{Computed as MetricsComputed, Key as MetricsKey},
+            nutrition::{Computed as NutritionComputed, Key as NutritionKey},
+            ordination::{Computed as OrdinationComputed, Key as OrdinationKey},
+            prediction::{Computed as PredictionComputed, Key as PredictionKey},
+            properties::{Computed as PropertiesComputed, Key as PropertiesKey},
+            significance::{Computed as SignificanceComputed, Key as SignificanceKey},
             table::{Computed as TableComputed, Key as TableKey},
         },
-        states::fatty_acids::{ID_SOURCE, State, settings::Settings},
+        states::fatty_acids::{
+            ID_SOURCE, State,
+            settings::{Dispersion, MODELS, Model, ORDINATIONS, Settings, UNGROUPED},
+        },
+        widgets::{
+            clusters::Clusters, comparison::Comparison, detection::DetectionWidget,
+            diversity::Diversity, groups::GroupsWidget, membership::Membership,
+            ordination::Ordination, significance::Significance,
+        },
     },
+    r#const::DISPERSION,
     export::ron,
     utils::{HashedDataFrame, HashedMetaDataFrame},
 };
@@ -21,10 +41,12 @@ use egui::{
 };
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::{
-    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, DROP, FLOPPY_DISK, GEAR, SIGMA, SLIDERS_HORIZONTAL, TAG, X,
+    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, BOWL_FOOD, CHART_SCATTER, CIRCLES_THREE, DROP, FLASK,
+    FLOPPY_DISK, GAS_PUMP, GEAR, INTERSECT, MAGNIFYING_GLASS, SCALES, SHUFFLE, SIGMA,
+    SLIDERS_HORIZONTAL, TAG, USERS_THREE, X,
 };
 use egui_tiles::{TileId, UiResponse};
-use metadata::{egui::MetadataWidget, polars::MetaDataFrame};
+use metadata::{DESCRIPTION, NAME, egui::MetadataWidget, polars::MetaDataFrame};
 use polars::prelude::*;
 use polars_utils::{format_list, format_list_truncated};
 use serde::{Deserialize, Serialize};
@@ -164,9 +186,23 @@ impl Pane {
             RichText::new(GEAR).heading(),
         )
         .on_hover_text("ShowSettings");
+        // Groups
+        ui.toggle_value(
+            &mut state.windows.open_groups,
+            RichText::new(USERS_THREE).heading(),
+        )
+        .on_hover_localized("Groups.hover");
+        // Detection
+        ui.toggle_value(
+            &mut state.windows.open_detection,
+            RichText::new(MAGNIFYING_GLASS).heading(),
+        )
+        .on_hover_localized("Detection.hover");
         ui.separator();
         self.sum_button(ui, state);
         ui.separator();
+        self.prediction_button(ui);
+        ui.separator();
         self.save_button(ui);
         ui.separator();
         response
@@ -197,6 +233,39 @@ impl Pane {
             .on_hover_ui(|ui| {
                 ui.label(ui.localize("Indices"));
             });
+            // Properties
+            ui.toggle_value(
+                &mut state.windows.open_properties,
+                (
+                    RichText::new(FLASK).heading(),
+                    RichText::new(ui.localize("Properties")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Properties.hover"));
+            });
+            // Biodiesel
+            ui.toggle_value(
+                &mut state.windows.open_biodiesel,
+                (
+                    RichText::new(GAS_PUMP).heading(),
+                    RichText::new(ui.localize("BiodieselProperties")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("BiodieselProperties.hover"));
+            });
+            // Nutrition
+            ui.toggle_value(
+                &mut state.windows.open_nutrition,
+                (
+                    RichText::new(BOWL_FOOD).heading(),
+                    RichText::new(ui.localize("Nutrition")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Nutrition.hover"));
+            });
             // Metrics
             ui.toggle_value(
                 &mut state.windows.open_metrics,
@@ -208,9 +277,149 @@ impl Pane {
             .on_hover_ui(|ui| {
                 ui.label(ui.localize("Metric?PluralCategory=other"));
             });
+            // Diversity
+            ui.toggle_value(
+                &mut state.windows.open_diversity,
+                (
+                    RichText::new(SIGMA).heading(),
+                    RichText::new(ui.localize("Diversity")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Diversity.hover"));
+            });
+            // Ordination
+            ui.toggle_value(
+                &mut state.windows.open_ordination,
+                (
+                    RichText::new(CHART_SCATTER).heading(),
+                    RichText::new(ui.localize("Ordination")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Ordination.hover"));
+            });
+            // Clusters
+            ui.toggle_value(
+                &mut state.windows.open_clusters,
+                (
+                    RichText::new(CIRCLES_THREE).heading(),
+                    RichText::new(ui.localize("Clusters")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Clusters.hover"));
+            });
+            // Membership
+            ui.toggle_value(
+                &mut state.windows.open_membership,
+                (
+                    RichText::new(INTERSECT).heading(),
+                    RichText::new(ui.localize("Membership")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Membership.hover"));
+            });
+            // Comparison
+            // Two samples or groups
+            let samples = self.calculated.width().saturating_sub(3);
+            ui.add_enabled_ui(samples == 2, |ui| {
+                ui.toggle_value(
+                    &mut state.windows.open_comparison,
+                    (
+                        RichText::new(SCALES).heading(),
+                        RichText::new(ui.localize("Comparison")).heading(),
+                    ),
+                )
+                .on_hover_ui(|ui| {
+                    ui.label(ui.localize("Comparison.hover"));
+                });
+            });
+        });
+    }
+
+    /// Prediction button
+    fn prediction_button(&self, ui: &mut Ui) {
+        ui.menu_button(RichText::new(SHUFFLE).heading(), |ui| {
+            ui.heading(ui.localize("Prediction"))
+                .on_hover_localized("Prediction.hover");
+            ui.separator();
+            for model in MODELS {
+                if ui
+                    .button(ui.localize(model.text()))
+                    .on_hover_localized(model.hover_text())
+                    .clicked()
+                {
+                    self.predict(ui, model);
+                }
+                if model == Model::OneThreeCalculatedTwoRandom {
+                    self.clipping(ui);
+                }
+            }
         });
     }
 
+    /// Clipped mass of the calculated SN-1,3 compositions
+    fn clipping(&self, ui: &mut Ui) {
+        for frame in &self.frames {
+            let clipped = ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<ClippingComputed>()
+                    .get(ClippingKey::new(&frame.data))
+            });
+            if let Some(clipped) = clipped
+                && clipped > 0.0
+            {
+                ui.label(
+                    RichText::new(format!("{} {clipped:.2}%", frame.meta.format(" ")))
+                        .color(ui.visuals().warn_fg_color),
+                )
+                .on_hover_localized("Clipping.hover");
+            }
+        }
+    }
+
+    /// Adds the predicted triacylglycerols to the data
+    fn predict(&self, ui: &mut Ui, model: Model) {
+        let mut frames = Vec::with_capacity(self.frames.len());
+        for frame in &self.frames {
+            let data = ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<PredictionComputed>()
+                    .get(PredictionKey::new(&frame.data, model))
+            });
+            if data.is_empty() {
+                continue;
+            }
+            let mut meta = frame.meta.clone();
+            meta.0
+                .entry(NAME.to_owned())
+                .or_default()
+                .push_str(&format!(" ({})", model.id()));
+            if model == Model::OneThreeCalculatedTwoRandom {
+                let clipped = ui.memory_mut(|memory| {
+                    memory
+                        .caches
+                        .cache::<ClippingComputed>()
+                        .get(ClippingKey::new(&frame.data))
+                });
+                if let Some(clipped) = clipped
+                    && clipped > 0.0
+                {
+                    meta.0
+                        .entry(DESCRIPTION.to_owned())
+                        .or_default()
+                        .push_str(&format!("\nClipped SN-1,3: {clipped:.2}%"));
+                }
+            }
+            frames.push(HashedMetaDataFrame::new(meta, data));
+        }
+        ui.data_mut(|data| data.insert_temp(Id::new("Data"), frames));
+    }
+
     /// Save button
     fn save_button(&self, ui: &mut Ui) {
         ui.menu_button(RichText::new(FLOPPY_DISK).heading(), |ui| {
@@ -275,16 +484,38 @@ impl Pane {
                 .cache::<TableComputed>()
                 .get(TableKey::new(&self.calculated, &state.settings))
         });
-        _ = TableView::new(&data_frame, state).show(ui);
+        if state.settings.clusters.table {
+            let clusters = ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<ClustersComputed>()
+                    .get(ClustersKey::new(&self.calculated, &state.settings))
+            });
+            _ = TableView::new(&data_frame, state)
+                .with_clusters(&clusters)
+                .show(ui);
+        } else {
+            _ = TableView::new(&data_frame, state).show(ui);
+        }
     }
 }
 
 impl Pane {
     fn windows(&mut self, ui: &mut Ui, state: &mut State) {
         self.settings(ui, state);
+        self.groups(ui, state);
+        self.detection(ui, state);
         self.factors(ui, state);
         self.indices(ui, state);
+        self.properties(ui, state);
+        self.biodiesel(ui, state);
+        self.nutrition(ui, state);
         self.metrics(ui, state);
+        self.diversity(ui, state);
+        self.ordination(ui, state);
+        self.clusters(ui, state);
+        self.comparison(ui, state);
+        self.membership(ui, state);
     }
 
     fn settings(&mut self, ui: &mut Ui, state: &mut State) {
@@ -297,6 +528,26 @@ impl Pane {
             });
     }
 
+    fn groups(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{USERS_THREE} Groups"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Groups"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_groups)
+            .show(ui.ctx(), |ui| {
+                GroupsWidget::new(&mut state.settings.groups, &self.frames).show(ui);
+            });
+    }
+
+    fn detection(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{MAGNIFYING_GLASS} Detection"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Detection"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_detection)
+            .show(ui.ctx(), |ui| {
+                DetectionWidget::new(&mut state.settings.detection, &self.frames).show(ui);
+            });
+    }
+
     fn factors(&mut self, ui: &mut Ui, state: &mut State) {
         Window::new(format!("{SIGMA} Factors"))
             .id(ui.auto_id_with(ID_SOURCE).with("Factors"))
@@ -333,6 +584,103 @@ impl Pane {
         Indices::new(&data_frame, settings).show(ui)
     }
 
+    fn properties(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{FLASK} Properties"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Properties"))
+            .open(&mut state.windows.open_properties)
+            .show(ui.ctx(), |ui| self.properties_content(ui, &state.settings));
+    }
+
+    #[instrument(skip_all, err)]
+    fn properties_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<PropertiesComputed>()
+                .get(PropertiesKey::new(&self.calculated, settings))
+        });
+        Indices::new(&data_frame, settings)
+            .with_id_salt(Id::new(ID_SOURCE).with("Properties"))
+            .show(ui)
+    }
+
+    fn biodiesel(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{GAS_PUMP} Biodiesel"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Biodiesel"))
+            .open(&mut state.windows.open_biodiesel)
+            .show(ui.ctx(), |ui| self.biodiesel_content(ui, &state.settings));
+    }
+
+    #[instrument(skip_all, err)]
+    fn biodiesel_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<BiodieselComputed>()
+                .get(BiodieselKey::new(&self.calculated, settings))
+        });
+        Indices::new(&data_frame, settings)
+            .with_id_salt(Id::new(ID_SOURCE).with("Biodiesel"))
+            .with_standards(&STANDARDS)
+            .show(ui)
+    }
+
+    fn nutrition(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{BOWL_FOOD} Nutrition"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Nutrition"))
+            .open(&mut state.windows.open_nutrition)
+            .show(ui.ctx(), |ui| {
+                self.nutrition_content(ui, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn nutrition_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        settings.nutrition.show(ui);
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<NutritionComputed>()
+                .get(NutritionKey::new(&self.calculated, settings))
+        });
+        Indices::new(&data_frame, settings)
+            .with_id_salt(Id::new(ID_SOURCE).with("Nutrition"))
+            .show(ui)?;
+        ui.add_enabled_ui(!data_frame.is_empty(), |ui| {
+            let title = self.title();
+            if ui
+                .button((FLOPPY_DISK, "RON"))
+                .on_hover_localized("Save")
+                .on_hover_ui(|ui| {
+                    ui.label(format!("{title}.nutrition.utca.ron"));
+                })
+                .clicked()
+            {
+                _ = self.save_nutrition(&title, &data_frame, settings.dispersion);
+            }
+        });
+        Ok(())
+    }
+
+    #[instrument(skip_all, err)]
+    fn save_nutrition(
+        &self,
+        title: &str,
+        data_frame: &DataFrame,
+        dispersion: Dispersion,
+    ) -> Result<()> {
+        let mut meta = self.frames[0].meta.clone();
+        meta.0
+            .insert(NAME.to_owned(), format!("{title} (Nutrition)"));
+        // The statistic of the standard deviation field
+        meta.0
+            .insert(DISPERSION.to_owned(), dispersion.id().to_owned());
+        let frame = MetaDataFrame::new(&meta, data_frame);
+        ron::save(&frame, &format!("{title}.nutrition.utca.ron"))?;
+        Ok(())
+    }
+
     fn metrics(&mut self, ui: &mut Ui, state: &mut State) {
         Window::new(format!("{SIGMA} Metrics"))
             .id(ui.auto_id_with(ID_SOURCE).with("Metrics"))
@@ -350,8 +698,195 @@ impl Pane {
                 .get(MetricsKey::new(&self.calculated, settings))
         });
         _ = Metrics::new(&data_frame, settings).show(ui);
+        ui.separator();
+        // Every joined sample, regardless of the view of the groups
+        let mut key = FattyAcidsKey::new(&self.frames, settings);
+        key.groups = &UNGROUPED;
+        let frame = ui.memory_mut(|memory| memory.caches.cache::<FattyAcidsComputed>().get(key));
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<SignificanceComputed>()
+                .get(SignificanceKey::new(&frame, settings))
+        });
+        Significance::new(&data_frame, Id::new(ID_SOURCE).with("Significance"))
+            .with_precision(settings.precision)
+            .show(ui)
+    }
+
+    fn ordination(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{CHART_SCATTER} Ordination"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Ordination"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_ordination)
+            .show(ui.ctx(), |ui| {
+                self.ordination_content(ui, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn ordination_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        ui.horizontal(|ui| {
+            for ordination in ORDINATIONS {
+                ui.selectable_value(
+                    &mut settings.ordination,
+                    ordination,
+                    ui.localize(ordination.text()),
+                )
+                .on_hover_localized(ordination.hover_text());
+            }
+        });
+        // Every joined sample colored by the group, regardless of the view of
+        // the groups
+        let mut key = FattyAcidsKey::new(&self.frames, settings);
+        key.groups = &UNGROUPED;
+        let frame = ui.memory_mut(|memory| memory.caches.cache::<FattyAcidsComputed>().get(key));
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<OrdinationComputed>()
+                .get(OrdinationKey::new(&frame, settings))
+        });
+        Ordination::new(&data_frame, Id::new(ID_SOURCE).with("Ordination"))
+            .with_precision(settings.precision)
+            .show(ui)?;
+        ui.add_enabled_ui(!data_frame.is_empty(), |ui| {
+            let title = self.title();
+            if ui
+                .button((FLOPPY_DISK, "RON"))
+                .on_hover_localized("Save")
+                .on_hover_ui(|ui| {
+                    ui.label(format!("{title}.ordination.utca.ron"));
+                })
+                .clicked()
+            {
+                _ = self.save_ordination(&title, &data_frame);
+            }
+        });
         Ok(())
     }
+
+    #[instrument(skip_all, err)]
+    fn save_ordination(&self, title: &str, data_frame: &DataFrame) -> Result<()> {
+        let mut meta = self.frames[0].meta.clone();
+        meta.0
+            .insert(NAME.to_owned(), format!("{title} (Ordination)"));
+        let frame = MetaDataFrame::new(&meta, data_frame);
+        ron::save(&frame, &format!("{title}.ordination.utca.ron"))?;
+        Ok(())
+    }
+
+    fn clusters(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{CIRCLES_THREE} Clusters"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Clusters"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_clusters)
+            .show(ui.ctx(), |ui| {
+                self.clusters_content(ui, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn clusters_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let samples = self.calculated.width().saturating_sub(3);
+        settings.clusters.show(ui, samples);
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ClustersComputed>()
+                .get(ClustersKey::new(&self.calculated, settings))
+        });
+        Clusters::new(&data_frame, Id::new(ID_SOURCE).with("Clusters"))
+            .with_count(settings.clusters.count)
+            .with_precision(settings.precision)
+            .show(ui)
+    }
+
+    fn comparison(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{SCALES} Comparison"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Comparison"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_comparison)
+            .show(ui.ctx(), |ui| self.comparison_content(ui, &state.settings))
+            && let Some(Ok(Some(row))) = inner_response.inner
+        {
+            state.scroll_to_row = Some(row);
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn comparison_content(
+        &mut self,
+        ui: &mut Ui,
+        settings: &Settings,
+    ) -> PolarsResult<Option<usize>> {
+        let table = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<TableComputed>()
+                .get(TableKey::new(&self.calculated, settings))
+        });
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ComparisonComputed>()
+                .get(ComparisonKey::new(&self.calculated, &table, settings))
+        });
+        Comparison::new(&data_frame, Id::new(ID_SOURCE).with("Comparison"))
+            .with_precision(settings.precision)
+            .show(ui)
+    }
+
+    fn membership(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{INTERSECT} Membership"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Membership"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_membership)
+            .show(ui.ctx(), |ui| {
+                self.membership_content(ui, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn membership_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<MembershipComputed>()
+                .get(MembershipKey::new(&self.calculated, settings))
+        });
+        let clicked = Membership::new(&data_frame, Id::new(ID_SOURCE).with("Membership"))
+            .with_selected(settings.membership)
+            .show(ui)?;
+        if let Some(membership) = clicked {
+            settings.membership = (settings.membership != Some(membership)).then_some(membership);
+        }
+        Ok(())
+    }
+
+    fn diversity(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{SIGMA} Diversity"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Diversity"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_diversity)
+            .show(ui.ctx(), |ui| self.diversity_content(ui, &state.settings));
+    }
+
+    #[instrument(skip_all, err)]
+    fn diversity_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<DiversityComputed>()
+                .get(DiversityKey::new(&self.calculated, settings))
+        });
+        Diversity::new(&data_frame, Id::new(ID_SOURCE))
+            .with_dispersion(settings.dispersion)
+            .with_standard_deviation(settings.standard_deviation)
+            .with_truncate(settings.truncate)
+            .show(ui)
+    }
 }
 
 mod factors;
@@ -2,11 +2,16 @@ use crate::{
     app::{
         panes::MARGIN,
         states::fatty_acids::{ID_SOURCE, State},
-        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+        widgets::{
+            clusters::cluster,
+            mean_and_standard_deviation::MeanAndStandardDeviation,
+            ordination::color,
+            reference::{reference_heading, reference_label},
+        },
     },
     r#const::THRESHOLD,
 };
-use egui::{Context, Frame, Id, Margin, TextStyle, TextWrapMode, Ui};
+use egui::{Align, Color32, Context, Frame, Id, Margin, TextStyle, TextWrapMode, Ui};
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::HASH;
 use egui_table::{CellInfo, Column, HeaderCellInfo, HeaderRow, Table, TableDelegate, TableState};
@@ -22,12 +27,24 @@ const LEN: usize = ID.end;
 /// Table view
 pub(super) struct TableView<'a> {
     data_frame: &'a DataFrame,
+    clusters: Option<&'a DataFrame>,
     state: &'a mut State,
 }
 
 impl<'a> TableView<'a> {
     pub(super) fn new(data_frame: &'a DataFrame, state: &'a mut State) -> Self {
-        Self { data_frame, state }
+        Self {
+            data_frame,
+            clusters: None,
+            state,
+        }
+    }
+
+    pub(super) fn with_clusters(self, clusters: &'a DataFrame) -> Self {
+        Self {
+            clusters: Some(clusters),
+            ..self
+        }
     }
 }
 
@@ -44,7 +61,7 @@ impl TableView<'_> {
         let num_rows = self.data_frame.height() as u64;
         let value = self.data_frame.width() - 3;
         let num_columns = LEN + value;
-        Table::new()
+        let mut table = Table::new()
             .id_salt(id_salt)
             .num_rows(num_rows)
             .columns(vec![
@@ -59,11 +76,21 @@ impl TableView<'_> {
                     groups: vec![INDEX, ID, LEN..num_columns],
                 },
                 HeaderRow::new(height),
-            ])
-            .show(ui, self);
+            ]);
+        if let Some(row) = self.state.scroll_to_row.take() {
+            table = table.scroll_to_row(row as u64, Some(Align::Center));
+        }
+        table.show(ui, self);
         Ok(())
     }
 
+    /// Cluster color of the sample column
+    fn cluster_color(&self, column: usize) -> Option<Color32> {
+        let name = self.data_frame.get_columns().get(column)?.name();
+        let cluster = cluster(self.clusters?, self.state.settings.clusters.count, name)?;
+        Some(color(cluster))
+    }
+
     fn header_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: Range<usize>) {
         if self.state.settings.truncate {
             ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
@@ -77,13 +104,19 @@ impl TableView<'_> {
                 ui.heading(ui.localize("Label"));
             }
             (0, _) => {
-                ui.heading(ui.localize("Value"));
+                ui.heading(format!(
+                    "{} ±{}",
+                    ui.localize("Value"),
+                    ui.localize(self.state.settings.dispersion.abbreviation()),
+                ))
+                .on_hover_localized(self.state.settings.dispersion.hover_text());
             }
             // Bottom
             (1, INDEX) => {}
             (1, ID) => {}
             (1, column) => {
-                ui.heading(self.data_frame[column.start].name().to_string());
+                let column = &self.data_frame[column.start];
+                reference_heading(ui, column, &mut self.state.settings.reference);
             }
             _ => {}
         };
@@ -106,7 +139,8 @@ impl TableView<'_> {
             }
             (row, &ID) => {
                 if let Some(label) = self.data_frame[LABEL].str()?.get(row) {
-                    let response = ui.label(label);
+                    let response =
+                        reference_label(ui, label, &mut self.state.settings.transformation);
                     if response.hovered()
                         && let Some(fatty_acid) = self.data_frame[FATTY_ACID].str()?.get(row)
                     {
@@ -120,6 +154,7 @@ impl TableView<'_> {
             (row, column) => {
                 MeanAndStandardDeviation::new(&self.data_frame, column.start, row)
                     .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_dispersion(self.state.settings.dispersion)
                     .with_sample(true)
                     .show(ui)?;
             }
@@ -182,11 +217,15 @@ impl TableView<'_> {
 
 impl TableDelegate for TableView<'_> {
     fn header_cell_ui(&mut self, ui: &mut Ui, cell: &HeaderCellInfo) {
-        Frame::new()
-            .inner_margin(Margin::from(MARGIN))
-            .show(ui, |ui| {
-                self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
-            });
+        let mut frame = Frame::new().inner_margin(Margin::from(MARGIN));
+        if cell.row_nr == 1
+            && let Some(color) = self.cluster_color(cell.col_range.start)
+        {
+            frame = frame.fill(color.gamma_multiply(0.5));
+        }
+        frame.show(ui, |ui| {
+            self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
+        });
     }
 
     fn cell_ui(&mut self, ui: &mut Ui, cell: &CellInfo) {
@@ -194,6 +233,10 @@ impl TableDelegate for TableView<'_> {
             ui.painter()
                 .rect_filled(ui.max_rect(), 0.0, ui.visuals().faint_bg_color);
         }
+        if let Some(color) = self.cluster_color(cell.col_nr) {
+            ui.painter()
+                .rect_filled(ui.max_rect(), 0.0, color.gamma_multiply(0.1));
+        }
         Frame::new()
             .inner_margin(Margin::from(MARGIN))
             .show(ui, |ui| {
@@ -20,8 +20,11 @@ impl Pane {
         Self::FattyAcids(fatty_acids::Pane::new(frames))
     }
 
-    pub(crate) fn triacylglycerols(frames: Vec<HashedMetaDataFrame>) -> Self {
-        Self::Triacylglycerols(triacylglycerols::Pane::new(frames))
+    pub(crate) fn triacylglycerols(
+        frames: Vec<HashedMetaDataFrame>,
+        fatty_acids: Vec<HashedMetaDataFrame>,
+    ) -> Self {
+        Self::Triacylglycerols(triacylglycerols::Pane::new(frames, fatty_acids))
     }
 }
 
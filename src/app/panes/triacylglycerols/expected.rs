@@ -0,0 +1,151 @@
+use crate::{
+    app::{
+        panes::MARGIN,
+        states::triacylglycerols::{ID_SOURCE, settings::Settings},
+    },
+    r#const::{DISCREPANCY, EXPECTED, OBSERVED, RATIO},
+};
+use egui::{Grid, Id, TextStyle, TextWrapMode, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::SIGMA;
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+const FIELDS: [&str; 4] = [OBSERVED, EXPECTED, RATIO, DISCREPANCY];
+
+/// Expected
+pub struct Expected<'a> {
+    pub data_frame: &'a DataFrame,
+    pub settings: &'a Settings,
+}
+
+impl<'a> Expected<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+}
+
+impl Expected<'_> {
+    #[instrument(skip_all, err)]
+    pub fn show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Expected.hover"));
+            return Ok(());
+        }
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Model"));
+            ui.label(ui.localize(self.settings.model.text()))
+                .on_hover_localized(self.settings.model.hover_text());
+        });
+        self.summary(ui)?;
+        ui.separator();
+        self.table(ui);
+        Ok(())
+    }
+
+    /// Discrepancy of every sample
+    fn summary(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let last = self.data_frame.height() - 1;
+        Grid::new(ui.auto_id_with(ID_SOURCE).with("ExpectedSummary"))
+            .show(ui, |ui| -> PolarsResult<()> {
+                ui.label("");
+                ui.heading(ui.localize("Discrepancy"))
+                    .on_hover_localized("Discrepancy.hover");
+                ui.end_row();
+                for column in &self.data_frame.get_columns()[1..] {
+                    let discrepancy = column.struct_()?.field_by_name(DISCREPANCY)?;
+                    ui.label(column.name().as_str());
+                    if let Some(value) = discrepancy.f64()?.get(last) {
+                        ui.label(format!("{value:.0$}", self.settings.precision))
+                            .on_hover_text(value.to_string());
+                    }
+                    ui.end_row();
+                }
+                Ok(())
+            })
+            .inner
+    }
+
+    fn table(&self, ui: &mut Ui) {
+        let id_salt = Id::new(ID_SOURCE).with("Expected");
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let rows = self.data_frame.height();
+        let samples = self.data_frame.width() - 1;
+        ui.style_mut().wrap_mode = if self.settings.truncate {
+            Some(TextWrapMode::Truncate)
+        } else {
+            Some(TextWrapMode::Extend)
+        };
+        TableBuilder::new(ui)
+            .id_salt(id_salt)
+            .striped(true)
+            .resizable(true)
+            .columns(Column::auto(), 1 + samples * FIELDS.len())
+            .header(height + 2.0 * MARGIN.y, |mut row| {
+                row.col(|ui| {
+                    ui.heading(ui.localize(self.settings.composition.text()));
+                });
+                for sample in 1..=samples {
+                    let name = self.data_frame[sample].name().to_string();
+                    for field in FIELDS {
+                        row.col(|ui| {
+                            let text = match field {
+                                RATIO if self.settings.log_ratio => "LogRatio",
+                                field => field,
+                            };
+                            ui.heading(ui.localize(text)).on_hover_text(&name);
+                        });
+                    }
+                }
+            })
+            .body(|mut body| {
+                body.ui_mut().style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                body.rows(height, rows, |mut row| {
+                    let index = row.index();
+                    row.col(|ui| {
+                        _ = self.label_cell_content_ui(ui, index);
+                    });
+                    for sample in 1..=samples {
+                        for field in FIELDS {
+                            row.col(|ui| {
+                                _ = self.value_cell_content_ui(ui, index, sample, field);
+                            });
+                        }
+                    }
+                });
+            });
+    }
+
+    fn label_cell_content_ui(&self, ui: &mut Ui, row: usize) -> PolarsResult<()> {
+        if row + 1 == self.data_frame.height() {
+            ui.heading(SIGMA);
+        } else if let Some(label) = self.data_frame[LABEL].str()?.get(row) {
+            ui.label(label);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self, ui), err)]
+    fn value_cell_content_ui(
+        &self,
+        ui: &mut Ui,
+        row: usize,
+        column: usize,
+        field: &str,
+    ) -> PolarsResult<()> {
+        let series = self.data_frame[column].struct_()?.field_by_name(field)?;
+        if let Some(mut value) = series.f64()?.get(row) {
+            if self.settings.percent && matches!(field, OBSERVED | EXPECTED) {
+                value *= 100.0;
+            }
+            ui.label(format!("{value:.0$}", self.settings.precision))
+                .on_hover_text(value.to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    app::{
+        states::triacylglycerols::{ID_SOURCE, settings::Settings},
+        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+    },
+    r#const::LEVEL,
+};
+use egui::{Id, ScrollArea, TextWrapMode, Ui, collapsing_header::CollapsingState};
+use egui_l20n::prelude::*;
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+const COLUMNS: usize = 2;
+
+/// Hierarchy
+pub struct Hierarchy<'a> {
+    pub data_frame: &'a DataFrame,
+    pub settings: &'a Settings,
+}
+
+impl<'a> Hierarchy<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+}
+
+impl Hierarchy<'_> {
+    #[instrument(skip_all, err)]
+    pub fn show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+        ui.horizontal(|ui| {
+            ui.heading(ui.localize("Hierarchy"))
+                .on_hover_localized("Hierarchy.hover");
+            ui.label(
+                self.settings
+                    .hierarchy
+                    .iter()
+                    .map(|composition| ui.localize(composition.text()))
+                    .collect::<Vec<_>>()
+                    .join(" → "),
+            );
+        });
+        ui.separator();
+        let levels = self.data_frame[LEVEL].u32()?;
+        let mut row = 0;
+        ScrollArea::vertical()
+            .show(ui, |ui| self.level(ui, levels, &mut row, 0))
+            .inner
+    }
+
+    /// Shows the nodes of the level, starting from the row, and moves the row
+    /// past them and their children
+    fn level(
+        &self,
+        ui: &mut Ui,
+        levels: &UInt32Chunked,
+        row: &mut usize,
+        level: u32,
+    ) -> PolarsResult<()> {
+        let id_salt = Id::new(ID_SOURCE).with("Hierarchy");
+        while let Some(current) = levels.get(*row)
+            && current == level
+        {
+            let index = *row;
+            *row += 1;
+            if levels.get(*row).is_some_and(|next| next > level) {
+                let mut children = *row;
+                CollapsingState::load_with_default_open(ui.ctx(), id_salt.with(index), false)
+                    .show_header(ui, |ui| {
+                        _ = self.node(ui, index);
+                    })
+                    .body(|ui| {
+                        _ = self.level(ui, levels, &mut children, level + 1);
+                    });
+                // Skip the children of the collapsed node
+                while levels.get(*row).is_some_and(|next| next > level) {
+                    *row += 1;
+                }
+            } else {
+                ui.horizontal(|ui| {
+                    ui.add_space(ui.spacing().indent);
+                    self.node(ui, index)
+                })
+                .inner?;
+            }
+        }
+        Ok(())
+    }
+
+    fn node(&self, ui: &mut Ui, row: usize) -> PolarsResult<()> {
+        if let Some(label) = self.data_frame[LABEL].str()?.get(row) {
+            ui.label(label);
+        }
+        for column in COLUMNS..self.data_frame.width() {
+            ui.separator();
+            MeanAndStandardDeviation::new(self.data_frame, column, row)
+                .with_standard_deviation(self.settings.standard_deviation)
+                .with_dispersion(self.settings.dispersion)
+                .with_sample(true)
+                .show(ui)?
+                .on_hover_text(self.data_frame[column].name().to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    app::{
+        panes::MARGIN,
+        states::triacylglycerols::{ID_SOURCE, settings::Settings},
+    },
+    r#const::{EM_DASH, LOWER, MEAN, SAMPLE, STANDARD_DEVIATION, UPPER},
+};
+use egui::{Id, TextStyle, TextWrapMode, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+use polars_utils::format_list;
+use tracing::instrument;
+
+/// Melting widget
+pub(crate) struct Melting<'a> {
+    pub data_frame: &'a DataFrame,
+    pub settings: &'a Settings,
+}
+
+impl<'a> Melting<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    pub(crate) fn show(mut self, ui: &mut Ui) -> PolarsResult<()> {
+        let id_salt = Id::new(ID_SOURCE).with("Melting");
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let rows = self.data_frame.height();
+        let columns = self.data_frame.width();
+        ui.style_mut().wrap_mode = if self.settings.truncate {
+            Some(TextWrapMode::Truncate)
+        } else {
+            Some(TextWrapMode::Extend)
+        };
+        TableBuilder::new(ui)
+            .id_salt(id_salt)
+            .striped(true)
+            .resizable(true)
+            .columns(Column::auto(), columns)
+            .header(height + 2.0 * MARGIN.y, |mut row| {
+                for name in self.data_frame.schema().iter_names() {
+                    row.col(|ui| {
+                        ui.heading(name.as_str());
+                    });
+                }
+            })
+            .body(|mut body| {
+                body.ui_mut().style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                body.rows(height, rows, |mut row| {
+                    let index = row.index();
+                    for column in 0..columns {
+                        row.col(|ui| {
+                            _ = self.body_cell_content_ui(ui, index, column);
+                        });
+                    }
+                });
+            });
+        Ok(())
+    }
+
+    #[instrument(skip(self, ui), err)]
+    fn body_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
+        match column {
+            0 => {
+                let name = self.data_frame[0].get(row)?.str_value();
+                ui.label(ui.localize(&name)).on_hover_ui(|ui| {
+                    ui.label(ui.localize(&format!("{name}.hover")));
+                });
+            }
+            column => {
+                let mean_series = self.data_frame[column].struct_()?.field_by_name(MEAN)?;
+                let mean = mean_series.f64()?.get(row);
+                let standard_deviation_series = self.data_frame[column]
+                    .struct_()?
+                    .field_by_name(STANDARD_DEVIATION)?;
+                let standard_deviation = standard_deviation_series.f64()?.get(row);
+                let lower_series = self.data_frame[column].struct_()?.field_by_name(LOWER)?;
+                let upper_series = self.data_frame[column].struct_()?.field_by_name(UPPER)?;
+                let interval = lower_series
+                    .f64()?
+                    .get(row)
+                    .zip(upper_series.f64()?.get(row));
+                let mut text = match mean {
+                    Some(mean)
+                        if self.settings.standard_deviation
+                            && let Some(standard_deviation) = standard_deviation =>
+                    {
+                        format!("{mean} ±{standard_deviation}")
+                    }
+                    Some(mean) => mean.to_string(),
+                    None => EM_DASH.to_owned(),
+                };
+                if let Some((lower, upper)) = interval {
+                    text = format!("{text} [{lower}, {upper}]");
+                }
+                let mut response = ui.label(text);
+                if response.hovered() {
+                    // Bootstrap interval
+                    if let Some((lower, upper)) = interval {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(self.settings.bootstrap.interval.text()));
+                            ui.label(format!("[{lower}, {upper}]"));
+                        });
+                    }
+                    // Standard deviation
+                    if let Some(standard_deviation) = standard_deviation {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(self.settings.dispersion.text()));
+                            ui.label(format!("±{standard_deviation}"));
+                        });
+                    }
+                    // Sample
+                    let sample_series = self.data_frame[column].struct_()?.field_by_name(SAMPLE)?;
+                    if let Some(sample) = sample_series.array()?.get_as_series(row) {
+                        response = response.on_hover_ui(|ui| {
+                            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                            ui.heading(ui.localize(SAMPLE));
+                            ui.label(format_list!(sample.iter()));
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
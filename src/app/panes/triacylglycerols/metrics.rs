@@ -1,9 +1,13 @@
-use crate::app::{
-    panes::{MARGIN, metrics::Sign},
-    states::triacylglycerols::{ID_SOURCE, settings::Settings},
+use crate::{
+    app::{
+        panes::{MARGIN, metrics::Sign},
+        states::triacylglycerols::{ID_SOURCE, settings::Settings},
+    },
+    r#const::{LOWER, UPPER, VALUE},
 };
 use egui::{Id, Label, RichText, TextStyle, TextWrapMode, Ui, Widget};
 use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
 use polars::prelude::*;
 use tracing::instrument;
 
@@ -66,8 +70,18 @@ impl Metrics<'_> {
 
     #[instrument(skip(self, ui), err)]
     fn body_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
-        if let Some(value) = self.data_frame[column].f64()?.get(row) {
-            let text = format!("{value:.0$}", self.settings.precision);
+        let value_series = self.data_frame[column].struct_()?.field_by_name(VALUE)?;
+        let lower_series = self.data_frame[column].struct_()?.field_by_name(LOWER)?;
+        let upper_series = self.data_frame[column].struct_()?.field_by_name(UPPER)?;
+        let interval = lower_series
+            .f64()?
+            .get(row)
+            .zip(upper_series.f64()?.get(row));
+        if let Some(value) = value_series.f64()?.get(row) {
+            let mut text = format!("{value:.0$}", self.settings.precision);
+            if let Some((lower, upper)) = interval {
+                text = format!("{text} [{lower:.0$}, {upper:.0$}]", self.settings.precision);
+            }
             let sign = Sign::from(value);
             let mut color = ui.style().visuals.text_color();
             if self.settings.metric.is_finite() {
@@ -77,10 +91,17 @@ impl Metrics<'_> {
                     color = sign.color(color);
                 }
             }
-            Label::new(RichText::new(text).color(color))
+            let response = Label::new(RichText::new(text).color(color))
                 .ui(ui)
                 .on_hover_text(value.to_string())
                 .on_hover_text(format!("{sign:?}"));
+            if let Some((lower, upper)) = interval {
+                response.on_hover_ui(|ui| {
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                    ui.heading(ui.localize(self.settings.bootstrap.interval.text()));
+                    ui.label(format!("[{lower}, {upper}]"));
+                });
+            }
         }
         Ok(())
     }
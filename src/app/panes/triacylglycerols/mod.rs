@@ -1,14 +1,40 @@
-use self::{metrics::Metrics, moments::Moments, table::TableView};
+use self::{
+    expected::Expected, hierarchy::Hierarchy, melting::Melting, metrics::Metrics, moments::Moments,
+    table::TableView,
+};
 use super::{Behavior, MARGIN};
 use crate::{
     app::{
         computers::triacylglycerols::{
             Computed as TriacylglycerolsComputed, Key as TriacylglycerolsKey,
+            clusters::{Computed as ClustersComputed, Key as ClustersKey},
+            comparison::{Computed as ComparisonComputed, Key as ComparisonKey},
+            decomposition::{Computed as DecompositionComputed, Key as DecompositionKey},
+            distribution::{Computed as DistributionComputed, Key as DistributionKey},
+            diversity::{Computed as DiversityComputed, Key as DiversityKey},
+            expected::{Computed as ExpectedComputed, Key as ExpectedKey, pair},
+            hierarchy::{Computed as HierarchyComputed, Key as HierarchyKey},
+            melting::{Computed as MeltingComputed, Key as MeltingKey},
+            membership::{Computed as MembershipComputed, Key as MembershipKey},
             metrics::{Computed as MetricsComputed, Key as MetricsKey},
             moments::{Computed as MomentsComputed, Key as MomentsKey},
+            ordination::{Computed as OrdinationComputed, Key as OrdinationKey},
+            significance::{Computed as SignificanceComputed, Key as SignificanceKey},
+        },
+        states::{
+            fatty_acids::settings::{Filter, ORDINATIONS, UNGROUPED},
+            triacylglycerols::{ID_SOURCE, State, settings::Settings},
+        },
+        widgets::{
+            buttons::{EditButton, MetadataButton, ResetButton, ResizeButton, SettingsButton},
+            clusters::Clusters,
+            comparison::Comparison,
+            diversity::Diversity,
+            groups::GroupsWidget,
+            membership::Membership,
+            ordination::Ordination,
+            significance::Significance,
         },
-        states::triacylglycerols::{ID_SOURCE, State, settings::Settings},
-        widgets::buttons::{EditButton, MetadataButton, ResetButton, ResizeButton, SettingsButton},
     },
     export,
     utils::HashedMetaDataFrame,
@@ -20,10 +46,12 @@ use egui::{
 };
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::{
-    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, DROP, FLOPPY_DISK, SIGMA, SLIDERS_HORIZONTAL, TAG, X,
+    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, ARROWS_SPLIT, CHART_SCATTER, CIRCLES_THREE, DROP,
+    FLOPPY_DISK, INTERSECT, SCALES, SIGMA, SLIDERS_HORIZONTAL, TAG, THERMOMETER, TREE_STRUCTURE,
+    USERS_THREE, X,
 };
 use egui_tiles::{TileId, UiResponse};
-use metadata::egui::MetadataWidget;
+use metadata::{NAME, egui::MetadataWidget, polars::MetaDataFrame};
 use polars::prelude::*;
 use polars_utils::format_list_truncated;
 use serde::{Deserialize, Serialize};
@@ -35,11 +63,20 @@ use tracing::instrument;
 pub struct Pane {
     id: Option<Id>,
     frames: Vec<HashedMetaDataFrame>,
+    #[serde(default)]
+    fatty_acids: Vec<HashedMetaDataFrame>,
 }
 
 impl Pane {
-    pub(super) fn new(frames: Vec<HashedMetaDataFrame>) -> Self {
-        Self { id: None, frames }
+    pub(super) fn new(
+        frames: Vec<HashedMetaDataFrame>,
+        fatty_acids: Vec<HashedMetaDataFrame>,
+    ) -> Self {
+        Self {
+            id: None,
+            frames,
+            fatty_acids,
+        }
     }
 
     pub(super) fn title(&self) -> String {
@@ -128,7 +165,22 @@ impl Pane {
         ui.separator();
         SettingsButton::new(&mut state.windows.open_settings).ui(ui);
         ui.separator();
+        // Groups
+        ui.toggle_value(
+            &mut state.windows.open_groups,
+            RichText::new(USERS_THREE).heading(),
+        )
+        .on_hover_localized("Groups.hover");
+        ui.separator();
         // Sigma
+        let frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<TriacylglycerolsComputed>()
+                .get(TriacylglycerolsKey::new(&self.frames, &state.settings))
+        });
+        // Two samples or groups
+        let samples = frame.width().saturating_sub(3);
         ui.menu_button(RichText::new(SIGMA).heading(), |ui| {
             // Metrics
             ui.toggle_value(
@@ -152,14 +204,144 @@ impl Pane {
             .on_hover_ui(|ui| {
                 ui.label(ui.localize("Moments"));
             });
+            // Diversity
+            ui.toggle_value(
+                &mut state.windows.open_diversity,
+                (
+                    RichText::new(SIGMA).heading(),
+                    RichText::new(ui.localize("Diversity")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Diversity.hover"));
+            });
+            // Ordination
+            ui.toggle_value(
+                &mut state.windows.open_ordination,
+                (
+                    RichText::new(CHART_SCATTER).heading(),
+                    RichText::new(ui.localize("Ordination")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Ordination.hover"));
+            });
+            // Clusters
+            ui.toggle_value(
+                &mut state.windows.open_clusters,
+                (
+                    RichText::new(CIRCLES_THREE).heading(),
+                    RichText::new(ui.localize("Clusters")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Clusters.hover"));
+            });
+            // Hierarchy
+            ui.toggle_value(
+                &mut state.windows.open_hierarchy,
+                (
+                    RichText::new(TREE_STRUCTURE).heading(),
+                    RichText::new(ui.localize("Hierarchy")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Hierarchy.hover"));
+            });
+            // Melting
+            ui.toggle_value(
+                &mut state.windows.open_melting,
+                (
+                    RichText::new(THERMOMETER).heading(),
+                    RichText::new(ui.localize("Melting")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Melting.hover"));
+            });
+            // Expected
+            ui.add_enabled_ui(!self.fatty_acids.is_empty(), |ui| {
+                ui.toggle_value(
+                    &mut state.windows.open_expected,
+                    (
+                        RichText::new(SIGMA).heading(),
+                        RichText::new(ui.localize("Expected")).heading(),
+                    ),
+                )
+                .on_hover_ui(|ui| {
+                    ui.label(ui.localize("Expected.hover"));
+                });
+            });
+            // Membership
+            ui.toggle_value(
+                &mut state.windows.open_membership,
+                (
+                    RichText::new(INTERSECT).heading(),
+                    RichText::new(ui.localize("Membership")).heading(),
+                ),
+            )
+            .on_hover_ui(|ui| {
+                ui.label(ui.localize("Membership.hover"));
+            });
+            // Comparison
+            ui.add_enabled_ui(samples == 2, |ui| {
+                ui.toggle_value(
+                    &mut state.windows.open_comparison,
+                    (
+                        RichText::new(SCALES).heading(),
+                        RichText::new(ui.localize("Comparison")).heading(),
+                    ),
+                )
+                .on_hover_ui(|ui| {
+                    ui.label(ui.localize("Comparison.hover"));
+                });
+            });
         });
         ui.separator();
+        // Decomposition
+        self.decomposition_button(ui);
+        ui.separator();
         // Save
         self.save_button(ui, state);
         ui.separator();
         response
     }
 
+    // Decomposition button
+    fn decomposition_button(&self, ui: &mut Ui) {
+        if ui
+            .button(RichText::new(ARROWS_SPLIT).heading())
+            .on_hover_localized("Decomposition")
+            .on_hover_localized("Decomposition.hover")
+            .clicked()
+        {
+            self.decompose(ui);
+        }
+    }
+
+    /// Adds the fatty acids implied by the triacylglycerols to the data
+    fn decompose(&self, ui: &mut Ui) {
+        let mut frames = Vec::with_capacity(self.frames.len());
+        for frame in &self.frames {
+            let data = ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<DecompositionComputed>()
+                    .get(DecompositionKey::new(&frame.data))
+            });
+            if data.is_empty() {
+                continue;
+            }
+            let mut meta = frame.meta.clone();
+            meta.0
+                .entry(NAME.to_owned())
+                .or_default()
+                .push_str(" (TAG)");
+            frames.push(HashedMetaDataFrame::new(meta, data));
+        }
+        ui.data_mut(|data| data.insert_temp(Id::new("Data"), frames));
+    }
+
     // Save button
     fn save_button(&self, ui: &mut Ui, state: &State) {
         ui.add_enabled_ui(self.frames.len() == 1, |ui| {
@@ -191,12 +373,190 @@ impl Pane {
 
 impl Pane {
     fn windows(&mut self, ui: &mut Ui, state: &mut State) {
+        self.clusters_window(ui, state);
+        self.comparison_window(ui, state);
+        self.diversity_window(ui, state);
+        self.expected_window(ui, state);
+        self.groups_window(ui, state);
+        self.hierarchy_window(ui, state);
+        self.melting_window(ui, state);
+        self.membership_window(ui, state);
         self.metadata_window(ui, state);
         self.metrics_window(ui, state);
         self.moments_window(ui, state);
+        self.ordination_window(ui, state);
         self.settings_window(ui, state);
     }
 
+    fn clusters_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{CIRCLES_THREE} Clusters"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Clusters"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_clusters)
+            .show(ui.ctx(), |ui| {
+                self.clusters_content(ui, &mut state.settings)
+            })
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn clusters_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<TriacylglycerolsComputed>()
+                .get(TriacylglycerolsKey::new(&self.frames, settings))
+        });
+        let samples = frame.width().saturating_sub(3);
+        settings.clusters.show(ui, samples);
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ClustersComputed>()
+                .get(ClustersKey::new(&frame, settings))
+        });
+        Clusters::new(&data_frame, Id::new(ID_SOURCE).with("Clusters"))
+            .with_count(settings.clusters.count)
+            .with_precision(settings.precision)
+            .show(ui)
+    }
+
+    fn comparison_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{SCALES} Comparison"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Comparison"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_comparison)
+            .show(ui.ctx(), |ui| self.comparison_content(ui, &state.settings))
+        {
+            if let Some(Ok(Some(row))) = inner_response.inner {
+                state.event.scroll_to_row = Some(row);
+            }
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn comparison_content(
+        &mut self,
+        ui: &mut Ui,
+        settings: &Settings,
+    ) -> PolarsResult<Option<usize>> {
+        let frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<TriacylglycerolsComputed>()
+                .get(TriacylglycerolsKey::new(&self.frames, settings))
+        });
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ComparisonComputed>()
+                .get(ComparisonKey::new(&frame, settings))
+        });
+        Comparison::new(&data_frame, Id::new(ID_SOURCE).with("Comparison"))
+            .with_precision(settings.precision)
+            .show(ui)
+    }
+
+    fn diversity_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{SIGMA} Diversity"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Diversity"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_diversity)
+            .show(ui.ctx(), |ui| self.diversity_content(ui, &state.settings))
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn diversity_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<TriacylglycerolsComputed>()
+                .get(TriacylglycerolsKey::new(&self.frames, settings))
+        });
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<DiversityComputed>()
+                .get(DiversityKey::new(&frame, settings))
+        });
+        _ = Diversity::new(&data_frame, Id::new(ID_SOURCE))
+            .with_dispersion(settings.dispersion)
+            .with_standard_deviation(settings.standard_deviation)
+            .with_truncate(settings.truncate)
+            .show(ui);
+        Ok(())
+    }
+
+    fn expected_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{SIGMA} Expected"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Expected"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_expected)
+            .show(ui.ctx(), |ui| self.expected_content(ui, &state.settings))
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn expected_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        // Every triacylglycerols frame needs the fatty acids with its name
+        for frame in &self.frames {
+            if let Err(error) = pair(frame, &self.fatty_acids) {
+                ui.label(RichText::new(error.to_string()).color(ui.visuals().error_fg_color));
+                return Err(error);
+            }
+        }
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ExpectedComputed>()
+                .get(ExpectedKey::new(&self.frames, &self.fatty_acids, settings))
+        });
+        _ = Expected::new(&data_frame, settings).show(ui);
+        Ok(())
+    }
+
+    fn hierarchy_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{TREE_STRUCTURE} Hierarchy"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Hierarchy"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_hierarchy)
+            .show(ui.ctx(), |ui| self.hierarchy_content(ui, &state.settings))
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn hierarchy_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<HierarchyComputed>()
+                .get(HierarchyKey::new(&self.frames, settings))
+        });
+        _ = Hierarchy::new(&data_frame, settings).show(ui);
+        Ok(())
+    }
+
     fn metadata_window(&mut self, ui: &mut Ui, state: &mut State) {
         Window::new(format!("{TAG} Configuration metadata"))
             .id(ui.auto_id_with(ID_SOURCE).with("Metadata"))
@@ -209,6 +569,68 @@ impl Pane {
             });
     }
 
+    fn melting_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{THERMOMETER} Melting"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Melting"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_melting)
+            .show(ui.ctx(), |ui| self.melting_content(ui, &state.settings))
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn melting_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<MeltingComputed>()
+                .get(MeltingKey::new(&self.frames, settings))
+        });
+        Melting::new(&data_frame, settings).show(ui)
+    }
+
+    fn membership_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{INTERSECT} Membership"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Membership"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_membership)
+            .show(ui.ctx(), |ui| {
+                self.membership_content(ui, &mut state.settings)
+            })
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn membership_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        // Every species of the samples, regardless of the table filters
+        let mut key = TriacylglycerolsKey::new(&self.frames, settings);
+        key.filter = Filter::Union;
+        key.membership = None;
+        let frame =
+            ui.memory_mut(|memory| memory.caches.cache::<TriacylglycerolsComputed>().get(key));
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<MembershipComputed>()
+                .get(MembershipKey::new(&frame, settings))
+        });
+        let clicked = Membership::new(&data_frame, Id::new(ID_SOURCE).with("Membership"))
+            .with_selected(settings.membership)
+            .show(ui)?;
+        if let Some(membership) = clicked {
+            settings.membership = (settings.membership != Some(membership)).then_some(membership);
+        }
+        Ok(())
+    }
+
     fn metrics_window(&mut self, ui: &mut Ui, state: &mut State) {
         if let Some(inner_response) = Window::new(format!("{SIGMA} Metrics"))
             .id(ui.auto_id_with(ID_SOURCE).with("Metrics"))
@@ -237,7 +659,21 @@ impl Pane {
                 .get(MetricsKey::new(&frame, &settings))
         });
         _ = Metrics::new(&data_frame, settings).show(ui);
-        Ok(())
+        ui.separator();
+        // Every joined sample, regardless of the view of the groups
+        let mut key = TriacylglycerolsKey::new(&self.frames, settings);
+        key.groups = &UNGROUPED;
+        let frame =
+            ui.memory_mut(|memory| memory.caches.cache::<TriacylglycerolsComputed>().get(key));
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<SignificanceComputed>()
+                .get(SignificanceKey::new(&frame, settings))
+        });
+        Significance::new(&data_frame, Id::new(ID_SOURCE).with("Significance"))
+            .with_precision(settings.precision)
+            .show(ui)
     }
 
     fn moments_window(&mut self, ui: &mut Ui, state: &mut State) {
@@ -262,15 +698,100 @@ impl Pane {
                 .get(TriacylglycerolsKey::new(&self.frames, settings))
         });
         let data_frame = ui.memory_mut(|memory| {
-            memory.caches.cache::<MomentsComputed>().get(MomentsKey {
-                frame: &frame,
-                bias: settings.bias,
+            memory
+                .caches
+                .cache::<MomentsComputed>()
+                .get(MomentsKey::new(&frame, settings))
+        });
+        let distribution = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<DistributionComputed>()
+                .get(DistributionKey::new(&frame, settings))
+        });
+        _ = Moments::new(&data_frame, &distribution, settings).show(ui);
+        Ok(())
+    }
+
+    fn ordination_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{CHART_SCATTER} Ordination"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Ordination"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_ordination)
+            .show(ui.ctx(), |ui| {
+                self.ordination_content(ui, &mut state.settings)
             })
+        {
+            inner_response.response.on_hover_ui(|ui| {
+                ui.label(format!("{DROP}{DROP}{DROP} {}", self.title()));
+            });
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn ordination_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        ui.horizontal(|ui| {
+            for ordination in ORDINATIONS {
+                ui.selectable_value(
+                    &mut settings.ordination,
+                    ordination,
+                    ui.localize(ordination.text()),
+                )
+                .on_hover_localized(ordination.hover_text());
+            }
+        });
+        // Every joined sample colored by the group, regardless of the view of
+        // the groups
+        let mut key = TriacylglycerolsKey::new(&self.frames, settings);
+        key.groups = &UNGROUPED;
+        let frame =
+            ui.memory_mut(|memory| memory.caches.cache::<TriacylglycerolsComputed>().get(key));
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<OrdinationComputed>()
+                .get(OrdinationKey::new(&frame, settings))
+        });
+        Ordination::new(&data_frame, Id::new(ID_SOURCE).with("Ordination"))
+            .with_precision(settings.precision)
+            .show(ui)?;
+        ui.add_enabled_ui(!data_frame.is_empty(), |ui| {
+            let title = self.title();
+            if ui
+                .button((FLOPPY_DISK, "RON"))
+                .on_hover_localized("Save")
+                .on_hover_ui(|ui| {
+                    ui.label(format!("{title}.ordination.utca.ron"));
+                })
+                .clicked()
+            {
+                _ = self.save_ordination(&title, &data_frame);
+            }
         });
-        _ = Moments::new(&data_frame, settings).show(ui);
         Ok(())
     }
 
+    #[instrument(skip_all, err)]
+    fn save_ordination(&self, title: &str, data_frame: &DataFrame) -> Result<()> {
+        let mut meta = self.frames[0].meta.clone();
+        meta.0
+            .insert(NAME.to_owned(), format!("{title} (Ordination)"));
+        export::ron::save(
+            &MetaDataFrame::new(&meta, data_frame),
+            &format!("{title}.ordination.utca.ron"),
+        )
+    }
+
+    fn groups_window(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{USERS_THREE} Groups"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Groups"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_groups)
+            .show(ui.ctx(), |ui| {
+                GroupsWidget::new(&mut state.settings.groups, &self.frames).show(ui);
+            });
+    }
+
     fn settings_window(&mut self, ui: &mut Ui, state: &mut State) {
         Window::new(format!("{SLIDERS_HORIZONTAL} Settings"))
             .id(ui.auto_id_with(ID_SOURCE).with("Settings"))
@@ -282,6 +803,9 @@ impl Pane {
     }
 }
 
+mod expected;
+mod hierarchy;
+mod melting;
 mod metrics;
 mod moments;
 mod table;
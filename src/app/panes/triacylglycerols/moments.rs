@@ -1,6 +1,7 @@
 use crate::app::{
     panes::MARGIN,
     states::triacylglycerols::{ID_SOURCE, settings::Settings},
+    widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
 };
 #[cfg(feature = "markdown")]
 use crate::r#const::markdown::{KURTOSIS, SKEWNESS};
@@ -8,19 +9,30 @@ use egui::{Id, TextStyle, TextWrapMode, Ui};
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown;
 use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
 use polars::prelude::*;
 use tracing::instrument;
 
 /// Moments
+///
+/// Two readings of the moments: the moments of the composition distribution
+/// (the composition value weighted by the abundance) and the moments of the
+/// abundance values themselves.
 pub struct Moments<'a> {
     pub data_frame: &'a DataFrame,
+    pub distribution: &'a DataFrame,
     pub settings: &'a Settings,
 }
 
 impl<'a> Moments<'a> {
-    pub(super) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+    pub(super) fn new(
+        data_frame: &'a DataFrame,
+        distribution: &'a DataFrame,
+        settings: &'a Settings,
+    ) -> Self {
         Self {
             data_frame,
+            distribution,
             settings,
         }
     }
@@ -29,15 +41,69 @@ impl<'a> Moments<'a> {
 impl Moments<'_> {
     #[instrument(skip_all, err)]
     pub fn show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
-        let id_salt = Id::new(ID_SOURCE).with("Moments");
-        let height = ui.text_style_height(&TextStyle::Heading);
-        let rows = self.data_frame.height();
-        let columns = self.data_frame.width();
         ui.style_mut().wrap_mode = if self.settings.truncate {
             Some(TextWrapMode::Truncate)
         } else {
             Some(TextWrapMode::Extend)
         };
+        // Distribution
+        ui.heading(ui.localize("Moments_Distribution"))
+            .on_hover_localized("Moments_Distribution.hover");
+        if self.distribution.is_empty() {
+            ui.label(ui.localize("Moments_Distribution.unavailable"));
+        } else {
+            ui.push_id("Distribution", |ui| self.distribution_table(ui));
+        }
+        ui.separator();
+        // Values
+        ui.heading(ui.localize("Moments_Values"))
+            .on_hover_localized("Moments_Values.hover");
+        ui.push_id("Values", |ui| self.values_table(ui));
+        Ok(())
+    }
+
+    fn distribution_table(&self, ui: &mut Ui) {
+        let id_salt = Id::new(ID_SOURCE).with("MomentsDistribution");
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let rows = self.distribution.height();
+        let columns = self.distribution.width();
+        TableBuilder::new(ui)
+            .id_salt(id_salt)
+            .striped(true)
+            .resizable(true)
+            .columns(Column::auto(), columns)
+            .header(height + 2.0 * MARGIN.y, |mut row| {
+                for name in self.distribution.get_column_names_str() {
+                    row.col(|ui| {
+                        ui.heading(name);
+                    });
+                }
+            })
+            .body(|mut body| {
+                body.ui_mut().style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                body.rows(height, rows, |mut row| {
+                    let index = row.index();
+                    row.col(|ui| {
+                        _ = moment_cell_content_ui(ui, self.distribution, index);
+                    });
+                    for column in 1..columns {
+                        row.col(|ui| {
+                            _ = MeanAndStandardDeviation::new(self.distribution, column, index)
+                                .with_standard_deviation(self.settings.standard_deviation)
+                                .with_dispersion(self.settings.dispersion)
+                                .with_sample(true)
+                                .show(ui);
+                        });
+                    }
+                });
+            });
+    }
+
+    fn values_table(&self, ui: &mut Ui) {
+        let id_salt = Id::new(ID_SOURCE).with("Moments");
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let rows = self.data_frame.height();
+        let columns = self.data_frame.width();
         TableBuilder::new(ui)
             .id_salt(id_salt)
             .striped(true)
@@ -61,24 +127,12 @@ impl Moments<'_> {
                     }
                 });
             });
-        Ok(())
     }
 
     #[instrument(skip(self, ui), err)]
-    fn body_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
+    fn body_cell_content_ui(&self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
         match column {
-            0 => {
-                if let Some(name) = self.data_frame["Moment"].str()?.get(row) {
-                    #[allow(unused_variables)]
-                    let response = ui.label(name);
-                    #[cfg(feature = "markdown")]
-                    response.on_hover_ui(|ui| match name {
-                        "Kurtosis" => ui.markdown(KURTOSIS),
-                        "Skewness" => ui.markdown(SKEWNESS),
-                        _ => {}
-                    });
-                }
-            }
+            0 => moment_cell_content_ui(ui, self.data_frame, row)?,
             column => {
                 if let Some(value) = self.data_frame[column].f64()?.get(row) {
                     let text = format!("{value:.0$}", self.settings.precision);
@@ -89,3 +143,17 @@ impl Moments<'_> {
         Ok(())
     }
 }
+
+fn moment_cell_content_ui(ui: &mut Ui, data_frame: &DataFrame, row: usize) -> PolarsResult<()> {
+    if let Some(name) = data_frame["Moment"].str()?.get(row) {
+        #[allow(unused_variables)]
+        let response = ui.label(name);
+        #[cfg(feature = "markdown")]
+        response.on_hover_ui(|ui| match name {
+            "Kurtosis" => ui.markdown(KURTOSIS),
+            "Skewness" => ui.markdown(SKEWNESS),
+            _ => {}
+        });
+    }
+    Ok(())
+}
@@ -2,18 +2,24 @@ use crate::{
     app::{
         computers::triacylglycerols::{
             Computed as TriacylglycerolsComputed, Key as TriacylglycerolsKey,
+            clusters::{Computed as ClustersComputed, Key as ClustersKey},
             table::{Computed as FormatComputed, Key as FormatKey},
         },
         panes::MARGIN,
         states::triacylglycerols::{ID_SOURCE, State},
-        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+        widgets::{
+            clusters::cluster,
+            mean_and_standard_deviation::MeanAndStandardDeviation,
+            ordination::color,
+            reference::{reference_heading, reference_label},
+        },
     },
     r#const::{SPECIES, THRESHOLD},
     utils::{HashedDataFrame, HashedMetaDataFrame},
 };
 use egui::{
-    Align, Context, Frame, Grid, Id, Label, Layout, Margin, Popup, PopupCloseBehavior, ScrollArea,
-    Sense, TextStyle, TextWrapMode, Ui, Widget,
+    Align, Color32, Context, Frame, Grid, Id, Label, Layout, Margin, Popup, PopupCloseBehavior,
+    ScrollArea, TextStyle, TextWrapMode, Ui, Widget,
 };
 use egui_ext::{InnerResponseExt as _, ResponseExt};
 use egui_l20n::prelude::*;
@@ -28,6 +34,7 @@ use tracing::instrument;
 pub(super) struct TableView<'a> {
     source: &'a mut [HashedMetaDataFrame],
     target: HashedDataFrame,
+    clusters: DataFrame,
     state: &'a mut State,
 }
 
@@ -36,6 +43,7 @@ impl<'a> TableView<'a> {
         Self {
             source: frames,
             target: HashedDataFrame::EMPTY,
+            clusters: DataFrame::empty(),
             state,
         }
     }
@@ -50,6 +58,14 @@ impl TableView<'_> {
                 .cache::<TriacylglycerolsComputed>()
                 .get(TriacylglycerolsKey::new(self.source, &self.state.settings))
         });
+        if self.state.settings.clusters.table {
+            self.clusters = ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<ClustersComputed>()
+                    .get(ClustersKey::new(&self.target, &self.state.settings))
+            });
+        }
         let id_salt = Id::new(ID_SOURCE).with("Table");
         if self.state.event.reset_table_state {
             let id = TableState::id(ui, Id::new(id_salt));
@@ -60,7 +76,7 @@ impl TableView<'_> {
         let num_rows = self.target.height() as u64 + 1;
         let value = self.target.width() - 2;
         let num_columns = headers::LEN + value;
-        Table::new()
+        let mut table = Table::new()
             .id_salt(id_salt)
             .num_rows(num_rows)
             .columns(vec![
@@ -80,11 +96,21 @@ impl TableView<'_> {
                     ],
                 },
                 HeaderRow::new(height),
-            ])
-            .show(ui, self);
+            ]);
+        if let Some(row) = self.state.event.scroll_to_row.take() {
+            table = table.scroll_to_row(row as u64, Some(Align::Center));
+        }
+        table.show(ui, self);
         Ok(())
     }
 
+    /// Cluster color of the sample column
+    fn cluster_color(&self, column: usize) -> Option<Color32> {
+        let name = self.target.get_columns().get(column)?.name();
+        let cluster = cluster(&self.clusters, self.state.settings.clusters.count, name)?;
+        Some(color(cluster))
+    }
+
     fn header_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: Range<usize>) {
         if self.state.settings.truncate {
             ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
@@ -101,7 +127,12 @@ impl TableView<'_> {
                     });
             }
             (0, column) if column.end != self.target.width() => {
-                ui.heading(ui.localize("Value"));
+                ui.heading(format!(
+                    "{} ±{}",
+                    ui.localize("Value"),
+                    ui.localize(self.state.settings.dispersion.abbreviation()),
+                ))
+                .on_hover_localized(self.state.settings.dispersion.hover_text());
             }
             (0, _) => {
                 ui.heading(ui.localize(SPECIES));
@@ -111,7 +142,8 @@ impl TableView<'_> {
                 if !matches!(column, headers::INDEX | headers::TAG)
                     && column.end != self.target.width() =>
             {
-                ui.heading(self.target[column.start].name().to_string());
+                let column = &self.target[column.start];
+                reference_heading(ui, column, &mut self.state.settings.reference);
             }
             //     ui.label(LayoutJob::subscripted_text(
             //         ui,
@@ -163,7 +195,8 @@ impl TableView<'_> {
                         .get(FormatKey::new(&self.target, &self.state.settings))
                 });
                 if let Some(label) = data_frame[LABEL].str()?.get(row) {
-                    let response = Label::new(label).sense(Sense::click()).ui(ui);
+                    let response =
+                        reference_label(ui, label, &mut self.state.settings.transformation);
                     Popup::menu(&response)
                         .id(ui.auto_id_with(SPECIES))
                         .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
@@ -180,6 +213,7 @@ impl TableView<'_> {
                 });
                 MeanAndStandardDeviation::new(&data_frame, column.start, row)
                     .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_dispersion(self.state.settings.dispersion)
                     .with_sample(true)
                     .show(ui)?;
             }
@@ -217,6 +251,7 @@ impl TableView<'_> {
             });
             MeanAndStandardDeviation::new(&data_frame, column.start, data_frame.height() - 1)
                 .with_standard_deviation(self.state.settings.standard_deviation)
+                .with_dispersion(self.state.settings.dispersion)
                 .with_sample(true)
                 .show(ui)?;
         }
@@ -226,11 +261,15 @@ impl TableView<'_> {
 
 impl TableDelegate for TableView<'_> {
     fn header_cell_ui(&mut self, ui: &mut Ui, cell: &HeaderCellInfo) {
-        Frame::new()
-            .inner_margin(Margin::from(MARGIN))
-            .show(ui, |ui| {
-                self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
-            });
+        let mut frame = Frame::new().inner_margin(Margin::from(MARGIN));
+        if cell.row_nr == 1
+            && let Some(color) = self.cluster_color(cell.col_range.start)
+        {
+            frame = frame.fill(color.gamma_multiply(0.5));
+        }
+        frame.show(ui, |ui| {
+            self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
+        });
     }
 
     fn cell_ui(&mut self, ui: &mut Ui, cell: &CellInfo) {
@@ -238,6 +277,10 @@ impl TableDelegate for TableView<'_> {
             ui.painter()
                 .rect_filled(ui.max_rect(), 0.0, ui.visuals().faint_bg_color);
         }
+        if let Some(color) = self.cluster_color(cell.col_nr) {
+            ui.painter()
+                .rect_filled(ui.max_rect(), 0.0, color.gamma_multiply(0.1));
+        }
         Frame::new()
             .inner_margin(Margin::from(MARGIN))
             .show(ui, |ui| {
@@ -8,6 +8,8 @@ pub(crate) const ID_SOURCE: &str = "FattyAcids";
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub(crate) struct State {
     pub(crate) reset_table_state: bool,
+    #[serde(skip)]
+    pub(crate) scroll_to_row: Option<usize>,
     pub(crate) settings: Settings,
     pub(crate) windows: Windows,
 }
@@ -16,6 +18,7 @@ impl State {
     pub(crate) fn new() -> Self {
         Self {
             reset_table_state: false,
+            scroll_to_row: None,
             settings: Settings::new(),
             windows: Windows::new(),
         }
@@ -1,26 +1,28 @@
 use crate::{
     app::{MAX_PRECISION, states::fatty_acids::ID_SOURCE},
-    r#const::markdown::*,
+    r#const::{EM_DASH, markdown::*},
 };
 use egui::{
-    ComboBox, Id, Key, Popup, PopupCloseBehavior, RichText, Slider, Ui, Widget, WidgetText,
+    ComboBox, DragValue, Id, Key, Popup, PopupCloseBehavior, RichText, Slider, Ui, Widget,
+    WidgetText,
 };
 use egui_dnd::dnd;
 use egui_ext::LabeledSeparator;
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown;
 use egui_l20n::prelude::*;
-use egui_phosphor::regular::{BOOKMARK, DOTS_SIX_VERTICAL, EXCLUDE, INTERSECT, UNITE};
+use egui_phosphor::regular::{BOOKMARK, DOTS_SIX_VERTICAL, EXCLUDE, INTERSECT, UNITE, X};
 use lipid::prelude::*;
 use ordered_float::OrderedFloat;
 use polars_utils::format_list_truncated;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     ops::{Deref, DerefMut},
     sync::LazyLock,
 };
 
-pub(crate) const METRICS: [Metric; 9] = [
+pub(crate) const METRICS: [Metric; 10] = [
     Metric::HellingerDistance,
     Metric::JensenShannonDistance,
     Metric::BhattacharyyaDistance,
@@ -32,10 +34,68 @@ pub(crate) const METRICS: [Metric; 9] = [
     Metric::EuclideanDistance,
     Metric::ChebyshevDistance,
     Metric::ManhattanDistance,
+    Metric::AitchisonDistance,
 ];
 
 pub(crate) const SEPARATORS: [usize; 2] = [3, 6];
 
+pub(crate) const DISPERSIONS: [Dispersion; 3] = [
+    Dispersion::StandardDeviation,
+    Dispersion::StandardError,
+    Dispersion::ConfidenceInterval,
+];
+
+pub(crate) const DIVERSITIES: [Diversity; 6] = [
+    Diversity::Shannon,
+    Diversity::Simpson,
+    Diversity::Pielou,
+    Diversity::Hill0,
+    Diversity::Hill1,
+    Diversity::Hill2,
+];
+
+pub(crate) const VIEWS: [View; 3] = [View::Samples, View::Groups, View::Both];
+
+pub(crate) const RELATIONS: [Relation; 3] =
+    [Relation::Difference, Relation::Ratio, Relation::LogRatio];
+
+pub(crate) const SUBSTITUTIONS: [Substitution; 4] = [
+    Substitution::Zero,
+    Substitution::HalfLimit,
+    Substitution::RootTwoLimit,
+    Substitution::Exclusion,
+];
+
+pub(crate) const INTERVALS: [Interval; 2] =
+    [Interval::Percentile, Interval::BiasCorrectedAccelerated];
+
+pub(crate) const BASES: [Basis; 3] = [
+    Basis::MoleFractions,
+    Basis::FattyAcidMassFractions,
+    Basis::MethylEsterMassFractions,
+];
+
+pub(crate) const CLUSTERINGS: [Clustering; 2] = [Clustering::KMeans, Clustering::KMedoids];
+
+pub(crate) const TRANSFORMS: [Transform; 5] = [
+    Transform::None,
+    Transform::Closure,
+    Transform::CenteredLogRatio,
+    Transform::IsometricLogRatio,
+    Transform::AdditiveLogRatio,
+];
+
+pub(crate) const ORDINATIONS: [Ordination; 2] = [
+    Ordination::PrincipalCoordinates,
+    Ordination::NonMetricMultidimensionalScaling,
+];
+
+pub(crate) const MODELS: [Model; 3] = [
+    Model::OneTwoThreeRandom,
+    Model::OneThreeRandomTwoRandom,
+    Model::OneThreeCalculatedTwoRandom,
+];
+
 const ID_SALT: LazyLock<Id> = LazyLock::new(|| Id::new(ID_SOURCE).with("Settings"));
 
 const STEREOSPECIFIC_NUMBERS: [StereospecificNumbers; 3] = [
@@ -63,11 +123,26 @@ pub(crate) struct Settings {
     pub(crate) normalize_factor: bool,
     // Metrics settings
     pub(crate) chaddock: bool,
+    pub(crate) clusters: Clusters,
     pub(crate) metric: Metric,
+    pub(crate) ordination: Ordination,
+    pub(crate) permutations: Permutations,
+    pub(crate) transformation: Transformation,
     // Indices settings
     pub(crate) indices: Indices,
+    // Nutrition settings
+    pub(crate) nutrition: Nutrition,
+    // Statistics settings
+    pub(crate) bootstrap: Bootstrap,
+    pub(crate) ddof: u8,
+    pub(crate) dispersion: Dispersion,
     //
+    pub(crate) detection: Detection,
     pub(crate) filter: Filter,
+    pub(crate) groups: Groups,
+    #[serde(skip)]
+    pub(crate) membership: Option<u64>,
+    pub(crate) reference: Reference,
     pub(crate) sort: Option<Sort>,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
     pub(crate) threshold: Threshold,
@@ -90,12 +165,26 @@ impl Settings {
             normalize_factor: false,
             // Metrics settings
             chaddock: true,
+            clusters: Clusters::new(),
             metric: Metric::HellingerDistance,
+            ordination: Ordination::PrincipalCoordinates,
+            permutations: Permutations::new(),
+            transformation: Transformation::new(),
             // Indices settings
             indices: Indices::new(),
+            // Nutrition settings
+            nutrition: Nutrition::new(),
+            // Statistics settings
+            bootstrap: Bootstrap::new(),
+            ddof: 1,
+            dispersion: Dispersion::StandardDeviation,
 
             stereospecific_numbers: StereospecificNumbers::Sn123,
+            detection: Detection::default(),
             filter: Filter::Union,
+            groups: Groups::default(),
+            membership: None,
+            reference: Reference::default(),
             threshold: Threshold::new(),
             sort: None,
         }
@@ -117,6 +206,7 @@ impl Settings {
         self.filter(ui);
 
         self.sort(ui);
+        self.reference.show(ui);
 
         ui.labeled_separator(ui.localize("Threshold"));
 
@@ -124,6 +214,12 @@ impl Settings {
         self.threshold_sort(ui);
         self.threshold_filter(ui);
 
+        ui.labeled_separator(ui.localize("Statistics"));
+
+        self.ddof(ui);
+        self.dispersion(ui);
+        self.bootstrap.show(ui);
+
         ui.separator();
         ui.labeled_separator(ui.localize("Factor?PluralCategory=other"));
 
@@ -140,6 +236,24 @@ impl Settings {
         self.indices(ui);
     }
 
+    // https://numpy.org/devdocs/reference/generated/numpy.std.html
+    /// DDOF
+    fn ddof(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("DeltaDegreesOfFreedom.abbreviation"))
+                .on_hover_localized("DeltaDegreesOfFreedom")
+                .on_hover_localized("DeltaDegreesOfFreedom.hover");
+            Slider::new(&mut self.ddof, 0..=2)
+                .update_while_editing(false)
+                .ui(ui);
+        });
+    }
+
+    /// Dispersion
+    fn dispersion(&mut self, ui: &mut Ui) {
+        self.dispersion.show(ui);
+    }
+
     /// Precision
     fn precision(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -436,6 +550,12 @@ impl Settings {
                 ui.label(ui.localize("Chaddock.hover"));
             });
         });
+
+        // Permutations
+        self.permutations.show(ui);
+
+        // Transformation
+        self.transformation.show(ui, self.metric);
     }
 
     /// Indices
@@ -525,8 +645,95 @@ impl Factor {
     }
 }
 
+/// Triacylglycerol distribution model
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Model {
+    /// 1,2,3-random: all positions from the SN-1,2,3 composition
+    OneTwoThreeRandom,
+    /// 1,3-random-2-random: SN-1 and SN-3 from the SN-1,3 composition, SN-2
+    /// from the SN-2 composition
+    OneThreeRandomTwoRandom,
+    /// 1,3-random-2-random with the calculated SN-1,3: SN-2 from the SN-2
+    /// composition, SN-1 and SN-3 from the SN-1,3 composition calculated as
+    /// (3·SN-1,2,3 - SN-2) / 2
+    OneThreeCalculatedTwoRandom,
+}
+
+impl Model {
+    pub(crate) fn id(&self) -> &'static str {
+        match self {
+            Self::OneTwoThreeRandom => "1,2,3-random",
+            Self::OneThreeRandomTwoRandom => "1,3-random-2-random",
+            Self::OneThreeCalculatedTwoRandom => "1,3-calculated-2-random",
+        }
+    }
+}
+
+impl Model {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::OneTwoThreeRandom => "Model_OneTwoThreeRandom",
+            Self::OneThreeRandomTwoRandom => "Model_OneThreeRandomTwoRandom",
+            Self::OneThreeCalculatedTwoRandom => "Model_OneThreeCalculatedTwoRandom",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::OneTwoThreeRandom => "Model_OneTwoThreeRandom.hover",
+            Self::OneThreeRandomTwoRandom => "Model_OneThreeRandomTwoRandom.hover",
+            Self::OneThreeCalculatedTwoRandom => "Model_OneThreeCalculatedTwoRandom.hover",
+        }
+    }
+}
+
+/// Diversity index
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Diversity {
+    /// Shannon entropy: -Σ p ln p
+    Shannon,
+    /// Gini-Simpson index: 1 - Σ p²
+    Simpson,
+    /// Pielou evenness: H / ln S
+    Pielou,
+    /// Hill number of order 0: the number of species S
+    Hill0,
+    /// Hill number of order 1: exp(H)
+    Hill1,
+    /// Hill number of order 2: 1 / Σ p²
+    Hill2,
+}
+
+impl Diversity {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Shannon => "Diversity_Shannon",
+            Self::Simpson => "Diversity_Simpson",
+            Self::Pielou => "Diversity_Pielou",
+            Self::Hill0 => "Diversity_Hill0",
+            Self::Hill1 => "Diversity_Hill1",
+            Self::Hill2 => "Diversity_Hill2",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Shannon => "Diversity_Shannon.hover",
+            Self::Simpson => "Diversity_Simpson.hover",
+            Self::Pielou => "Diversity_Pielou.hover",
+            Self::Hill0 => "Diversity_Hill0.hover",
+            Self::Hill1 => "Diversity_Hill1.hover",
+            Self::Hill2 => "Diversity_Hill2.hover",
+        }
+    }
+}
+
 /// Indices
+///
+/// The persisted indices are merged with the known ones: the unknown indices
+/// are dropped, the new ones are appended.
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(from = "Vec<Index>", into = "Vec<Index>")]
 pub(crate) struct Indices(Vec<Index>);
 
 impl Indices {
@@ -551,10 +758,39 @@ impl Indices {
             Index::new("Polyunsaturated-6ToPolyunsaturated-3"),
             Index::new("PolyunsaturatedToSaturated"),
             Index::new("UnsaturationIndex"),
+            Index::new("StearoylCoenzymeADesaturase16"),
+            Index::new("StearoylCoenzymeADesaturase18"),
+            Index::new("Delta12Desaturase"),
+            Index::new("Delta15Desaturase"),
+            Index::new("Delta6Desaturase"),
+            Index::new("Delta5Desaturase"),
+            Index::new("Elongase"),
+            Index::new("AverageChainLength"),
+            Index::new("DoubleBondIndex"),
+            Index::new("PeroxidizabilityIndex"),
         ])
     }
 }
 
+impl From<Vec<Index>> for Indices {
+    fn from(mut indices: Vec<Index>) -> Self {
+        let known = Self::new();
+        indices.retain(|index| known.iter().any(|known| known.name == index.name));
+        for index in known.0 {
+            if !indices.iter().any(|persisted| persisted.name == index.name) {
+                indices.push(index);
+            }
+        }
+        Self(indices)
+    }
+}
+
+impl From<Indices> for Vec<Index> {
+    fn from(indices: Indices) -> Self {
+        indices.0
+    }
+}
+
 impl Deref for Indices {
     type Target = Vec<Index>;
 
@@ -660,6 +896,825 @@ impl Filter {
     }
 }
 
+/// Dispersion
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Dispersion {
+    #[default]
+    StandardDeviation,
+    StandardError,
+    ConfidenceInterval,
+}
+
+impl Dispersion {
+    pub(crate) fn id(&self) -> &'static str {
+        match self {
+            Self::StandardDeviation => "StandardDeviation",
+            Self::StandardError => "StandardError",
+            Self::ConfidenceInterval => "ConfidenceInterval",
+        }
+    }
+
+    pub(crate) fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::StandardDeviation => "Dispersion_StandardDeviation.abbreviation",
+            Self::StandardError => "Dispersion_StandardError.abbreviation",
+            Self::ConfidenceInterval => "Dispersion_ConfidenceInterval.abbreviation",
+        }
+    }
+
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::StandardDeviation => "Dispersion_StandardDeviation",
+            Self::StandardError => "Dispersion_StandardError",
+            Self::ConfidenceInterval => "Dispersion_ConfidenceInterval",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::StandardDeviation => "Dispersion_StandardDeviation.hover",
+            Self::StandardError => "Dispersion_StandardError.hover",
+            Self::ConfidenceInterval => "Dispersion_ConfidenceInterval.hover",
+        }
+    }
+}
+
+impl Dispersion {
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Dispersion"))
+                .on_hover_localized("Dispersion.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Dispersion"))
+                .selected_text(ui.localize(self.text()))
+                .show_ui(ui, |ui| {
+                    for selected_value in DISPERSIONS {
+                        ui.selectable_value(
+                            self,
+                            selected_value,
+                            ui.localize(selected_value.text()),
+                        )
+                        .on_hover_localized(selected_value.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(self.hover_text());
+        });
+    }
+}
+
+/// Permutation test settings
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Permutations {
+    pub(crate) count: u32,
+    pub(crate) seed: u64,
+}
+
+impl Permutations {
+    pub(crate) fn new() -> Self {
+        Self {
+            count: 999,
+            seed: 0,
+        }
+    }
+}
+
+impl Permutations {
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Permutations"))
+                .on_hover_localized("Permutations.hover");
+            DragValue::new(&mut self.count)
+                .range(99..=99999)
+                .update_while_editing(false)
+                .ui(ui);
+        });
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Permutations_Seed"))
+                .on_hover_localized("Permutations_Seed.hover");
+            DragValue::new(&mut self.seed)
+                .update_while_editing(false)
+                .ui(ui);
+        });
+    }
+}
+
+impl Default for Permutations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bootstrap settings
+///
+/// The replicates are resampled within every sample, the intervals of the
+/// indices, the factors and the metrics are computed from the resamples.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Bootstrap {
+    pub(crate) enabled: bool,
+    pub(crate) interval: Interval,
+    pub(crate) resamples: u32,
+    pub(crate) seed: u64,
+}
+
+impl Bootstrap {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: false,
+            interval: Interval::Percentile,
+            resamples: 999,
+            seed: 0,
+        }
+    }
+}
+
+impl Bootstrap {
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Bootstrap"))
+                .on_hover_localized("Bootstrap.hover");
+            ui.checkbox(&mut self.enabled, ());
+        });
+        ui.add_enabled_ui(self.enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Bootstrap_Interval"))
+                    .on_hover_localized("Bootstrap_Interval.hover");
+                ComboBox::from_id_salt(ui.auto_id_with("Interval"))
+                    .selected_text(ui.localize(self.interval.text()))
+                    .show_ui(ui, |ui| {
+                        for selected_value in INTERVALS {
+                            ui.selectable_value(
+                                &mut self.interval,
+                                selected_value,
+                                ui.localize(selected_value.text()),
+                            )
+                            .on_hover_localized(selected_value.hover_text());
+                        }
+                    })
+                    .response
+                    .on_hover_localized(self.interval.hover_text());
+            });
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Bootstrap_Resamples"))
+                    .on_hover_localized("Bootstrap_Resamples.hover");
+                DragValue::new(&mut self.resamples)
+                    .range(99..=99999)
+                    .update_while_editing(false)
+                    .ui(ui);
+            });
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Bootstrap_Seed"))
+                    .on_hover_localized("Bootstrap_Seed.hover");
+                DragValue::new(&mut self.seed)
+                    .update_while_editing(false)
+                    .ui(ui);
+            });
+        });
+    }
+}
+
+impl Default for Bootstrap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bootstrap interval
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Interval {
+    /// Quantiles of the resampled estimates
+    #[default]
+    Percentile,
+    /// Bias-corrected and accelerated (BCa)
+    BiasCorrectedAccelerated,
+}
+
+impl Interval {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Percentile => "Interval_Percentile",
+            Self::BiasCorrectedAccelerated => "Interval_BiasCorrectedAccelerated",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Percentile => "Interval_Percentile.hover",
+            Self::BiasCorrectedAccelerated => "Interval_BiasCorrectedAccelerated.hover",
+        }
+    }
+}
+
+/// Nutrition settings
+///
+/// The fatty acids per 100 grams of the food are the fat per 100 grams of the
+/// food, the lipid conversion factor and the mass fractions of the fatty
+/// acids, converted from the composition by the basis.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Nutrition {
+    /// Total fat, grams per 100 grams of the food
+    pub(crate) fat: OrderedFloat<f64>,
+    /// Lipid conversion factor, grams of the fatty acids per gram of the fat
+    pub(crate) conversion: OrderedFloat<f64>,
+    /// Basis of the composition
+    pub(crate) basis: Basis,
+}
+
+impl Nutrition {
+    pub(crate) fn new() -> Self {
+        Self {
+            fat: OrderedFloat(100.0),
+            conversion: OrderedFloat(0.956),
+            basis: Basis::MethylEsterMassFractions,
+        }
+    }
+}
+
+impl Nutrition {
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Nutrition_Fat"))
+                .on_hover_localized("Nutrition_Fat.hover");
+            DragValue::new(&mut self.fat.0)
+                .range(0.0..=100.0)
+                .speed(0.1)
+                .suffix(" g")
+                .update_while_editing(false)
+                .ui(ui);
+        });
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Nutrition_Conversion"))
+                .on_hover_localized("Nutrition_Conversion.hover");
+            DragValue::new(&mut self.conversion.0)
+                .range(0.0..=1.0)
+                .speed(0.001)
+                .max_decimals(3)
+                .update_while_editing(false)
+                .ui(ui);
+        });
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Nutrition_Basis"))
+                .on_hover_localized("Nutrition_Basis.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Basis"))
+                .selected_text(ui.localize(self.basis.text()))
+                .show_ui(ui, |ui| {
+                    for selected_value in BASES {
+                        ui.selectable_value(
+                            &mut self.basis,
+                            selected_value,
+                            ui.localize(selected_value.text()),
+                        )
+                        .on_hover_localized(selected_value.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(self.basis.hover_text());
+        });
+    }
+}
+
+impl Default for Nutrition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Basis of the composition
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Basis {
+    /// Mole fractions of the fatty acids
+    MoleFractions,
+    /// Mass fractions of the fatty acids
+    FattyAcidMassFractions,
+    /// Mass fractions of the fatty acid methyl esters
+    #[default]
+    MethylEsterMassFractions,
+}
+
+impl Basis {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::MoleFractions => "Basis_MoleFractions",
+            Self::FattyAcidMassFractions => "Basis_FattyAcidMassFractions",
+            Self::MethylEsterMassFractions => "Basis_MethylEsterMassFractions",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::MoleFractions => "Basis_MoleFractions.hover",
+            Self::FattyAcidMassFractions => "Basis_FattyAcidMassFractions.hover",
+            Self::MethylEsterMassFractions => "Basis_MethylEsterMassFractions.hover",
+        }
+    }
+}
+
+/// Compositional transformation
+///
+/// The profiles are the compositions: they are transformed before the
+/// metrics, the significance, the ordination and the clusters. The log-ratio
+/// transforms replace the zeros first.
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Transformation {
+    pub(crate) transform: Transform,
+    /// Reference part (row label) of the additive log-ratio
+    pub(crate) reference: Option<String>,
+    /// Detection limit of the zero replacement
+    pub(crate) detection_limit: OrderedFloat<f64>,
+}
+
+impl Transformation {
+    pub(crate) fn new() -> Self {
+        Self {
+            transform: Transform::None,
+            reference: None,
+            detection_limit: OrderedFloat(0.0001),
+        }
+    }
+
+    /// Toggles the reference part
+    pub(crate) fn toggle(&mut self, label: &str) {
+        if self.reference.as_deref() == Some(label) {
+            self.reference = None;
+        } else {
+            self.reference = Some(label.to_owned());
+        }
+    }
+
+    /// Transform applied with the metric
+    ///
+    /// The Aitchison distance is the Euclidean distance of the centered
+    /// log-ratios. The log-ratios are negative, the metrics other than the
+    /// point metrics use the closure instead.
+    pub(crate) fn transform(&self, metric: Metric) -> Transform {
+        match metric {
+            Metric::AitchisonDistance => Transform::CenteredLogRatio,
+            _ if self.transform.is_log_ratio() && !metric.is_point() => Transform::Closure,
+            _ => self.transform,
+        }
+    }
+}
+
+impl Transformation {
+    pub(crate) fn show(&mut self, ui: &mut Ui, metric: Metric) {
+        let transform = self.transform(metric);
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Transform"))
+                .on_hover_localized("Transform.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Transform"))
+                .selected_text(ui.localize(transform.text()))
+                .show_ui(ui, |ui| {
+                    for selected_value in TRANSFORMS {
+                        ui.add_enabled_ui(
+                            !selected_value.is_log_ratio() || metric.is_point(),
+                            |ui| {
+                                ui.selectable_value(
+                                    &mut self.transform,
+                                    selected_value,
+                                    ui.localize(selected_value.text()),
+                                )
+                                .on_hover_localized(selected_value.hover_text())
+                                .on_disabled_hover_localized("Transform.unavailable");
+                            },
+                        );
+                    }
+                })
+                .response
+                .on_hover_localized(transform.hover_text());
+        });
+        ui.add_enabled_ui(transform == Transform::AdditiveLogRatio, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Transform_Reference"))
+                    .on_hover_localized("Transform_Reference.hover");
+                match &self.reference {
+                    Some(reference) => {
+                        if ui
+                            .button(format!("{reference} {X}"))
+                            .on_hover_localized("Transform_Reference.reset")
+                            .clicked()
+                        {
+                            self.reference = None;
+                        }
+                    }
+                    None => {
+                        ui.label(EM_DASH)
+                            .on_hover_localized("Transform_Reference.select");
+                    }
+                }
+            });
+        });
+        ui.add_enabled_ui(transform.is_log_ratio(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("DetectionLimit"))
+                    .on_hover_localized("DetectionLimit.hover");
+                DragValue::new(&mut self.detection_limit.0)
+                    .range(1e-6..=0.1)
+                    .speed(1e-5)
+                    .max_decimals(6)
+                    .update_while_editing(false)
+                    .ui(ui);
+            });
+        });
+    }
+}
+
+impl Default for Transformation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compositional transform
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Transform {
+    /// Raw means
+    #[default]
+    None,
+    /// x / Σx
+    Closure,
+    /// ln(x / g(x))
+    CenteredLogRatio,
+    /// Pivot balances
+    IsometricLogRatio,
+    /// ln(x / xᵣ)
+    AdditiveLogRatio,
+}
+
+impl Transform {
+    /// Log-ratio transforms require the zero replacement
+    pub(crate) fn is_log_ratio(&self) -> bool {
+        matches!(
+            self,
+            Self::CenteredLogRatio | Self::IsometricLogRatio | Self::AdditiveLogRatio
+        )
+    }
+
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::None => "Transform_None",
+            Self::Closure => "Transform_Closure",
+            Self::CenteredLogRatio => "Transform_CenteredLogRatio",
+            Self::IsometricLogRatio => "Transform_IsometricLogRatio",
+            Self::AdditiveLogRatio => "Transform_AdditiveLogRatio",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::None => "Transform_None.hover",
+            Self::Closure => "Transform_Closure.hover",
+            Self::CenteredLogRatio => "Transform_CenteredLogRatio.hover",
+            Self::IsometricLogRatio => "Transform_IsometricLogRatio.hover",
+            Self::AdditiveLogRatio => "Transform_AdditiveLogRatio.hover",
+        }
+    }
+}
+
+/// Ordination method
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Ordination {
+    #[default]
+    PrincipalCoordinates,
+    NonMetricMultidimensionalScaling,
+}
+
+impl Ordination {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::PrincipalCoordinates => "Ordination_PrincipalCoordinates",
+            Self::NonMetricMultidimensionalScaling => "Ordination_NonMetricMultidimensionalScaling",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::PrincipalCoordinates => "Ordination_PrincipalCoordinates.hover",
+            Self::NonMetricMultidimensionalScaling => {
+                "Ordination_NonMetricMultidimensionalScaling.hover"
+            }
+        }
+    }
+}
+
+/// Clustering method
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Clustering {
+    #[default]
+    KMeans,
+    KMedoids,
+}
+
+impl Clustering {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::KMeans => "Clustering_KMeans",
+            Self::KMedoids => "Clustering_KMedoids",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::KMeans => "Clustering_KMeans.hover",
+            Self::KMedoids => "Clustering_KMedoids.hover",
+        }
+    }
+}
+
+/// Sample clusters settings
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Clusters {
+    pub(crate) clustering: Clustering,
+    /// Number of the clusters
+    pub(crate) count: usize,
+    /// Cluster colors in the table
+    pub(crate) table: bool,
+}
+
+impl Clusters {
+    pub(crate) fn new() -> Self {
+        Self {
+            clustering: Clustering::KMeans,
+            count: 2,
+            table: false,
+        }
+    }
+}
+
+impl Clusters {
+    /// The maximum number of the clusters is one less than the samples
+    pub(crate) fn show(&mut self, ui: &mut Ui, samples: usize) {
+        ui.horizontal(|ui| {
+            for clustering in CLUSTERINGS {
+                ui.selectable_value(
+                    &mut self.clustering,
+                    clustering,
+                    ui.localize(clustering.text()),
+                )
+                .on_hover_localized(clustering.hover_text());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Clusters_Count"))
+                .on_hover_localized("Clusters_Count.hover");
+            DragValue::new(&mut self.count)
+                .range(2..=samples.saturating_sub(1).max(2))
+                .ui(ui);
+        });
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Clusters_Table"))
+                .on_hover_localized("Clusters_Table.hover");
+            ui.checkbox(&mut self.table, "");
+        });
+    }
+}
+
+impl Default for Clusters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Samples without the groups
+///
+/// The joined samples of the tests and the ordination, regardless of the view.
+pub(crate) static UNGROUPED: Groups = Groups {
+    view: View::Samples,
+    assignments: BTreeMap::new(),
+    rule: None,
+};
+
+/// Sample groups
+///
+/// The joined samples are assigned to the named groups, every group is the
+/// mean and the between-sample dispersion of the member sample means.
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Groups {
+    pub(crate) view: View,
+    /// Group name of the sample name
+    pub(crate) assignments: BTreeMap<String, String>,
+    /// Metadata key of the assignment rule
+    pub(crate) rule: Option<String>,
+}
+
+impl Groups {
+    /// Group name of the sample, the sample name for the ungrouped sample
+    pub(crate) fn group<'a>(&'a self, sample: &'a str) -> &'a str {
+        self.assignments
+            .get(sample)
+            .map(String::as_str)
+            .filter(|group| !group.is_empty())
+            .unwrap_or(sample)
+    }
+
+    /// Groups with the member samples, in the order of the samples
+    pub(crate) fn members<'a>(&self, samples: &[&'a str]) -> Vec<(&str, Vec<&'a str>)> {
+        let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+        for &sample in samples {
+            let Some(group) = self
+                .assignments
+                .get(sample)
+                .filter(|group| !group.is_empty())
+            else {
+                continue;
+            };
+            match groups.iter_mut().find(|(name, _)| *name == group.as_str()) {
+                Some((_, members)) => members.push(sample),
+                None => groups.push((group, vec![sample])),
+            }
+        }
+        groups
+    }
+}
+
+/// Detection limits
+///
+/// Every joined sample has its own limits of detection and quantification.
+/// The replicate values below the limit of detection are substituted for the
+/// statistics, the species with the mean below it is not detected. The zero
+/// limit of detection leaves only the zero mean not detected.
+///
+/// The limits apply to the fatty acids only: the triacylglycerols are
+/// calculated or composed, they have no measured replicates to compare with
+/// the limits and keep the threshold as their presence.
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Detection {
+    /// Limits of the sample name
+    pub(crate) limits: BTreeMap<String, Limits>,
+    pub(crate) substitution: Substitution,
+}
+
+impl Detection {
+    /// Limits of the sample, zero for the sample without the limits
+    ///
+    /// The limit of quantification is not below the limit of detection.
+    pub(crate) fn limits(&self, sample: &str) -> Limits {
+        let mut limits = self.limits.get(sample).copied().unwrap_or_default();
+        limits.quantification = limits.quantification.max(limits.detection);
+        limits
+    }
+}
+
+/// Limits of detection and quantification
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Limits {
+    /// Limit of detection (LOD)
+    pub(crate) detection: OrderedFloat<f64>,
+    /// Limit of quantification (LOQ)
+    pub(crate) quantification: OrderedFloat<f64>,
+}
+
+/// Substitution of the replicate values below the limit of detection
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Substitution {
+    /// 0
+    #[default]
+    Zero,
+    /// LOD / 2
+    HalfLimit,
+    /// LOD / √2
+    RootTwoLimit,
+    /// Excluded from the statistics
+    Exclusion,
+}
+
+impl Substitution {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Zero => "Substitution_Zero",
+            Self::HalfLimit => "Substitution_HalfLimit",
+            Self::RootTwoLimit => "Substitution_RootTwoLimit",
+            Self::Exclusion => "Substitution_Exclusion",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Zero => "Substitution_Zero.hover",
+            Self::HalfLimit => "Substitution_HalfLimit.hover",
+            Self::RootTwoLimit => "Substitution_RootTwoLimit.hover",
+            Self::Exclusion => "Substitution_Exclusion.hover",
+        }
+    }
+}
+
+/// Samples or groups view
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum View {
+    #[default]
+    Samples,
+    Groups,
+    Both,
+}
+
+impl View {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Samples => "View_Samples",
+            Self::Groups => "View_Groups",
+            Self::Both => "View_Both",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Samples => "View_Samples.hover",
+            Self::Groups => "View_Groups.hover",
+            Self::Both => "View_Both.hover",
+        }
+    }
+}
+
+/// Reference sample
+///
+/// Every other sample is expressed relative to the reference one.
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Reference {
+    /// Sample (column) name
+    pub(crate) sample: Option<String>,
+    pub(crate) relation: Relation,
+}
+
+impl Reference {
+    /// Toggles the reference sample
+    pub(crate) fn toggle(&mut self, sample: &str) {
+        if self.sample.as_deref() == Some(sample) {
+            self.sample = None;
+        } else {
+            self.sample = Some(sample.to_owned());
+        }
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Reference"))
+                .on_hover_localized("Reference.hover");
+            match &self.sample {
+                Some(sample) => {
+                    if ui
+                        .button(format!("{sample} {X}"))
+                        .on_hover_localized("Reference.reset")
+                        .clicked()
+                    {
+                        self.sample = None;
+                    }
+                }
+                None => {
+                    ui.label(EM_DASH).on_hover_localized("Reference.select");
+                }
+            }
+        });
+        ui.add_enabled_ui(self.sample.is_some(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Relation"))
+                    .on_hover_localized("Relation.hover");
+                ComboBox::from_id_salt(ui.auto_id_with("Relation"))
+                    .selected_text(ui.localize(self.relation.text()))
+                    .show_ui(ui, |ui| {
+                        for selected_value in RELATIONS {
+                            ui.selectable_value(
+                                &mut self.relation,
+                                selected_value,
+                                ui.localize(selected_value.text()),
+                            )
+                            .on_hover_localized(selected_value.hover_text());
+                        }
+                    })
+                    .response
+                    .on_hover_localized(self.relation.hover_text());
+            });
+        });
+    }
+}
+
+/// Relation to the reference sample
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Relation {
+    /// x - r
+    #[default]
+    Difference,
+    /// x / r
+    Ratio,
+    /// ln(x / r)
+    LogRatio,
+}
+
+impl Relation {
+    pub(crate) fn text(&self) -> &'static str {
+        match self {
+            Self::Difference => "Relation_Difference",
+            Self::Ratio => "Relation_Ratio",
+            Self::LogRatio => "Relation_LogRatio",
+        }
+    }
+
+    pub(crate) fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Difference => "Relation_Difference.hover",
+            Self::Ratio => "Relation_Ratio.hover",
+            Self::LogRatio => "Relation_LogRatio.hover",
+        }
+    }
+}
+
 /// Sort
 #[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub(crate) enum Sort {
@@ -694,6 +1749,8 @@ pub(crate) enum Metric {
     EuclideanDistance,
     ChebyshevDistance,
     ManhattanDistance,
+    // Distance between two compositions
+    AitchisonDistance,
     // Distance between two series
     CosineDistance,
     JaccardDistance,
@@ -701,6 +1758,18 @@ pub(crate) enum Metric {
 }
 
 impl Metric {
+    /// Distance between two points of the real space, defined for the negative
+    /// log-ratios
+    pub(crate) fn is_point(&self) -> bool {
+        matches!(
+            self,
+            Metric::EuclideanDistance
+                | Metric::ChebyshevDistance
+                | Metric::ManhattanDistance
+                | Metric::AitchisonDistance
+        )
+    }
+
     pub(crate) fn is_finite(&self) -> bool {
         matches!(
             self,
@@ -721,7 +1790,8 @@ impl Metric {
             Self::BhattacharyyaDistance => Self::EuclideanDistance,
             Self::EuclideanDistance => Self::ChebyshevDistance,
             Self::ChebyshevDistance => Self::ManhattanDistance,
-            Self::ManhattanDistance => Self::CosineDistance,
+            Self::ManhattanDistance => Self::AitchisonDistance,
+            Self::AitchisonDistance => Self::CosineDistance,
             Self::CosineDistance => Self::JaccardDistance,
             Self::JaccardDistance => Self::OverlapDistance,
             Self::OverlapDistance => Self::OverlapDistance,
@@ -736,7 +1806,8 @@ impl Metric {
             Self::EuclideanDistance => Self::BhattacharyyaDistance,
             Self::ChebyshevDistance => Self::EuclideanDistance,
             Self::ManhattanDistance => Self::ChebyshevDistance,
-            Self::CosineDistance => Self::ManhattanDistance,
+            Self::AitchisonDistance => Self::ManhattanDistance,
+            Self::CosineDistance => Self::AitchisonDistance,
             Self::JaccardDistance => Self::CosineDistance,
             Self::OverlapDistance => Self::JaccardDistance,
         }
@@ -752,6 +1823,7 @@ impl Metric {
             Self::EuclideanDistance => "EuclideanDistance",
             Self::ChebyshevDistance => "ChebyshevDistance",
             Self::ManhattanDistance => "ManhattanDistance",
+            Self::AitchisonDistance => "AitchisonDistance",
             Self::CosineDistance => "CosineDistance",
             Self::JaccardDistance => "JaccardDistance",
             Self::OverlapDistance => "OverlapDistance",
@@ -766,6 +1838,7 @@ impl Metric {
             Self::EuclideanDistance => EUCLIDEAN_DISTANCE,
             Self::ChebyshevDistance => CHEBYSHEV_DISTANCE,
             Self::ManhattanDistance => MANHATTAN_DISTANCE,
+            Self::AitchisonDistance => AITCHISON_DISTANCE,
             Self::CosineDistance => COSINE_COEFFICIENT,
             Self::JaccardDistance => JACCARD_COEFFICIENT,
             Self::OverlapDistance => OVERLAP_COEFFICIENT,
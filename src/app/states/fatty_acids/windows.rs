@@ -3,18 +3,38 @@ use serde::{Deserialize, Serialize};
 /// Windows
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct Windows {
+    pub open_biodiesel: bool,
+    pub open_clusters: bool,
+    pub open_comparison: bool,
+    pub open_detection: bool,
+    pub open_diversity: bool,
     pub open_factors: bool,
+    pub open_groups: bool,
     pub open_indices: bool,
+    pub open_membership: bool,
     pub open_metrics: bool,
+    pub open_nutrition: bool,
+    pub open_ordination: bool,
+    pub open_properties: bool,
     pub open_settings: bool,
 }
 
 impl Windows {
     pub fn new() -> Self {
         Self {
+            open_biodiesel: false,
+            open_clusters: false,
+            open_comparison: false,
+            open_detection: false,
+            open_diversity: false,
             open_factors: false,
+            open_groups: false,
             open_indices: false,
+            open_membership: false,
             open_metrics: false,
+            open_nutrition: false,
+            open_ordination: false,
+            open_properties: false,
             open_settings: false,
         }
     }
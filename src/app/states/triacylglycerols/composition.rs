@@ -4,7 +4,7 @@ use self::{
 };
 use serde::{Deserialize, Serialize};
 
-pub const COMPOSITIONS: [Composition; 12] = [
+pub const COMPOSITIONS: [Composition; 18] = [
     SPECIES_STEREO,
     SPECIES_POSITIONAL,
     SPECIES_MONO,
@@ -15,6 +15,12 @@ pub const COMPOSITIONS: [Composition; 12] = [
     MASS_MONO,
     ECN_STEREO,
     ECN_MONO,
+    PN_STEREO,
+    PN_MONO,
+    CN_STEREO,
+    CN_MONO,
+    CNDB_STEREO,
+    CNDB_MONO,
     UNSATURATION_STEREO,
     UNSATURATION_MONO,
 ];
@@ -29,6 +35,21 @@ pub const ECN_MONO: Composition = EquivalentCarbonNumber(NonStereospecific(Agreg
 // Equivalent carbon number composition, stereospecific
 pub const ECN_STEREO: Composition = EquivalentCarbonNumber(Stereospecific);
 
+// Carbon number composition, non-stereospecific, agregation
+pub const CN_MONO: Composition = CarbonNumber(NonStereospecific(Agregation));
+// Carbon number composition, stereospecific
+pub const CN_STEREO: Composition = CarbonNumber(Stereospecific);
+
+// Carbon number and double bond number composition, non-stereospecific, agregation
+pub const CNDB_MONO: Composition = CarbonAndDoubleBondNumber(NonStereospecific(Agregation));
+// Carbon number and double bond number composition, stereospecific
+pub const CNDB_STEREO: Composition = CarbonAndDoubleBondNumber(Stereospecific);
+
+// Partition number composition, non-stereospecific, agregation
+pub const PN_MONO: Composition = PartitionNumber(NonStereospecific(Agregation));
+// Partition number composition, stereospecific
+pub const PN_STEREO: Composition = PartitionNumber(Stereospecific);
+
 // Species composition, non-stereospecific, permutation
 pub const SPECIES_MONO: Composition = Species(NonStereospecific(Permutation { positional: false }));
 // Species composition, non-stereospecific, permutation, positional
@@ -52,8 +73,11 @@ pub const UNSATURATION_STEREO: Composition = Unsaturation(Stereospecific);
 /// Composition
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Composition {
+    CarbonNumber(Stereospecificity<Agregation>),
+    CarbonAndDoubleBondNumber(Stereospecificity<Agregation>),
     EquivalentCarbonNumber(Stereospecificity<Agregation>),
     Mass(Stereospecificity<Agregation>),
+    PartitionNumber(Stereospecificity<Agregation>),
     Species(Stereospecificity<Permutation>),
     Type(Stereospecificity<Permutation>),
     Unsaturation(Stereospecificity<Agregation>),
@@ -63,6 +87,14 @@ impl Composition {
     pub fn new() -> Self {
         SPECIES_STEREO
     }
+
+    /// Whether the composition value is a single number (ECN, mass, ...)
+    pub fn is_scalar(&self) -> bool {
+        matches!(
+            *self,
+            CN_MONO | ECN_MONO | MASS_MONO | PN_MONO | UNSATURATION_MONO
+        )
+    }
 }
 
 impl Composition {
@@ -77,7 +109,13 @@ impl Composition {
             MASS_STEREO => MASS_MONO,
             MASS_MONO => ECN_STEREO,
             ECN_STEREO => ECN_MONO,
-            ECN_MONO => UNSATURATION_STEREO,
+            ECN_MONO => PN_STEREO,
+            PN_STEREO => PN_MONO,
+            PN_MONO => CN_STEREO,
+            CN_STEREO => CN_MONO,
+            CN_MONO => CNDB_STEREO,
+            CNDB_STEREO => CNDB_MONO,
+            CNDB_MONO => UNSATURATION_STEREO,
             UNSATURATION_STEREO => UNSATURATION_MONO,
             UNSATURATION_MONO => UNSATURATION_MONO,
         }
@@ -95,7 +133,13 @@ impl Composition {
             MASS_MONO => MASS_STEREO,
             ECN_STEREO => MASS_MONO,
             ECN_MONO => ECN_STEREO,
-            UNSATURATION_STEREO => ECN_MONO,
+            PN_STEREO => ECN_MONO,
+            PN_MONO => PN_STEREO,
+            CN_STEREO => PN_MONO,
+            CN_MONO => CN_STEREO,
+            CNDB_STEREO => CN_MONO,
+            CNDB_MONO => CNDB_STEREO,
+            UNSATURATION_STEREO => CNDB_MONO,
             UNSATURATION_MONO => UNSATURATION_STEREO,
         }
     }
@@ -104,10 +148,16 @@ impl Composition {
 impl Composition {
     pub fn text(&self) -> &'static str {
         match *self {
+            CN_MONO => "Composition_CarbonNumber_Monospecific",
+            CN_STEREO => "Composition_CarbonNumber_Stereospecific",
+            CNDB_MONO => "Composition_CarbonAndDoubleBondNumber_Monospecific",
+            CNDB_STEREO => "Composition_CarbonAndDoubleBondNumber_Stereospecific",
             MASS_MONO => "Composition_Mass_Monospecific",
             MASS_STEREO => "Composition_Mass_Stereospecific",
             ECN_MONO => "Composition_EquivalentCarbonNumber_Monospecific",
             ECN_STEREO => "Composition_EquivalentCarbonNumber_Stereospecific",
+            PN_MONO => "Composition_PartitionNumber_Monospecific",
+            PN_STEREO => "Composition_PartitionNumber_Stereospecific",
             SPECIES_MONO => "Composition_Species_Monospecific",
             SPECIES_POSITIONAL => "Composition_Species_Positionalspecific",
             SPECIES_STEREO => "Composition_Species_Stereospecific",
@@ -121,10 +171,16 @@ impl Composition {
 
     pub fn hover_text(&self) -> &'static str {
         match *self {
+            CN_MONO => "Composition_CarbonNumber_Monospecific.hover",
+            CN_STEREO => "Composition_CarbonNumber_Stereospecific.hover",
+            CNDB_MONO => "Composition_CarbonAndDoubleBondNumber_Monospecific.hover",
+            CNDB_STEREO => "Composition_CarbonAndDoubleBondNumber_Stereospecific.hover",
             MASS_MONO => "Composition_Mass_Monospecific.hover",
             MASS_STEREO => "Composition_Mass_Stereospecific.hover",
             ECN_MONO => "Composition_EquivalentCarbonNumber_Monospecific.hover",
             ECN_STEREO => "Composition_EquivalentCarbonNumber_Stereospecific.hover",
+            PN_MONO => "Composition_PartitionNumber_Monospecific.hover",
+            PN_STEREO => "Composition_PartitionNumber_Stereospecific.hover",
             SPECIES_MONO => "Composition_Species_Monospecific.hover",
             SPECIES_POSITIONAL => "Composition_Species_Positionalspecific.hover",
             SPECIES_STEREO => "Composition_Species_Stereospecific.hover",
@@ -48,12 +48,14 @@ impl State {
 #[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
 pub struct Event {
     pub reset_table_state: bool,
+    pub scroll_to_row: Option<usize>,
 }
 
 impl Event {
     pub fn new() -> Self {
         Self {
             reset_table_state: false,
+            scroll_to_row: None,
         }
     }
 }
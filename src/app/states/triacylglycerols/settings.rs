@@ -1,7 +1,10 @@
 use crate::app::{
     MAX_PRECISION,
     states::{
-        fatty_acids::settings::{Filter, METRICS, Metric, SEPARATORS, Sort, Threshold},
+        fatty_acids::settings::{
+            Bootstrap, Clusters, Dispersion, Filter, Groups, METRICS, MODELS, Metric, Model,
+            Ordination, Permutations, Reference, SEPARATORS, Sort, Threshold, Transformation,
+        },
         triacylglycerols::{
             ID_SOURCE,
             composition::{
@@ -18,7 +21,7 @@ use egui_ext::LabeledSeparator;
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown;
 use egui_l20n::prelude::*;
-use egui_phosphor::regular::BOOKMARK;
+use egui_phosphor::regular::{BOOKMARK, MINUS, PLUS};
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
@@ -41,12 +44,27 @@ pub struct Settings {
     pub sticky: usize,
     // Metrics settings
     pub chaddock: bool,
+    pub clusters: Clusters,
+    pub ordination: Ordination,
+    pub permutations: Permutations,
+    pub transformation: Transformation,
     // Moment settings
     pub bias: bool,
+    // Hierarchy settings
+    pub hierarchy: Vec<Composition>,
+    // Expected settings
+    pub log_ratio: bool,
+    pub model: Model,
     //
+    pub bootstrap: Bootstrap,
     pub ddof: u8,
+    pub dispersion: Dispersion,
     pub composition: Composition,
     pub filter: Filter,
+    pub groups: Groups,
+    #[serde(skip)]
+    pub membership: Option<u64>,
+    pub reference: Reference,
     pub metric: Metric,
     pub sort: Option<Sort>,
     pub threshold: Threshold,
@@ -67,12 +85,31 @@ impl Settings {
             sticky: 0,
             // Metrics settings
             chaddock: true,
+            clusters: Clusters::new(),
+            ordination: Ordination::PrincipalCoordinates,
+            permutations: Permutations::new(),
+            transformation: Transformation::new(),
             // Moment settings
             bias: true,
+            // Hierarchy settings
+            hierarchy: vec![
+                UNSATURATION_MONO,
+                TYPE_POSITIONAL,
+                SPECIES_POSITIONAL,
+                SPECIES_STEREO,
+            ],
+            // Expected settings
+            log_ratio: false,
+            model: Model::OneThreeRandomTwoRandom,
             //
+            bootstrap: Bootstrap::new(),
             ddof: 1,
+            dispersion: Dispersion::StandardDeviation,
             composition: SPECIES_STEREO,
             filter: Filter::Union,
+            groups: Groups::default(),
+            membership: None,
+            reference: Reference::default(),
             metric: Metric::HellingerDistance,
             sort: None,
             threshold: Threshold::new(),
@@ -94,6 +131,7 @@ impl Settings {
         self.composition(ui);
         self.filter(ui);
         self.sort(ui);
+        self.reference.show(ui);
 
         ui.labeled_separator(ui.localize("Threshold"));
         self.threshold(ui);
@@ -101,17 +139,32 @@ impl Settings {
         // Statistics
         ui.labeled_separator(ui.localize("Statistics"));
         self.ddof(ui);
+        self.dispersion.show(ui);
+        self.bootstrap.show(ui);
 
         // Metrics
         ui.collapsing(ui.localize("Metric?PluralCategory=other"), |ui| {
             self.metric(ui);
             self.chaddock(ui);
+            self.permutations.show(ui);
+            self.transformation.show(ui, self.metric);
         });
 
         // Moments
         ui.collapsing(ui.localize("Moments"), |ui| {
             self.bias(ui);
         });
+
+        // Hierarchy
+        ui.collapsing(ui.localize("Hierarchy"), |ui| {
+            self.hierarchy(ui);
+        });
+
+        // Expected
+        ui.collapsing(ui.localize("Expected"), |ui| {
+            self.model(ui);
+            self.log_ratio(ui);
+        });
     }
 
     // https://numpy.org/devdocs/reference/generated/numpy.std.html
@@ -231,6 +284,78 @@ impl Settings {
         });
     }
 
+    /// Hierarchy
+    fn hierarchy(&mut self, ui: &mut Ui) {
+        let mut remove = None;
+        for (level, composition) in self.hierarchy.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(level.to_string())
+                    .on_hover_localized("Hierarchy_Level.hover");
+                ComboBox::from_id_salt(ui.auto_id_with("Hierarchy").with(level))
+                    .selected_text(ui.localize(composition.text()))
+                    .show_ui(ui, |ui| {
+                        for selected_value in COMPOSITIONS {
+                            ui.selectable_value(
+                                composition,
+                                selected_value,
+                                ui.localize(selected_value.text()),
+                            )
+                            .on_hover_ui(|ui| {
+                                ui.label(ui.localize(selected_value.hover_text()));
+                            });
+                        }
+                    })
+                    .response
+                    .on_hover_text(ui.localize(composition.hover_text()));
+                if ui.button(MINUS).clicked() {
+                    remove = Some(level);
+                }
+            });
+        }
+        if let Some(level) = remove {
+            self.hierarchy.remove(level);
+        }
+        if ui
+            .button(PLUS)
+            .on_hover_localized("Hierarchy_Level.add")
+            .clicked()
+        {
+            self.hierarchy
+                .push(self.hierarchy.last().copied().unwrap_or(self.composition));
+        }
+    }
+
+    /// Model
+    fn model(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Model"))
+                .on_hover_localized("Model.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Model"))
+                .selected_text(ui.localize(self.model.text()))
+                .show_ui(ui, |ui| {
+                    for selected_value in MODELS {
+                        ui.selectable_value(
+                            &mut self.model,
+                            selected_value,
+                            ui.localize(selected_value.text()),
+                        )
+                        .on_hover_localized(selected_value.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(self.model.hover_text());
+        });
+    }
+
+    /// Log ratio
+    fn log_ratio(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("LogRatio"))
+                .on_hover_localized("LogRatio.hover");
+            ui.checkbox(&mut self.log_ratio, ());
+        });
+    }
+
     /// Filter
     fn filter(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -3,18 +3,36 @@ use serde::{Deserialize, Serialize};
 /// Windows
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct Windows {
+    pub open_clusters: bool,
+    pub open_comparison: bool,
+    pub open_diversity: bool,
+    pub open_expected: bool,
+    pub open_groups: bool,
+    pub open_hierarchy: bool,
+    pub open_melting: bool,
     pub open_metadata: bool,
+    pub open_membership: bool,
     pub open_metrics: bool,
     pub open_moments: bool,
+    pub open_ordination: bool,
     pub open_settings: bool,
 }
 
 impl Windows {
     pub fn new() -> Self {
         Self {
+            open_clusters: false,
+            open_comparison: false,
+            open_diversity: false,
+            open_expected: false,
+            open_groups: false,
+            open_hierarchy: false,
+            open_melting: false,
             open_metadata: false,
+            open_membership: false,
             open_metrics: false,
             open_moments: false,
+            open_ordination: false,
             open_settings: false,
         }
     }
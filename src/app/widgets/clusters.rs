@@ -0,0 +1,135 @@
+use super::ordination::color;
+use crate::r#const::{CLUSTER, EM_DASH, SAMPLE, SILHOUETTE};
+use egui::{Grid, Id, RichText, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Bar, BarChart, Plot};
+use polars::prelude::*;
+
+/// Clusters widget
+///
+/// The mean silhouette width of every cluster count and the clusters of the
+/// samples for the selected count.
+pub struct Clusters<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    count: usize,
+    precision: usize,
+}
+
+impl<'a> Clusters<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            count: 2,
+            precision: 2,
+        }
+    }
+
+    pub fn with_count(self, count: usize) -> Self {
+        Self { count, ..self }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+}
+
+impl Clusters<'_> {
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Clusters.unavailable"));
+            return Ok(());
+        }
+        self.silhouettes(ui)?;
+        ui.separator();
+        self.members(ui)
+    }
+
+    /// Mean silhouette width by the cluster count
+    fn silhouettes(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let mut bars = Vec::new();
+        for column in self.data_frame.get_columns() {
+            let Ok(count) = column.name().parse::<usize>() else {
+                continue;
+            };
+            let silhouettes = column.struct_()?.field_by_name(SILHOUETTE)?;
+            if let Some(mean) = silhouettes.f64()?.mean() {
+                let mut bar = Bar::new(count as _, mean).width(0.8);
+                if count == self.count {
+                    bar = bar.fill(ui.visuals().selection.bg_fill);
+                }
+                bars.push(bar);
+            }
+        }
+        Plot::new(self.id_salt.with("Silhouette"))
+            .height(ui.available_height().min(160.0))
+            .x_axis_label(ui.localize("Clusters_Count"))
+            .y_axis_label(ui.localize("Silhouette"))
+            .allow_drag(false)
+            .allow_scroll(false)
+            .allow_zoom(false)
+            .show(ui, |ui| {
+                ui.bar_chart(BarChart::new(ui.localize("Silhouette"), bars));
+            });
+        Ok(())
+    }
+
+    /// Clusters and silhouette widths of the samples
+    fn members(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let name = self.count.to_string();
+        let Ok(column) = self.data_frame.column(&name) else {
+            ui.label(ui.localize("Clusters.unavailable"));
+            return Ok(());
+        };
+        let samples = self.data_frame[SAMPLE].str()?;
+        let clusters = column.struct_()?.field_by_name(CLUSTER)?;
+        let clusters = clusters.u32()?;
+        let silhouettes = column.struct_()?.field_by_name(SILHOUETTE)?;
+        let silhouettes = silhouettes.f64()?;
+        Grid::new(self.id_salt.with("Clusters"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(ui.localize(SAMPLE));
+                ui.label(ui.localize(CLUSTER));
+                ui.label(ui.localize(SILHOUETTE))
+                    .on_hover_localized("Silhouette.hover");
+                ui.end_row();
+                for row in 0..self.data_frame.height() {
+                    ui.label(samples.get(row).unwrap_or_default());
+                    match clusters.get(row) {
+                        Some(cluster) => {
+                            ui.label(
+                                RichText::new((cluster + 1).to_string())
+                                    .color(color(cluster as _))
+                                    .strong(),
+                            );
+                        }
+                        None => {
+                            ui.label(EM_DASH);
+                        }
+                    }
+                    match silhouettes.get(row) {
+                        Some(silhouette) => {
+                            ui.label(format!("{silhouette:.0$}", self.precision))
+                                .on_hover_text(silhouette.to_string());
+                        }
+                        None => {
+                            ui.label(EM_DASH);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        Ok(())
+    }
+}
+
+/// Cluster of the sample for the cluster count
+pub fn cluster(data_frame: &DataFrame, count: usize, sample: &str) -> Option<usize> {
+    let samples = data_frame.column(SAMPLE).ok()?.str().ok()?;
+    let row = samples.iter().position(|other| other == Some(sample))?;
+    let column = data_frame.column(&count.to_string()).ok()?;
+    let clusters = column.struct_().ok()?.field_by_name(CLUSTER).ok()?;
+    Some(clusters.u32().ok()?.get(row)? as _)
+}
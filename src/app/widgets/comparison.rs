@@ -0,0 +1,173 @@
+use crate::r#const::{EM_DASH, FOLD_CHANGE, P_VALUE, ROW};
+use egui::{Color32, Id, TextStyle, TextWrapMode, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::{CHECK, MINUS};
+use egui_plot::{HLine, Plot, PlotPoint, Points, VLine};
+use lipid::prelude::*;
+use polars::prelude::*;
+
+/// Significance level
+const ALPHA: f64 = 0.05;
+/// Pick radius of the volcano plot point, in points
+const RADIUS: f32 = 8.0;
+
+/// Comparison widget
+///
+/// The volcano plot (log2 fold change against -log10 p-value) and the table
+/// of the compared species. Returns the table row of the clicked species.
+pub struct Comparison<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    precision: usize,
+}
+
+impl<'a> Comparison<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            precision: 2,
+        }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+}
+
+impl Comparison<'_> {
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<Option<usize>> {
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Comparison.hover"));
+            return Ok(None);
+        }
+        let mut clicked = self.volcano(ui)?;
+        ui.separator();
+        if let Some(row) = self.table(ui)? {
+            clicked = Some(row);
+        }
+        Ok(clicked)
+    }
+
+    fn volcano(&self, ui: &mut Ui) -> PolarsResult<Option<usize>> {
+        let rows = self.data_frame[ROW].u32()?;
+        let fold_changes = self.data_frame[FOLD_CHANGE].f64()?;
+        let p_values = self.data_frame[P_VALUE].f64()?;
+        let mut significant = Vec::new();
+        let mut insignificant = Vec::new();
+        // (table row, plot point)
+        let mut points = Vec::new();
+        for ((row, fold_change), p_value) in rows.iter().zip(fold_changes).zip(p_values) {
+            if let (Some(row), Some(fold_change), Some(p_value)) = (row, fold_change, p_value) {
+                let point = [fold_change, -p_value.log10()];
+                if p_value < ALPHA && fold_change.abs() >= 1.0 {
+                    significant.push(point);
+                } else {
+                    insignificant.push(point);
+                }
+                points.push((row as usize, PlotPoint::new(point[0], point[1])));
+            }
+        }
+        let significant_name = ui.localize("Volcano_Significant");
+        let insignificant_name = ui.localize("Volcano_Insignificant");
+        Ok(Plot::new(self.id_salt.with("Volcano"))
+            .height(ui.available_height().min(320.0))
+            .x_axis_label(ui.localize("FoldChange"))
+            .y_axis_label(ui.localize("PValue.logarithm"))
+            .show(ui, |ui| {
+                ui.points(Points::new(insignificant_name, insignificant).radius(2.0));
+                ui.points(
+                    Points::new(significant_name, significant)
+                        .radius(3.0)
+                        .color(Color32::RED),
+                );
+                ui.hline(HLine::new(format!("p = {ALPHA}"), -ALPHA.log10()));
+                ui.vline(VLine::new("-1", -1.0));
+                ui.vline(VLine::new("1", 1.0));
+                if ui.response().clicked()
+                    && let Some(pointer) = ui.response().interact_pointer_pos()
+                {
+                    return points
+                        .iter()
+                        .map(|(row, point)| (row, ui.screen_from_plot(*point).distance(pointer)))
+                        .filter(|&(_, distance)| distance <= RADIUS)
+                        .min_by(|left, right| left.1.total_cmp(&right.1))
+                        .map(|(row, _)| *row);
+                }
+                None
+            })
+            .inner)
+    }
+
+    fn table(&self, ui: &mut Ui) -> PolarsResult<Option<usize>> {
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let names = self.data_frame.get_column_names_str();
+        let (first, second) = (names[2], names[3]);
+        let rows = self.data_frame[ROW].u32()?;
+        let labels = self.data_frame[LABEL].str()?;
+        let first_presence = self.data_frame[first].bool()?;
+        let second_presence = self.data_frame[second].bool()?;
+        let fold_changes = self.data_frame[FOLD_CHANGE].f64()?;
+        let p_values = self.data_frame[P_VALUE].f64()?;
+        let mut clicked = None;
+        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+        TableBuilder::new(ui)
+            .id_salt(self.id_salt.with("Table"))
+            .striped(true)
+            .resizable(true)
+            .columns(Column::auto(), 5)
+            .header(height, |mut row| {
+                row.col(|ui| {
+                    ui.heading(ui.localize("Label"));
+                });
+                for name in [first, second] {
+                    row.col(|ui| {
+                        ui.heading(name).on_hover_localized("Presence");
+                    });
+                }
+                row.col(|ui| {
+                    ui.heading(ui.localize("FoldChange"))
+                        .on_hover_localized("FoldChange.hover");
+                });
+                row.col(|ui| {
+                    ui.heading(ui.localize("PValue"))
+                        .on_hover_localized("PValue.hover");
+                });
+            })
+            .body(|body| {
+                body.rows(height, self.data_frame.height(), |mut row| {
+                    let index = row.index();
+                    row.col(|ui| {
+                        if ui
+                            .link(labels.get(index).unwrap_or_default())
+                            .on_hover_localized("Comparison.scroll")
+                            .clicked()
+                        {
+                            clicked = rows.get(index).map(|row| row as usize);
+                        }
+                    });
+                    for presence in [first_presence, second_presence] {
+                        row.col(|ui| {
+                            ui.label(match presence.get(index) {
+                                Some(true) => CHECK,
+                                _ => MINUS,
+                            });
+                        });
+                    }
+                    for values in [fold_changes, p_values] {
+                        row.col(|ui| match values.get(index) {
+                            Some(value) => {
+                                ui.label(format!("{value:.0$}", self.precision))
+                                    .on_hover_text(value.to_string());
+                            }
+                            None => {
+                                ui.label(EM_DASH);
+                            }
+                        });
+                    }
+                });
+            });
+        Ok(clicked)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    app::states::fatty_acids::settings::{Detection, SUBSTITUTIONS},
+    r#const::{LIMIT_OF_DETECTION, LIMIT_OF_QUANTIFICATION},
+    utils::HashedMetaDataFrame,
+};
+use egui::{DragValue, Grid, Ui};
+use egui_l20n::prelude::*;
+
+/// Detection limits editor
+///
+/// Sets the limits of detection and quantification of every joined sample and
+/// the substitution of the replicate values below the limit of detection.
+pub struct DetectionWidget<'a> {
+    detection: &'a mut Detection,
+    frames: &'a [HashedMetaDataFrame],
+}
+
+impl<'a> DetectionWidget<'a> {
+    pub fn new(detection: &'a mut Detection, frames: &'a [HashedMetaDataFrame]) -> Self {
+        Self { detection, frames }
+    }
+}
+
+impl DetectionWidget<'_> {
+    pub fn show(mut self, ui: &mut Ui) {
+        // Substitution
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Substitution"))
+                .on_hover_localized("Substitution.hover");
+            for substitution in SUBSTITUTIONS {
+                ui.selectable_value(
+                    &mut self.detection.substitution,
+                    substitution,
+                    ui.localize(substitution.text()),
+                )
+                .on_hover_localized(substitution.hover_text());
+            }
+        });
+        ui.separator();
+        // Limits
+        Grid::new(ui.auto_id_with("Limits"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.heading(ui.localize("Detection_Sample"));
+                ui.heading(LIMIT_OF_DETECTION)
+                    .on_hover_localized("LimitOfDetection.hover");
+                ui.heading(LIMIT_OF_QUANTIFICATION)
+                    .on_hover_localized("LimitOfQuantification.hover");
+                ui.end_row();
+                for frame in self.frames {
+                    let sample = frame.meta.format(".").to_string();
+                    ui.label(&sample);
+                    let limits = self.detection.limits.entry(sample).or_default();
+                    ui.add(
+                        DragValue::new(&mut limits.detection.0)
+                            .range(0.0..=1.0)
+                            .speed(0.0001),
+                    );
+                    // The limit of quantification is not below the limit of
+                    // detection
+                    limits.quantification.0 = limits.quantification.0.max(limits.detection.0);
+                    ui.add(
+                        DragValue::new(&mut limits.quantification.0)
+                            .range(limits.detection.0..=1.0)
+                            .speed(0.0001),
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+}
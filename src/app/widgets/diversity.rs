@@ -0,0 +1,123 @@
+use crate::{
+    app::{
+        panes::MARGIN, states::fatty_acids::settings::Dispersion,
+        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+    },
+    r#const::{DIVERSITY, MEAN},
+};
+use egui::{Id, TextStyle, TextWrapMode, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Diversity widget
+///
+/// Rows are the diversity indices, every sample is a struct of the mean, the
+/// dispersion and the replicates.
+pub struct Diversity<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    dispersion: Dispersion,
+    standard_deviation: bool,
+    truncate: bool,
+}
+
+impl<'a> Diversity<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            dispersion: Dispersion::StandardDeviation,
+            standard_deviation: false,
+            truncate: false,
+        }
+    }
+
+    pub fn with_dispersion(self, dispersion: Dispersion) -> Self {
+        Self { dispersion, ..self }
+    }
+
+    pub fn with_standard_deviation(self, standard_deviation: bool) -> Self {
+        Self {
+            standard_deviation,
+            ..self
+        }
+    }
+
+    pub fn with_truncate(self, truncate: bool) -> Self {
+        Self { truncate, ..self }
+    }
+}
+
+impl Diversity<'_> {
+    #[instrument(skip_all, err)]
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let rows = self.data_frame.height();
+        let columns = self.data_frame.width();
+        ui.style_mut().wrap_mode = if self.truncate {
+            Some(TextWrapMode::Truncate)
+        } else {
+            Some(TextWrapMode::Extend)
+        };
+        TableBuilder::new(ui)
+            .id_salt(self.id_salt.with("Diversity"))
+            .striped(true)
+            .resizable(true)
+            .columns(Column::auto(), columns)
+            .header(height + 2.0 * MARGIN.y, |mut row| {
+                row.col(|ui| {
+                    ui.heading(ui.localize(DIVERSITY))
+                        .on_hover_localized("Diversity.hover");
+                });
+                for name in &self.data_frame.get_column_names_str()[1..] {
+                    row.col(|ui| {
+                        ui.heading(*name);
+                    });
+                }
+            })
+            .body(|mut body| {
+                body.ui_mut().style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                body.rows(height, rows, |mut row| {
+                    let index = row.index();
+                    for column in 0..columns {
+                        row.col(|ui| {
+                            _ = self.body_cell_content_ui(ui, index, column);
+                        });
+                    }
+                });
+            });
+        Ok(())
+    }
+
+    #[instrument(skip(self, ui), err)]
+    fn body_cell_content_ui(&self, ui: &mut Ui, row: usize, column: usize) -> PolarsResult<()> {
+        match column {
+            0 => {
+                if let Some(text) = self.data_frame[DIVERSITY].str()?.get(row) {
+                    ui.label(ui.localize(text))
+                        .on_hover_text(ui.localize(&format!("{text}.hover")));
+                }
+            }
+            column => {
+                // Undefined index, e.g. the evenness of a single species
+                let mean = self.data_frame[column].struct_()?.field_by_name(MEAN)?;
+                if mean.f64()?.get(row).is_none_or(f64::is_nan) {
+                    ui.label(ui.localize("Undefined"))
+                        .on_hover_localized("Undefined.hover");
+                    return Ok(());
+                }
+                MeanAndStandardDeviation::new(self.data_frame, column, row)
+                    .with_standard_deviation(self.standard_deviation)
+                    .with_dispersion(self.dispersion)
+                    .with_sample(true)
+                    .show(ui)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    app::states::fatty_acids::settings::{Groups, VIEWS},
+    utils::HashedMetaDataFrame,
+};
+use egui::{Button, ComboBox, Grid, Ui};
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::{ERASER, MAGIC_WAND};
+use std::collections::BTreeSet;
+
+/// Sample groups editor
+///
+/// Assigns the joined samples to the named groups by hand or by the metadata
+/// rule: the value of the selected metadata key is the group name.
+pub struct GroupsWidget<'a> {
+    groups: &'a mut Groups,
+    frames: &'a [HashedMetaDataFrame],
+}
+
+impl<'a> GroupsWidget<'a> {
+    pub fn new(groups: &'a mut Groups, frames: &'a [HashedMetaDataFrame]) -> Self {
+        Self { groups, frames }
+    }
+}
+
+impl GroupsWidget<'_> {
+    pub fn show(mut self, ui: &mut Ui) {
+        // View
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("View"))
+                .on_hover_localized("View.hover");
+            for view in VIEWS {
+                ui.selectable_value(&mut self.groups.view, view, ui.localize(view.text()))
+                    .on_hover_localized(view.hover_text());
+            }
+        });
+        // Rule
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Groups_Rule"))
+                .on_hover_localized("Groups_Rule.hover");
+            let keys = self
+                .frames
+                .iter()
+                .flat_map(|frame| frame.meta.0.keys().map(String::as_str))
+                .collect::<BTreeSet<_>>();
+            ComboBox::from_id_salt(ui.auto_id_with("Rule"))
+                .selected_text(self.groups.rule.as_deref().unwrap_or_default())
+                .show_ui(ui, |ui| {
+                    for key in keys {
+                        ui.selectable_value(&mut self.groups.rule, Some(key.to_owned()), key);
+                    }
+                });
+            if ui
+                .add_enabled(self.groups.rule.is_some(), Button::new(MAGIC_WAND))
+                .on_hover_localized("Groups_Rule.apply")
+                .clicked()
+                && let Some(rule) = &self.groups.rule
+            {
+                for frame in self.frames {
+                    if let Some(group) = frame.meta.0.get(rule.as_str()) {
+                        self.groups
+                            .assignments
+                            .insert(frame.meta.format(".").to_string(), group.clone());
+                    }
+                }
+            }
+            if ui
+                .button(ERASER)
+                .on_hover_localized("Groups_Clear")
+                .clicked()
+            {
+                self.groups.assignments.clear();
+            }
+        });
+        ui.separator();
+        // Assignments
+        Grid::new(ui.auto_id_with("Assignments"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.heading(ui.localize("Groups_Sample"));
+                ui.heading(ui.localize("Groups_Group"))
+                    .on_hover_localized("Groups_Group.hover");
+                ui.end_row();
+                for frame in self.frames {
+                    let sample = frame.meta.format(".").to_string();
+                    ui.label(&sample);
+                    let group = self.groups.assignments.entry(sample).or_default();
+                    ui.text_edit_singleline(group);
+                    ui.end_row();
+                }
+            });
+    }
+}
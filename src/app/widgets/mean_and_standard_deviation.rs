@@ -1,4 +1,7 @@
-use crate::r#const::{EM_DASH, MEAN, SAMPLE, STANDARD_DEVIATION};
+use crate::{
+    app::states::fatty_acids::settings::Dispersion,
+    r#const::{EM_DASH, LIMIT, LIMIT_OF_DETECTION, MEAN, SAMPLE, STANDARD_DEVIATION},
+};
 use egui::{Color32, Response, TextWrapMode, Ui, WidgetText};
 use egui_l20n::prelude::*;
 use polars::prelude::*;
@@ -10,6 +13,7 @@ pub struct MeanAndStandardDeviation<'a> {
     column: usize,
     row: usize,
     color: Option<Color32>,
+    dispersion: Dispersion,
     sample: bool,
     standard_deviation: bool,
 }
@@ -21,6 +25,7 @@ impl<'a> MeanAndStandardDeviation<'a> {
             column,
             row,
             color: None,
+            dispersion: Dispersion::StandardDeviation,
             sample: false,
             standard_deviation: false,
         }
@@ -30,6 +35,10 @@ impl<'a> MeanAndStandardDeviation<'a> {
         Self { color, ..self }
     }
 
+    pub fn with_dispersion(self, dispersion: Dispersion) -> Self {
+        Self { dispersion, ..self }
+    }
+
     pub fn with_sample(self, sample: bool) -> Self {
         Self { sample, ..self }
     }
@@ -52,7 +61,20 @@ impl MeanAndStandardDeviation<'_> {
             .struct_()?
             .field_by_name(STANDARD_DEVIATION)?;
         let standard_deviation = standard_deviation_series.f64()?.get(self.row);
+        // The limit the value is below, only the fatty acid samples have it
+        let limit_series = self.data_frame[self.column]
+            .struct_()?
+            .field_by_name(LIMIT)
+            .ok();
+        let limit = match &limit_series {
+            Some(series) => series.str()?.get(self.row),
+            None => None,
+        };
         let mut text = match mean {
+            Some(_) if limit == Some(LIMIT_OF_DETECTION) => {
+                WidgetText::from(format!("<{LIMIT_OF_DETECTION}"))
+            }
+            Some(mean) if limit.is_some() => WidgetText::from(mean.to_string()).italics(),
             Some(mean)
                 if self.standard_deviation
                     && let Some(standard_deviation) = standard_deviation =>
@@ -67,11 +89,26 @@ impl MeanAndStandardDeviation<'_> {
         }
         let mut response = ui.label(text);
         if response.hovered() {
+            // Limit
+            if let Some(limit) = limit {
+                response = response.on_hover_ui(|ui| {
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                    ui.heading(format!("<{limit}"));
+                    ui.label(ui.localize(if limit == LIMIT_OF_DETECTION {
+                        "Limit.detection"
+                    } else {
+                        "Limit.quantification"
+                    }));
+                    if let Some(mean) = mean {
+                        ui.label(mean.to_string());
+                    }
+                });
+            }
             // Standard deviation
             if let Some(standard_deviation) = standard_deviation {
                 response = response.on_hover_ui(|ui| {
                     ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    ui.heading(ui.localize(STANDARD_DEVIATION));
+                    ui.heading(ui.localize(self.dispersion.text()));
                     ui.label(format!("±{standard_deviation}"));
                 });
             }
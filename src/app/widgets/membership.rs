@@ -0,0 +1,261 @@
+use crate::{
+    app::computers::MEMBERSHIP_LIMIT,
+    r#const::{COUNT, MEMBERSHIP},
+};
+use egui::{Align2, Color32, FontId, Grid, Id, Pos2, Sense, Stroke, Ui, Vec2, emath::Rot2, vec2};
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::X;
+use egui_plot::{Bar, BarChart, Plot};
+use polars::prelude::*;
+
+/// Venn diagram circle radius, in points
+const RADIUS: f32 = 60.0;
+
+/// Set membership widget
+///
+/// The UpSet plot of the exclusive intersections of the samples and the Venn
+/// diagram for up to three samples. Returns the clicked intersection.
+pub struct Membership<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    selected: Option<u64>,
+}
+
+impl<'a> Membership<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            selected: None,
+        }
+    }
+
+    pub fn with_selected(self, selected: Option<u64>) -> Self {
+        Self { selected, ..self }
+    }
+}
+
+impl Membership<'_> {
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<Option<u64>> {
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Membership.hover"));
+            return Ok(None);
+        }
+        let names = &self.data_frame.get_column_names_str()[2..];
+        // The bitmask holds up to the limit samples, the selected intersection
+        // is reset
+        if names.len() > MEMBERSHIP_LIMIT {
+            ui.label(format!(
+                "{} {MEMBERSHIP_LIMIT}",
+                ui.localize("Membership.limit")
+            ));
+            return Ok(self.selected);
+        }
+        let memberships = self.data_frame[MEMBERSHIP]
+            .u64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        let counts = self.data_frame[COUNT]
+            .cast(&DataType::UInt32)?
+            .u32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        let mut clicked = None;
+        // Selected intersection, clicking it again resets the table filter
+        if let Some(selected) = self.selected {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Membership.filter"));
+                let text = names
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| selected & (1u64 << bit) != 0)
+                    .map(|(_, name)| *name)
+                    .collect::<Vec<_>>()
+                    .join(" ∩ ");
+                if ui
+                    .button(format!("{text} {X}"))
+                    .on_hover_localized("Membership.reset")
+                    .clicked()
+                {
+                    clicked = Some(selected);
+                }
+            });
+            ui.separator();
+        }
+        if let Some(membership) = self.upset(ui, names, &memberships, &counts) {
+            clicked = Some(membership);
+        }
+        if names.len() <= 3 {
+            ui.separator();
+            if let Some(membership) = self.venn(ui, names, &memberships, &counts) {
+                clicked = Some(membership);
+            }
+        }
+        Ok(clicked)
+    }
+
+    /// UpSet plot: the intersection sizes above the membership matrix
+    fn upset(
+        &self,
+        ui: &mut Ui,
+        names: &[&str],
+        memberships: &[u64],
+        counts: &[u32],
+    ) -> Option<u64> {
+        let mut clicked = None;
+        let bars = counts
+            .iter()
+            .enumerate()
+            .map(|(index, &count)| {
+                let bar = Bar::new(index as f64, count as f64).width(0.6);
+                if Some(memberships[index]) == self.selected {
+                    bar.fill(ui.visuals().selection.bg_fill)
+                } else {
+                    bar
+                }
+            })
+            .collect();
+        let response = Plot::new(self.id_salt.with("UpSet"))
+            .height(160.0)
+            .show_x(false)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .y_axis_label(ui.localize(COUNT))
+            .show(ui, |ui| {
+                ui.bar_chart(BarChart::new(MEMBERSHIP, bars));
+                if ui.response().clicked() {
+                    ui.pointer_coordinate()
+                        .map(|coordinate| coordinate.x.round())
+                        .filter(|&x| x >= 0.0 && (x as usize) < memberships.len())
+                        .map(|x| memberships[x as usize])
+                } else {
+                    None
+                }
+            });
+        if response.inner.is_some() {
+            clicked = response.inner;
+        }
+        // Membership matrix
+        Grid::new(self.id_salt.with("Matrix"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                for (index, &membership) in memberships.iter().enumerate() {
+                    if ui
+                        .selectable_label(
+                            Some(membership) == self.selected,
+                            counts[index].to_string(),
+                        )
+                        .on_hover_localized("Membership.filter")
+                        .clicked()
+                    {
+                        clicked = Some(membership);
+                    }
+                }
+                ui.end_row();
+                for (bit, name) in names.iter().enumerate() {
+                    ui.label(*name);
+                    for &membership in memberships {
+                        ui.label(if membership & (1u64 << bit) != 0 {
+                            "●"
+                        } else {
+                            "○"
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+        clicked
+    }
+
+    /// Venn diagram for up to three samples
+    fn venn(
+        &self,
+        ui: &mut Ui,
+        names: &[&str],
+        memberships: &[u64],
+        counts: &[u32],
+    ) -> Option<u64> {
+        let size = vec2(4.0 * RADIUS, 3.6 * RADIUS);
+        let (response, painter) = ui.allocate_painter(size, Sense::click());
+        let center = response.rect.center();
+        // Circle centers around the diagram center
+        let offset = match names.len() {
+            1 => 0.0,
+            2 => RADIUS * 0.55,
+            _ => RADIUS * 0.6,
+        };
+        let centers = (0..names.len())
+            .map(|index| {
+                let angle = -std::f32::consts::FRAC_PI_2
+                    + index as f32 * std::f32::consts::TAU / names.len() as f32;
+                center + Rot2::from_angle(angle) * Vec2::new(offset, 0.0)
+            })
+            .collect::<Vec<_>>();
+        let text_color = ui.visuals().text_color();
+        let font_id = FontId::proportional(14.0);
+        for (index, (&circle, name)) in centers.iter().zip(names).enumerate() {
+            let color = Color32::from_rgb(
+                [230, 80, 80][index % 3],
+                [80, 160, 80][index % 3],
+                [80, 80, 230][index % 3],
+            );
+            painter.circle(
+                circle,
+                RADIUS,
+                color.gamma_multiply(0.2),
+                Stroke::new(1.0, color),
+            );
+            let outward = (circle - center).normalized();
+            let outward = if outward.is_finite() && outward != Vec2::ZERO {
+                outward
+            } else {
+                -Vec2::Y
+            };
+            painter.text(
+                center + outward * (offset + RADIUS * 1.1),
+                Align2::CENTER_CENTER,
+                *name,
+                font_id.clone(),
+                color,
+            );
+        }
+        // Region labels
+        for (&membership, count) in memberships.iter().zip(counts) {
+            let mut position = center.to_vec2();
+            for (bit, &circle) in centers.iter().enumerate() {
+                let direction = circle - center;
+                if membership & (1u64 << bit) != 0 {
+                    position += direction * 0.6;
+                } else {
+                    position -= direction * 0.6;
+                }
+            }
+            let color = if Some(membership) == self.selected {
+                ui.visuals().selection.stroke.color
+            } else {
+                text_color
+            };
+            painter.text(
+                Pos2::ZERO + position,
+                Align2::CENTER_CENTER,
+                count.to_string(),
+                font_id.clone(),
+                color,
+            );
+        }
+        // The clicked region is the set of the circles under the pointer
+        if response.clicked()
+            && let Some(pointer) = response.interact_pointer_pos()
+        {
+            let membership = centers
+                .iter()
+                .enumerate()
+                .filter(|(_, circle)| circle.distance(pointer) <= RADIUS)
+                .fold(0, |membership, (bit, _)| membership | 1u64 << bit);
+            return (membership != 0).then_some(membership);
+        }
+        None
+    }
+}
@@ -2,6 +2,15 @@ pub(super) use self::{github::Github, presets::Presets};
 
 pub(super) mod about;
 pub(super) mod buttons;
+pub(super) mod clusters;
+pub(super) mod comparison;
+pub(super) mod detection;
+pub(super) mod diversity;
 pub(super) mod github;
+pub(super) mod groups;
 pub(super) mod mean_and_standard_deviation;
+pub(super) mod membership;
+pub(super) mod ordination;
 pub(super) mod presets;
+pub(super) mod reference;
+pub(super) mod significance;
@@ -0,0 +1,115 @@
+use crate::r#const::{EXPLAINED, GROUP, SAMPLE, STRESS, X, Y};
+use egui::{Align2, Color32, Id, Ui, ecolor::Hsva};
+use egui_l20n::prelude::*;
+use egui_plot::{Legend, MarkerShape, Plot, PlotPoint, Points, Text};
+use polars::prelude::*;
+use std::collections::BTreeMap;
+
+/// Golden ratio conjugate, spreads the group hues
+const GOLDEN_RATIO: f32 = 0.618_034;
+
+/// Ordination widget
+///
+/// The scatter of the replicates in the ordination space, colored by the
+/// group, with the sample centroids and the stress or the explained variance
+/// of the axes.
+pub struct Ordination<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    precision: usize,
+}
+
+impl<'a> Ordination<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            precision: 2,
+        }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+}
+
+impl Ordination<'_> {
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Ordination.unavailable"));
+            return Ok(());
+        }
+        let samples = self.data_frame[SAMPLE].str()?;
+        let groups = self.data_frame[GROUP].str()?;
+        let xs = self.data_frame[X].f64()?;
+        let ys = self.data_frame[Y].f64()?;
+        let explained = self.data_frame[EXPLAINED].struct_()?;
+        let explained_x = explained.field_by_name(X)?.f64()?.get(0);
+        let explained_y = explained.field_by_name(Y)?.f64()?.get(0);
+        let stress = self.data_frame[STRESS].f64()?.get(0);
+        // Group name, (replicate points, sample centroids)
+        let mut series = BTreeMap::<_, (Vec<_>, BTreeMap<_, Vec<_>>)>::new();
+        for row in 0..self.data_frame.height() {
+            if let (Some(sample), Some(group), Some(x), Some(y)) =
+                (samples.get(row), groups.get(row), xs.get(row), ys.get(row))
+            {
+                let (points, centroids) = series.entry(group).or_default();
+                points.push([x, y]);
+                centroids.entry(sample).or_default().push([x, y]);
+            }
+        }
+        let axis = |index: usize, explained: Option<f64>| match explained {
+            Some(explained) => format!(
+                "{} {index} ({:.1$}%)",
+                ui.localize("Ordination.axis"),
+                explained * 100.0,
+                self.precision,
+            ),
+            None => format!("{} {index}", ui.localize("Ordination.axis")),
+        };
+        let (x_axis, y_axis) = (axis(1, explained_x), axis(2, explained_y));
+        if let Some(stress) = stress {
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("Stress"))
+                    .on_hover_localized("Stress.hover");
+                ui.label(format!("{stress:.0$}", self.precision))
+                    .on_hover_text(stress.to_string());
+            });
+        }
+        Plot::new(self.id_salt.with("Ordination"))
+            .height(ui.available_height().min(320.0))
+            .data_aspect(1.0)
+            .legend(Legend::default())
+            .x_axis_label(x_axis)
+            .y_axis_label(y_axis)
+            .show(ui, |ui| {
+                for (index, (group, (points, centroids))) in series.into_iter().enumerate() {
+                    let color = color(index);
+                    ui.points(Points::new(group, points).radius(3.0).color(color));
+                    for (sample, points) in centroids {
+                        let count = points.len() as f64;
+                        let [x, y] = points.iter().fold([0.0, 0.0], |[x, y], point| {
+                            [x + point[0] / count, y + point[1] / count]
+                        });
+                        ui.points(
+                            Points::new(group, [x, y])
+                                .radius(5.0)
+                                .shape(MarkerShape::Diamond)
+                                .color(color),
+                        );
+                        ui.text(
+                            Text::new(group, PlotPoint::new(x, y), sample)
+                                .anchor(Align2::LEFT_BOTTOM)
+                                .color(color),
+                        );
+                    }
+                }
+            });
+        Ok(())
+    }
+}
+
+/// Color of the group or the cluster
+pub fn color(index: usize) -> Color32 {
+    Hsva::new((index as f32 * GOLDEN_RATIO).fract(), 0.85, 0.5, 1.0).into()
+}
@@ -0,0 +1,82 @@
+use crate::{
+    app::states::fatty_acids::settings::{Reference, Transformation},
+    r#const::SAMPLE,
+    utils::polars::count_arr,
+};
+use egui::{Label, Popup, Response, RichText, Sense, Ui, Widget};
+use egui_l20n::prelude::*;
+use egui_phosphor::regular::ANCHOR;
+use polars::prelude::*;
+
+/// Sample column heading
+///
+/// The reference sample is marked, the context menu toggles the reference.
+/// The non-null replicate count of the sample follows the name.
+pub fn reference_heading(ui: &mut Ui, column: &Column, reference: &mut Reference) {
+    let name = column.name().as_str();
+    let is_reference = reference.sample.as_deref() == Some(name);
+    let text = match replicates(column) {
+        Some(replicates) => format!(
+            "{name} ({})",
+            ui.localize(&format!("Replicates?n={replicates}"))
+        ),
+        None => name.to_owned(),
+    };
+    let response = if is_reference {
+        ui.heading(RichText::new(format!("{ANCHOR} {text}")).strong())
+            .on_hover_localized("Reference")
+    } else if reference.sample.is_some() {
+        ui.heading(text)
+            .on_hover_localized(reference.relation.hover_text())
+    } else {
+        ui.heading(text)
+    };
+    Popup::context_menu(&response).show(|ui| {
+        let text = if is_reference {
+            "Reference.reset"
+        } else {
+            "Reference.set"
+        };
+        if ui.button((ANCHOR, ui.localize(text))).clicked() {
+            reference.toggle(name);
+        }
+    });
+}
+
+/// Replicates of the sample column: the largest non-null replicate count of
+/// the species
+fn replicates(column: &Column) -> Option<u64> {
+    let sample = column.struct_().ok()?.field_by_name(SAMPLE).ok()?;
+    let data_frame = DataFrame::new(vec![sample.into_column()])
+        .ok()?
+        .lazy()
+        .select([count_arr(col(SAMPLE)).max()])
+        .collect()
+        .ok()?;
+    Some(data_frame[0].f64().ok()?.get(0)? as _)
+}
+
+/// Row label
+///
+/// The additive log-ratio reference part is marked, the context menu toggles
+/// the reference.
+pub fn reference_label(ui: &mut Ui, label: &str, transformation: &mut Transformation) -> Response {
+    let is_reference = transformation.reference.as_deref() == Some(label);
+    let text = if is_reference {
+        RichText::new(format!("{ANCHOR} {label}")).strong()
+    } else {
+        RichText::new(label)
+    };
+    let response = Label::new(text).sense(Sense::click()).ui(ui);
+    Popup::context_menu(&response).show(|ui| {
+        let text = if is_reference {
+            "Transform_Reference.reset"
+        } else {
+            "Transform_Reference.set"
+        };
+        if ui.button((ANCHOR, ui.localize(text))).clicked() {
+            transformation.toggle(label);
+        }
+    });
+    response
+}
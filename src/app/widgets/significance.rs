@@ -0,0 +1,71 @@
+use crate::r#const::{EM_DASH, P_VALUE, R_SQUARED, STATISTIC, TEST};
+use egui::{Grid, Id, Ui};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+
+/// Significance widget
+///
+/// PERMANOVA and ANOSIM results: the statistic, R² and the permutation
+/// p-value of every test.
+pub struct Significance<'a> {
+    data_frame: &'a DataFrame,
+    id_salt: Id,
+    precision: usize,
+}
+
+impl<'a> Significance<'a> {
+    pub fn new(data_frame: &'a DataFrame, id_salt: Id) -> Self {
+        Self {
+            data_frame,
+            id_salt,
+            precision: 2,
+        }
+    }
+
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+}
+
+impl Significance<'_> {
+    pub fn show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        ui.heading(ui.localize("Significance"))
+            .on_hover_localized("Significance.hover");
+        if self.data_frame.is_empty() {
+            ui.label(ui.localize("Significance.unavailable"));
+            return Ok(());
+        }
+        let tests = self.data_frame[TEST].str()?;
+        let statistics = self.data_frame[STATISTIC].f64()?;
+        let r_squared = self.data_frame[R_SQUARED].f64()?;
+        let p_values = self.data_frame[P_VALUE].f64()?;
+        Grid::new(self.id_salt.with("Significance"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label(ui.localize("Statistic"))
+                    .on_hover_localized("Statistic.hover");
+                ui.label(ui.localize("RSquared"))
+                    .on_hover_localized("RSquared.hover");
+                ui.label(ui.localize("PValue"))
+                    .on_hover_localized("Significance.p_value");
+                ui.end_row();
+                for row in 0..self.data_frame.height() {
+                    ui.label(tests.get(row).unwrap_or_default());
+                    for values in [statistics, r_squared, p_values] {
+                        match values.get(row) {
+                            Some(value) => {
+                                ui.label(format!("{value:.0$}", self.precision))
+                                    .on_hover_text(value.to_string());
+                            }
+                            None => {
+                                ui.label(EM_DASH);
+                            }
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        Ok(())
+    }
+}
@@ -9,13 +9,45 @@ pub(crate) const EM_DASH: &str = "—";
 pub(crate) const NON_BREAKING_HYPHEN: &str = "‑";
 pub(crate) const NO_BREAK_SPACE: &str = " ";
 
+pub(crate) const CARBON_NUMBER: &str = "CarbonNumber";
+pub(crate) const CLUSTER: &str = "Cluster";
 pub(crate) const COMPOSITION: &str = "Composition";
+pub(crate) const COUNT: &str = "Count";
+pub(crate) const DISCREPANCY: &str = "Discrepancy";
+pub(crate) const DISPERSION: &str = "Dispersion";
+pub(crate) const DIVERSITY: &str = "Diversity";
+pub(crate) const DOUBLE_BOND_NUMBER: &str = "DoubleBondNumber";
+pub(crate) const EXPECTED: &str = "Expected";
+pub(crate) const EXPLAINED: &str = "Explained";
+pub(crate) const FOLD_CHANGE: &str = "FoldChange";
+pub(crate) const GROUP: &str = "Group";
+pub(crate) const LEVEL: &str = "Level";
+pub(crate) const LIMIT: &str = "Limit";
+pub(crate) const LIMIT_OF_DETECTION: &str = "LOD";
+pub(crate) const LIMIT_OF_QUANTIFICATION: &str = "LOQ";
+pub(crate) const LOWER: &str = "Lower";
 pub(crate) const MEAN: &str = "Mean";
+pub(crate) const MEMBERSHIP: &str = "Membership";
+pub(crate) const OBSERVED: &str = "Observed";
+pub(crate) const P_VALUE: &str = "PValue";
+pub(crate) const PROPERTY: &str = "Property";
+pub(crate) const RATIO: &str = "Ratio";
+pub(crate) const REFERENCE_INTAKE: &str = "ReferenceIntake";
+pub(crate) const REPLICATE: &str = "Replicate";
+pub(crate) const ROW: &str = "Row";
+pub(crate) const R_SQUARED: &str = "RSquared";
 pub(crate) const SAMPLE: &str = "Sample";
+pub(crate) const SILHOUETTE: &str = "Silhouette";
 pub(crate) const SPECIES: &str = "Species";
+pub(crate) const STATISTIC: &str = "Statistic";
 pub(crate) const STANDARD_DEVIATION: &str = "StandardDeviation";
+pub(crate) const STRESS: &str = "Stress";
+pub(crate) const TEST: &str = "Test";
 pub(crate) const THRESHOLD: &str = "Threshold";
+pub(crate) const UPPER: &str = "Upper";
 pub(crate) const VALUE: &str = "Value";
+pub(crate) const X: &str = "X";
+pub(crate) const Y: &str = "Y";
 
 pub(crate) mod markdown {
     pub const KURTOSIS: &str = include_str!("../doc/ru/Moments/Kurtosis.md");
@@ -39,6 +71,9 @@ pub(crate) mod markdown {
         include_str!("../doc/ru/Similarity/BetweenTwoPoints/Canberra.md");
     pub const MINKOWSKI_DISTANCE: &str =
         include_str!("../doc/ru/Similarity/BetweenTwoPoints/Minkowski.md");
+    // Similarity between two compositions
+    pub const AITCHISON_DISTANCE: &str =
+        include_str!("../doc/ru/Similarity/BetweenTwoCompositions/Aitchison.md");
     // Similarity between two sets
     pub const BRAUN_BLANQUET_COEFFICIENT: &str =
         include_str!("../doc/ru/Similarity/BetweenTwoSets/Braun-Blanquet.md");
@@ -41,6 +41,7 @@ mod sources {
     pub(super) const RU: &[&str] = &[
         asset!("/ftl/en/aocs.org.ftl"),
         asset!("/ftl/en/aocs.org.ext.ftl"),
-        // asset!("/ftl/ru/main.ftl"),
+        asset!("/ftl/ru/main.ftl"),
+        asset!("/ftl/ru/properties.ftl"),
     ];
 }
@@ -0,0 +1,178 @@
+use super::statistics::{SplitMix64, normal_cdf, normal_quantile};
+use std::cmp::Ordering;
+
+/// Two-sided 95% interval
+const ALPHA: f64 = 0.05;
+
+/// Bootstrap resamples of the replicate indices
+///
+/// Every resample draws the replicates of every sample with replacement, the
+/// counts are the replicate counts of the samples.
+pub fn resamples(counts: &[usize], resamples: u32, seed: u64) -> Vec<Vec<Vec<usize>>> {
+    let mut random = SplitMix64(seed);
+    (0..resamples)
+        .map(|_| {
+            counts
+                .iter()
+                .map(|&count| {
+                    (0..count)
+                        .map(|_| (random.next_u64() % count.max(1) as u64) as usize)
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Jackknife subsamples of the replicate indices
+///
+/// Every subsample leaves out one replicate of one sample, the samples with
+/// one replicate are kept whole.
+pub fn jackknife(counts: &[usize]) -> Vec<Vec<Vec<usize>>> {
+    let whole = counts
+        .iter()
+        .map(|&count| (0..count).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut subsamples = Vec::new();
+    for (sample, &count) in counts.iter().enumerate() {
+        if count < 2 {
+            continue;
+        }
+        for left in 0..count {
+            let mut subsample = whole.clone();
+            subsample[sample].remove(left);
+            subsamples.push(subsample);
+        }
+    }
+    subsamples
+}
+
+/// Percentile interval (Efron, 1979)
+///
+/// The 2.5% and 97.5% quantiles of the finite bootstrap estimates.
+pub fn percentile(estimates: &[f64]) -> Option<[f64; 2]> {
+    let sorted = sorted(estimates)?;
+    Some([
+        quantile(&sorted, ALPHA / 2.0),
+        quantile(&sorted, 1.0 - ALPHA / 2.0),
+    ])
+}
+
+/// Bias-corrected and accelerated interval (Efron, 1987)
+///
+/// The percentile interval shifted by the median bias of the bootstrap
+/// estimates and scaled by the acceleration, the skewness of the jackknife
+/// estimates. The bootstrap estimates equal to the estimate count as half
+/// below it.
+pub fn bias_corrected_accelerated(
+    estimate: f64,
+    estimates: &[f64],
+    jackknife: &[f64],
+) -> Option<[f64; 2]> {
+    let sorted = sorted(estimates)?;
+    let below = sorted
+        .iter()
+        .map(|&value| match value.total_cmp(&estimate) {
+            Ordering::Less => 1.0,
+            Ordering::Equal => 0.5,
+            Ordering::Greater => 0.0,
+        })
+        .sum::<f64>();
+    let bias = normal_quantile(below / sorted.len() as f64);
+    if !bias.is_finite() {
+        return percentile(estimates);
+    }
+    let jackknife = jackknife
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect::<Vec<_>>();
+    let mean = jackknife.iter().sum::<f64>() / jackknife.len().max(1) as f64;
+    let (squares, cubes) = jackknife
+        .iter()
+        .fold((0.0, 0.0), |(squares, cubes), value| {
+            let deviation = mean - value;
+            (squares + deviation.powi(2), cubes + deviation.powi(3))
+        });
+    let acceleration = if squares > 0.0 {
+        cubes / (6.0 * squares.powf(1.5))
+    } else {
+        0.0
+    };
+    let level = |alpha: f64| {
+        let z = bias + normal_quantile(alpha);
+        normal_cdf(bias + z / (1.0 - acceleration * z))
+    };
+    Some([
+        quantile(&sorted, level(ALPHA / 2.0)),
+        quantile(&sorted, level(1.0 - ALPHA / 2.0)),
+    ])
+}
+
+/// Sorted finite values, none for less than two
+fn sorted(values: &[f64]) -> Option<Vec<f64>> {
+    let mut sorted = values
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect::<Vec<_>>();
+    if sorted.len() < 2 {
+        return None;
+    }
+    sorted.sort_by(f64::total_cmp);
+    Some(sorted)
+}
+
+/// Quantile of the sorted values with the linear interpolation
+fn quantile(sorted: &[f64], probability: f64) -> f64 {
+    let position = probability.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[cfg(test)]
+mod test {
+    const EPSILON: f64 = 1e-4;
+
+    /// Estimates 0, 1, …, 99
+    fn estimates() -> Vec<f64> {
+        (0..100).map(f64::from).collect()
+    }
+
+    #[test]
+    fn percentile() {
+        // The quantiles 0.025 · 99 and 0.975 · 99
+        let [lower, upper] = super::percentile(&estimates()).unwrap();
+        assert!((lower - 2.475).abs() < EPSILON);
+        assert!((upper - 96.525).abs() < EPSILON);
+        // The infinite estimates are dropped
+        let [lower, upper] = super::percentile(&[f64::NAN, 1.0, 3.0, f64::INFINITY]).unwrap();
+        assert!((lower - 1.05).abs() < EPSILON);
+        assert!((upper - 2.95).abs() < EPSILON);
+        assert!(super::percentile(&[1.0, f64::NAN]).is_none());
+    }
+
+    #[test]
+    fn bias_corrected_accelerated() {
+        // Without the bias and the acceleration it is the percentile interval
+        let symmetric = (0..=100).map(f64::from).collect::<Vec<_>>();
+        let interval =
+            super::bias_corrected_accelerated(50.0, &symmetric, &[-1.0, 0.0, 1.0]).unwrap();
+        let percentile = super::percentile(&symmetric).unwrap();
+        assert!((interval[0] - percentile[0]).abs() < EPSILON);
+        assert!((interval[1] - percentile[1]).abs() < EPSILON);
+        // The ties count as half
+        let interval = super::bias_corrected_accelerated(1.0, &[1.0; 10], &[1.0; 3]).unwrap();
+        assert_eq!(interval, [1.0, 1.0]);
+        // z₀ = Φ⁻¹(0.31): Φ(2z₀ ± 1.96) · 99
+        let interval = super::bias_corrected_accelerated(30.5, &estimates(), &[0.0; 3]).unwrap();
+        assert!((interval[0] - 0.156_453).abs() < EPSILON);
+        assert!((interval[1] - 82.520_812).abs() < EPSILON);
+        // a = Σ(θ̄ - θᵢ)³ / 6(Σ(θ̄ - θᵢ)²)^1.5 = -0.084853
+        let interval =
+            super::bias_corrected_accelerated(30.5, &estimates(), &[1.0, 2.0, 3.0, 10.0]).unwrap();
+        assert!((interval[0] - 0.015_866).abs() < EPSILON);
+        assert!((interval[1] - 78.211_407).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,294 @@
+use super::statistics::SplitMix64;
+
+/// Number of the k-means restarts
+const RESTARTS: u64 = 16;
+/// Maximum number of the k-means iterations
+const ITERATIONS: usize = 100;
+
+/// k-means clustering (Lloyd, 1982)
+///
+/// k-means++ seeding, the best of the seeded restarts by the within-cluster
+/// sum of squares. Undefined for less than two clusters or for less distinct
+/// points than clusters.
+pub fn kmeans(points: &[Vec<f64>], count: usize) -> Option<Vec<usize>> {
+    if count < 2 || count >= points.len() {
+        return None;
+    }
+    let mut best: Option<(f64, Vec<usize>)> = None;
+    for seed in 0..RESTARTS {
+        let Some(mut centroids) = seeding(points, count, seed) else {
+            continue;
+        };
+        let mut clusters = vec![0; points.len()];
+        for iteration in 0..ITERATIONS {
+            let assigned = points
+                .iter()
+                .map(|point| nearest(point, &centroids).0)
+                .collect::<Vec<_>>();
+            if iteration > 0 && assigned == clusters {
+                break;
+            }
+            clusters = assigned;
+            for (cluster, centroid) in centroids.iter_mut().enumerate() {
+                let members = points
+                    .iter()
+                    .zip(&clusters)
+                    .filter(|&(_, &other)| other == cluster)
+                    .map(|(point, _)| point)
+                    .collect::<Vec<_>>();
+                // The empty cluster keeps its centroid
+                if members.is_empty() {
+                    continue;
+                }
+                for (dimension, value) in centroid.iter_mut().enumerate() {
+                    *value = members.iter().map(|member| member[dimension]).sum::<f64>()
+                        / members.len() as f64;
+                }
+            }
+        }
+        let inertia = points
+            .iter()
+            .map(|point| nearest(point, &centroids).1)
+            .sum::<f64>();
+        if best.as_ref().is_none_or(|(best, _)| inertia < *best) {
+            best = Some((inertia, clusters));
+        }
+    }
+    best.map(|(_, clusters)| canonical(&clusters))
+}
+
+/// k-means++ seeding (Arthur and Vassilvitskii, 2007)
+fn seeding(points: &[Vec<f64>], count: usize, seed: u64) -> Option<Vec<Vec<f64>>> {
+    let mut random = SplitMix64(seed);
+    let first = (random.next_u64() % points.len() as u64) as usize;
+    let mut centroids = vec![points[first].clone()];
+    while centroids.len() < count {
+        let weights = points
+            .iter()
+            .map(|point| nearest(point, &centroids).1)
+            .collect::<Vec<_>>();
+        let total = weights.iter().sum::<f64>();
+        if total == 0.0 {
+            return None;
+        }
+        let mut target = random.next_f64() * total;
+        let index = weights
+            .iter()
+            .position(|&weight| {
+                target -= weight;
+                target < 0.0
+            })
+            .unwrap_or(points.len() - 1);
+        centroids.push(points[index].clone());
+    }
+    Some(centroids)
+}
+
+/// Nearest centroid and the squared distance to it
+fn nearest(point: &[f64], centroids: &[Vec<f64>]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|centroid| {
+            point
+                .iter()
+                .zip(centroid)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+        })
+        .enumerate()
+        .min_by(|left, right| left.1.total_cmp(&right.1))
+        .unwrap_or_default()
+}
+
+/// k-medoids clustering, partitioning around medoids (Kaufman and Rousseeuw,
+/// 1990)
+///
+/// The greedy BUILD and the best improvement SWAP on the distance matrix.
+/// Undefined for less than two clusters or for not less clusters than points.
+pub fn pam(distances: &[Vec<f64>], count: usize) -> Option<Vec<usize>> {
+    let size = distances.len();
+    if count < 2 || count >= size {
+        return None;
+    }
+    let cost = |medoids: &[usize]| {
+        (0..size)
+            .map(|point| {
+                medoids
+                    .iter()
+                    .map(|&medoid| distances[point][medoid])
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum::<f64>()
+    };
+    // Build
+    let mut medoids = Vec::with_capacity(count);
+    while medoids.len() < count {
+        let candidate = (0..size)
+            .filter(|candidate| !medoids.contains(candidate))
+            .map(|candidate| (candidate, cost(&[&medoids[..], &[candidate]].concat())))
+            .min_by(|left, right| left.1.total_cmp(&right.1))?
+            .0;
+        medoids.push(candidate);
+    }
+    // Swap
+    let mut current = cost(&medoids);
+    loop {
+        let mut best = None;
+        for index in 0..count {
+            for candidate in (0..size).filter(|candidate| !medoids.contains(candidate)) {
+                let mut swapped = medoids.clone();
+                swapped[index] = candidate;
+                let cost = cost(&swapped);
+                if cost < best.as_ref().map_or(current, |(cost, _)| *cost) {
+                    best = Some((cost, swapped));
+                }
+            }
+        }
+        let Some((cost, swapped)) = best else {
+            break;
+        };
+        current = cost;
+        medoids = swapped;
+    }
+    let clusters = (0..size)
+        .map(|point| {
+            (0..count)
+                .min_by(|&left, &right| {
+                    distances[point][medoids[left]].total_cmp(&distances[point][medoids[right]])
+                })
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    Some(canonical(&clusters))
+}
+
+/// Silhouette widths of the points (Rousseeuw, 1987)
+///
+/// The width of the point in the singleton cluster is zero.
+pub fn silhouette(distances: &[Vec<f64>], clusters: &[usize]) -> Vec<f64> {
+    let count = clusters.iter().max().map_or(0, |max| max + 1);
+    (0..clusters.len())
+        .map(|point| {
+            let mut sums = vec![0.0; count];
+            let mut sizes = vec![0usize; count];
+            for other in (0..clusters.len()).filter(|&other| other != point) {
+                sums[clusters[other]] += distances[point][other];
+                sizes[clusters[other]] += 1;
+            }
+            let own = clusters[point];
+            if sizes[own] == 0 {
+                return 0.0;
+            }
+            let a = sums[own] / sizes[own] as f64;
+            let b = (0..count)
+                .filter(|&cluster| cluster != own && sizes[cluster] != 0)
+                .map(|cluster| sums[cluster] / sizes[cluster] as f64)
+                .fold(f64::INFINITY, f64::min);
+            if !b.is_finite() || a.max(b) == 0.0 {
+                return 0.0;
+            }
+            (b - a) / a.max(b)
+        })
+        .collect()
+}
+
+/// Clusters numbered in the order of the first point
+fn canonical(clusters: &[usize]) -> Vec<usize> {
+    let mut order = Vec::new();
+    clusters
+        .iter()
+        .map(
+            |cluster| match order.iter().position(|other| other == cluster) {
+                Some(index) => index,
+                None => {
+                    order.push(*cluster);
+                    order.len() - 1
+                }
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    const EPSILON: f64 = 1e-12;
+
+    /// Points 0, 1, 10, 11, 12 on the line
+    fn points() -> Vec<Vec<f64>> {
+        [0.0, 1.0, 10.0, 11.0, 12.0]
+            .into_iter()
+            .map(|point| vec![point])
+            .collect()
+    }
+
+    fn distances(points: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        points
+            .iter()
+            .map(|a| {
+                points
+                    .iter()
+                    .map(|b| {
+                        a.iter()
+                            .zip(b)
+                            .map(|(a, b)| (a - b).powi(2))
+                            .sum::<f64>()
+                            .sqrt()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kmeans() {
+        assert_eq!(super::kmeans(&points(), 2).unwrap(), [0, 0, 1, 1, 1]);
+        let points = [
+            [0.0, 0.0],
+            [10.0, 10.0],
+            [0.5, 0.0],
+            [-10.0, 10.0],
+            [10.5, 10.0],
+            [0.0, 0.5],
+            [-10.0, 10.5],
+        ]
+        .map(Vec::from);
+        assert_eq!(super::kmeans(&points, 3).unwrap(), [0, 1, 0, 2, 1, 0, 2]);
+        // Undefined
+        assert!(super::kmeans(&points, 1).is_none());
+        assert!(super::kmeans(&points, 7).is_none());
+        assert!(super::kmeans(&[vec![1.0], vec![1.0], vec![1.0]], 2).is_none());
+    }
+
+    #[test]
+    fn pam() {
+        let distances = distances(&points());
+        assert_eq!(super::pam(&distances, 2).unwrap(), [0, 0, 1, 1, 1]);
+        assert_eq!(super::pam(&distances, 3).unwrap().len(), 5);
+        // Undefined
+        assert!(super::pam(&distances, 1).is_none());
+        assert!(super::pam(&distances, 5).is_none());
+    }
+
+    #[test]
+    fn silhouette() {
+        let distances = distances(&points());
+        // s = (b - a) / max(a, b): 0 has a = 1, b = (10 + 11 + 12) / 3, 10 has
+        // a = (1 + 2) / 2, b = (10 + 9) / 2
+        let expected = [
+            10.0 / 11.0,
+            9.0 / 10.0,
+            16.0 / 19.0,
+            19.0 / 21.0,
+            20.0 / 23.0,
+        ];
+        let widths = super::silhouette(&distances, &[0, 0, 1, 1, 1]);
+        for (width, expected) in widths.iter().zip(expected) {
+            assert!((width - expected).abs() < EPSILON);
+        }
+        // The misplaced point has the negative width: 1 has a = (9 + 10 + 11) / 3,
+        // b = 1
+        let widths = super::silhouette(&distances, &[0, 1, 1, 1, 1]);
+        assert_eq!(widths[0], 0.0);
+        assert!((widths[1] + 0.9).abs() < EPSILON);
+    }
+}
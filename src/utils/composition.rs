@@ -0,0 +1,161 @@
+/// Share of the detection limit the zeros are replaced with
+const DELTA: f64 = 0.65;
+
+/// Closure: the parts rescaled to the unit sum
+///
+/// The zero composition is kept.
+pub fn closure(parts: &mut [f64]) {
+    let sum = parts.iter().sum::<f64>();
+    if sum > 0.0 {
+        for part in parts {
+            *part /= sum;
+        }
+    }
+}
+
+/// Multiplicative replacement of the zeros (Martín-Fernández et al., 2003)
+///
+/// The closed composition, every zero part is replaced with 65% of the
+/// detection limit, the nonzero parts are shrunk to keep the unit sum and
+/// their ratios. The replacement is at most 65% of the equal share of the
+/// parts, the nonzero parts stay positive.
+pub fn replace_zeros(parts: &mut [f64], detection_limit: f64) {
+    closure(parts);
+    let delta = DELTA * detection_limit.min(1.0 / parts.len().max(1) as f64);
+    let zeros = parts.iter().filter(|&&part| part <= 0.0).count();
+    let shrink = 1.0 - delta * zeros as f64;
+    for part in parts {
+        if *part <= 0.0 {
+            *part = delta;
+        } else {
+            *part *= shrink;
+        }
+    }
+}
+
+/// Centered log-ratio transform (Aitchison, 1986)
+///
+/// clr(x)ᵢ = ln(xᵢ / g(x)), g(x) is the geometric mean of the parts. The parts
+/// are positive.
+pub fn clr(parts: &mut [f64]) {
+    let mean = parts.iter().map(|part| part.ln()).sum::<f64>() / parts.len().max(1) as f64;
+    for part in parts {
+        *part = part.ln() - mean;
+    }
+}
+
+/// Additive log-ratio transform (Aitchison, 1986)
+///
+/// alr(x)ᵢ = ln(xᵢ / xᵣ), the reference part itself becomes zero. The parts are
+/// positive.
+pub fn alr(parts: &mut [f64], reference: usize) {
+    let Some(&denominator) = parts.get(reference) else {
+        return;
+    };
+    for part in parts {
+        *part = (*part / denominator).ln();
+    }
+}
+
+/// Isometric log-ratio transform (Egozcue et al., 2003)
+///
+/// The pivot balances: ilr(x)ᵢ = √((D - i) / (D - i + 1)) ln(xᵢ / g(xᵢ₊₁, …,
+/// x_D)). The D parts give D - 1 coordinates, the last one is zero. The parts
+/// are positive.
+pub fn ilr(parts: &mut [f64]) {
+    let count = parts.len();
+    let logarithms = parts.iter().map(|part| part.ln()).collect::<Vec<_>>();
+    for (index, part) in parts.iter_mut().enumerate() {
+        let rest = (count - index - 1) as f64;
+        *part = if rest > 0.0 {
+            let mean = logarithms[index + 1..].iter().sum::<f64>() / rest;
+            (rest / (rest + 1.0)).sqrt() * (logarithms[index] - mean)
+        } else {
+            0.0
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    const EPSILON: f64 = 1e-12;
+
+    fn euclidean(a: &[f64], b: &[f64]) -> f64 {
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    #[test]
+    fn closure() {
+        let mut parts = [1.0, 3.0, 4.0];
+        super::closure(&mut parts);
+        assert_eq!(parts, [0.125, 0.375, 0.5]);
+        let mut parts = [0.0; 3];
+        super::closure(&mut parts);
+        assert_eq!(parts, [0.0; 3]);
+    }
+
+    #[test]
+    fn replace_zeros() {
+        let mut parts = [0.0, 20.0, 0.0, 60.0];
+        super::replace_zeros(&mut parts, 0.01);
+        // The zeros are 65% of the detection limit
+        assert!((parts[0] - 0.0065).abs() < EPSILON);
+        assert!((parts[2] - 0.0065).abs() < EPSILON);
+        // The unit sum and the ratios of the nonzero parts are kept
+        assert!((parts.iter().sum::<f64>() - 1.0).abs() < EPSILON);
+        assert!((parts[3] / parts[1] - 3.0).abs() < EPSILON);
+        // The detection limit above the equal share of the parts
+        let mut parts = [0.0, 0.0, 0.0, 1.0];
+        super::replace_zeros(&mut parts, 0.5);
+        assert!(parts.iter().all(|&part| part > 0.0));
+        assert!((parts.iter().sum::<f64>() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn clr() {
+        let mut parts = [0.1, 0.2, 0.3, 0.4];
+        super::clr(&mut parts);
+        // The centered log-ratios sum to zero
+        assert!(parts.iter().sum::<f64>().abs() < EPSILON);
+        assert!((parts[1] - parts[0] - 2f64.ln()).abs() < EPSILON);
+        // Scale invariant
+        let mut scaled = [1.0, 2.0, 3.0, 4.0];
+        super::clr(&mut scaled);
+        assert!(euclidean(&parts, &scaled) < EPSILON);
+    }
+
+    #[test]
+    fn ilr() {
+        let (x, y) = ([0.1, 0.2, 0.3, 0.4], [0.25, 0.05, 0.5, 0.2]);
+        let (mut x_clr, mut y_clr) = (x, y);
+        super::clr(&mut x_clr);
+        super::clr(&mut y_clr);
+        let (mut x_ilr, mut y_ilr) = (x, y);
+        super::ilr(&mut x_ilr);
+        super::ilr(&mut y_ilr);
+        // D - 1 coordinates
+        assert_eq!(x_ilr[3], 0.0);
+        // The Aitchison distance is kept
+        assert!((euclidean(&x_ilr, &y_ilr) - euclidean(&x_clr, &y_clr)).abs() < EPSILON);
+        // The first balance: √(3 / 4) ln(x₁ / g(x₂, x₃, x₄))
+        let mean = (0.2f64.ln() + 0.3f64.ln() + 0.4f64.ln()) / 3.0;
+        assert!((x_ilr[0] - 0.75f64.sqrt() * (0.1f64.ln() - mean)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn alr() {
+        let mut parts = [0.1, 0.2, 0.3, 0.4];
+        super::alr(&mut parts, 3);
+        assert!((parts[0] - 0.25f64.ln()).abs() < EPSILON);
+        assert!((parts[1] - 0.5f64.ln()).abs() < EPSILON);
+        assert_eq!(parts[3], 0.0);
+        // The reference out of the parts
+        let mut parts = [0.1, 0.9];
+        super::alr(&mut parts, 2);
+        assert_eq!(parts, [0.1, 0.9]);
+    }
+}
@@ -3,7 +3,12 @@ pub use self::{
     spawn::spawn,
 };
 
+pub(crate) mod bootstrap;
+pub(crate) mod clustering;
+pub(crate) mod composition;
+pub(crate) mod ordination;
 pub(crate) mod polars;
+pub(crate) mod statistics;
 
 mod hash;
 mod layout_job;
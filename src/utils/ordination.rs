@@ -0,0 +1,359 @@
+/// Maximum number of the NMDS iterations
+const ITERATIONS: usize = 500;
+/// NMDS convergence tolerance of the stress
+const TOLERANCE: f64 = 1e-9;
+
+/// PCoA result
+#[derive(Clone, Debug)]
+pub struct Pcoa {
+    /// Coordinates of the observations on the first two principal axes
+    pub coordinates: Vec<[f64; 2]>,
+    /// Shares of the variance explained by the first two principal axes
+    pub explained: [f64; 2],
+}
+
+/// Principal coordinates analysis (Gower, 1966)
+///
+/// The eigen decomposition of the double-centered matrix of the squared
+/// distances, the negative eigenvalues are ignored. Undefined for less than
+/// three observations or for the zero distances.
+pub fn pcoa(distances: &[Vec<f64>]) -> Option<Pcoa> {
+    let count = distances.len();
+    if count < 3 {
+        return None;
+    }
+    let mut matrix = distances
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|distance| -0.5 * distance * distance)
+                .collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    let means = matrix
+        .iter()
+        .map(|row| row.iter().sum::<f64>() / count as f64)
+        .collect::<Vec<_>>();
+    let mean = means.iter().sum::<f64>() / count as f64;
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value += mean - means[i] - means[j];
+        }
+    }
+    let (values, vectors) = eigen(matrix);
+    let mut axes = (0..count).collect::<Vec<_>>();
+    axes.sort_by(|&left, &right| values[right].total_cmp(&values[left]));
+    let total = values.iter().filter(|&&value| value > 0.0).sum::<f64>();
+    if total <= 0.0 {
+        return None;
+    }
+    let value = |axis: usize| values[axes[axis]].max(0.0);
+    let coordinate =
+        |observation: usize, axis: usize| vectors[observation][axes[axis]] * value(axis).sqrt();
+    let coordinates = (0..count)
+        .map(|observation| [0, 1].map(|axis| coordinate(observation, axis)))
+        .collect();
+    Some(Pcoa {
+        coordinates,
+        explained: [value(0) / total, value(1) / total],
+    })
+}
+
+/// NMDS result
+#[derive(Clone, Debug)]
+pub struct Nmds {
+    /// Coordinates of the observations in two dimensions
+    pub coordinates: Vec<[f64; 2]>,
+    /// Kruskal's stress-1
+    pub stress: f64,
+}
+
+/// Non-metric multidimensional scaling (Kruskal, 1964)
+///
+/// SMACOF iterations with the monotone regression of the configuration
+/// distances on the dissimilarity ranks, started from the PCoA coordinates.
+/// Undefined when PCoA is undefined.
+pub fn nmds(distances: &[Vec<f64>]) -> Option<Nmds> {
+    let count = distances.len();
+    let mut coordinates = pcoa(distances)?.coordinates;
+    // Pairs ordered by the dissimilarity
+    let mut pairs = (0..count)
+        .flat_map(|i| (i + 1..count).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    pairs.sort_by(|&(a, b), &(c, d)| distances[a][b].total_cmp(&distances[c][d]));
+    let mut stress = f64::INFINITY;
+    for _ in 0..ITERATIONS {
+        let configuration = pairs
+            .iter()
+            .map(|&(i, j)| euclidean(coordinates[i], coordinates[j]))
+            .collect::<Vec<_>>();
+        let squares = configuration
+            .iter()
+            .map(|distance| distance * distance)
+            .sum::<f64>();
+        if squares == 0.0 {
+            return None;
+        }
+        let disparities = isotonic(&configuration);
+        let residuals = configuration
+            .iter()
+            .zip(&disparities)
+            .map(|(distance, disparity)| (distance - disparity).powi(2))
+            .sum::<f64>();
+        let current = (residuals / squares).sqrt();
+        if stress - current < TOLERANCE {
+            stress = current.min(stress);
+            break;
+        }
+        stress = current;
+        // Disparities normalized to the number of pairs
+        let scale = (pairs.len() as f64
+            / disparities
+                .iter()
+                .map(|disparity| disparity * disparity)
+                .sum::<f64>())
+        .sqrt();
+        // Guttman transform
+        let mut ratios = vec![vec![0.0; count]; count];
+        for (index, &(i, j)) in pairs.iter().enumerate() {
+            if configuration[index] > 0.0 {
+                let ratio = scale * disparities[index] / configuration[index];
+                ratios[i][j] = ratio;
+                ratios[j][i] = ratio;
+            }
+        }
+        coordinates = (0..count)
+            .map(|i| {
+                [0, 1].map(|axis| {
+                    (0..count)
+                        .filter(|&j| j != i)
+                        .map(|j| ratios[i][j] * (coordinates[i][axis] - coordinates[j][axis]))
+                        .sum::<f64>()
+                        / count as f64
+                })
+            })
+            .collect();
+    }
+    Some(Nmds {
+        coordinates,
+        stress,
+    })
+}
+
+/// Euclidean distance between two points
+fn euclidean(a: [f64; 2], b: [f64; 2]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+}
+
+/// Isotonic (monotone non-decreasing) regression
+///
+/// Pool adjacent violators algorithm.
+fn isotonic(values: &[f64]) -> Vec<f64> {
+    // (sum, count)
+    let mut blocks = Vec::<(f64, usize)>::with_capacity(values.len());
+    for &value in values {
+        blocks.push((value, 1));
+        while let [.., (left_sum, left_count), (right_sum, right_count)] = blocks[..]
+            && left_sum / left_count as f64 > right_sum / right_count as f64
+        {
+            blocks.pop();
+            *blocks.last_mut().unwrap() = (left_sum + right_sum, left_count + right_count);
+        }
+    }
+    blocks
+        .into_iter()
+        .flat_map(|(sum, count)| std::iter::repeat_n(sum / count as f64, count))
+        .collect()
+}
+
+/// Eigen decomposition of the symmetric matrix
+///
+/// Cyclic Jacobi rotations. Returns the eigenvalues and the matrix with the
+/// eigenvectors in the columns.
+fn eigen(mut matrix: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let count = matrix.len();
+    let mut vectors = (0..count)
+        .map(|i| (0..count).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect::<Vec<Vec<_>>>();
+    let norm = matrix
+        .iter()
+        .flatten()
+        .map(|value| value * value)
+        .sum::<f64>();
+    for _ in 0..100 {
+        let off = (0..count)
+            .flat_map(|p| (p + 1..count).map(move |q| (p, q)))
+            .map(|(p, q)| matrix[p][q] * matrix[p][q])
+            .sum::<f64>();
+        if off <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..count {
+            for q in p + 1..count {
+                if matrix[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in matrix.iter_mut().chain(vectors.iter_mut()) {
+                    let (a, b) = (row[p], row[q]);
+                    row[p] = c * a - s * b;
+                    row[q] = s * a + c * b;
+                }
+                for k in 0..count {
+                    let (a, b) = (matrix[p][k], matrix[q][k]);
+                    matrix[p][k] = c * a - s * b;
+                    matrix[q][k] = s * a + c * b;
+                }
+            }
+        }
+    }
+    // The largest component of the eigenvector is positive
+    for column in 0..count {
+        let largest = (0..count)
+            .map(|row| vectors[row][column])
+            .max_by(|left, right| left.abs().total_cmp(&right.abs()))
+            .unwrap_or_default();
+        if largest < 0.0 {
+            for row in &mut vectors {
+                row[column] = -row[column];
+            }
+        }
+    }
+    ((0..count).map(|i| matrix[i][i]).collect(), vectors)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    /// Euclidean distance matrix of the points
+    fn euclidean_distances<const N: usize>(points: &[[f64; N]]) -> Vec<Vec<f64>> {
+        points
+            .iter()
+            .map(|a| {
+                points
+                    .iter()
+                    .map(|b| {
+                        a.iter()
+                            .zip(b)
+                            .map(|(a, b)| (a - b).powi(2))
+                            .sum::<f64>()
+                            .sqrt()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Kruskal's stress-1 of the configuration
+    fn kruskal_stress(distances: &[Vec<f64>], coordinates: &[[f64; 2]]) -> f64 {
+        let count = distances.len();
+        let mut pairs = (0..count)
+            .flat_map(|i| (i + 1..count).map(move |j| (i, j)))
+            .collect::<Vec<_>>();
+        pairs.sort_by(|&(a, b), &(c, d)| distances[a][b].total_cmp(&distances[c][d]));
+        let configuration = pairs
+            .iter()
+            .map(|&(i, j)| euclidean(coordinates[i], coordinates[j]))
+            .collect::<Vec<_>>();
+        let disparities = super::isotonic(&configuration);
+        let residuals = configuration
+            .iter()
+            .zip(&disparities)
+            .map(|(distance, disparity)| (distance - disparity).powi(2))
+            .sum::<f64>();
+        let squares = configuration
+            .iter()
+            .map(|distance| distance * distance)
+            .sum::<f64>();
+        (residuals / squares).sqrt()
+    }
+
+    #[test]
+    fn eigen() {
+        let (values, _) = super::eigen(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+        assert!((sorted[0] - 1.0).abs() < EPSILON);
+        assert!((sorted[1] - 3.0).abs() < EPSILON);
+        // A·v = λ·v
+        let matrix = [[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]];
+        let (values, vectors) = super::eigen(matrix.iter().map(|row| row.to_vec()).collect());
+        for column in 0..3 {
+            for row in 0..3 {
+                let product = (0..3)
+                    .map(|k| matrix[row][k] * vectors[k][column])
+                    .sum::<f64>();
+                assert!((product - values[column] * vectors[row][column]).abs() < EPSILON);
+            }
+        }
+        // The trace
+        assert!((values.iter().sum::<f64>() - 9.0).abs() < EPSILON);
+        // Orthonormal
+        for first in 0..3 {
+            for second in 0..3 {
+                let product = (0..3)
+                    .map(|k| vectors[k][first] * vectors[k][second])
+                    .sum::<f64>();
+                let expected = if first == second { 1.0 } else { 0.0 };
+                assert!((product - expected).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn isotonic() {
+        assert_eq!(super::isotonic(&[1.0, 3.0, 2.0, 4.0]), [1.0, 2.5, 2.5, 4.0]);
+        assert_eq!(super::isotonic(&[3.0, 2.0, 1.0]), [2.0, 2.0, 2.0]);
+        let values = [0.5, 4.0, 1.0, 2.0, 7.0, 3.0, 3.0, 9.0];
+        let fitted = super::isotonic(&values);
+        // Monotone
+        assert!(fitted.windows(2).all(|pair| pair[0] <= pair[1]));
+        // The sum is kept
+        assert!((fitted.iter().sum::<f64>() - values.iter().sum::<f64>()).abs() < EPSILON);
+        // The monotone values are kept
+        assert_eq!(super::isotonic(&[1.0, 2.0, 2.0, 3.0]), [1.0, 2.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn pcoa() {
+        let points = [[0.0, 0.0], [3.0, 0.0], [0.0, 4.0], [3.0, 4.0], [1.0, 1.0]];
+        let distances = euclidean_distances(&points);
+        let pcoa = super::pcoa(&distances).unwrap();
+        // The configuration is recovered up to the rotation and the reflection
+        let recovered = euclidean_distances(&pcoa.coordinates);
+        for (expected, recovered) in distances.iter().flatten().zip(recovered.iter().flatten()) {
+            assert!((expected - recovered).abs() < 1e-6);
+        }
+        // The two axes explain the whole planar variance
+        assert!((pcoa.explained[0] + pcoa.explained[1] - 1.0).abs() < 1e-6);
+        assert!(pcoa.explained[0] >= pcoa.explained[1]);
+        // Undefined
+        assert!(super::pcoa(&distances[..2]).is_none());
+        assert!(super::pcoa(&vec![vec![0.0; 3]; 3]).is_none());
+    }
+
+    #[test]
+    fn nmds() {
+        // Vertices of the cube, not planar
+        let points = (0..8)
+            .map(|index| [index & 1, index >> 1 & 1, index >> 2 & 1].map(|bit| bit as f64))
+            .chain([[0.3, 0.6, 0.2], [0.8, 0.1, 0.5]])
+            .collect::<Vec<_>>();
+        let distances = euclidean_distances(&points);
+        let initial = kruskal_stress(&distances, &super::pcoa(&distances).unwrap().coordinates);
+        let nmds = super::nmds(&distances).unwrap();
+        // The stress decreases from the PCoA start
+        assert!(nmds.stress <= initial + EPSILON);
+        assert!((nmds.stress - kruskal_stress(&distances, &nmds.coordinates)).abs() < 1e-6);
+        // The planar configuration has the zero stress
+        let points = [[0.0, 0.0], [3.0, 0.0], [0.0, 4.0], [3.0, 4.0], [1.0, 1.0]];
+        let nmds = super::nmds(&euclidean_distances(&points)).unwrap();
+        assert!(nmds.stress < 1e-6);
+    }
+}
@@ -1,6 +1,17 @@
 use polars::prelude::*;
 use std::fmt::{Display, from_fn};
 
+/// Two-sided 95% critical values of Student's t distribution for 1..=30
+/// degrees of freedom.
+const STUDENT_T_975: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// 0.975 quantile of the standard normal distribution
+const Z_975: f64 = 1.959963984540054;
+
 // TODO: Следить когда добавят возможность складывать массивы поэлементно
 // format_array(
 //     expr
@@ -26,6 +37,62 @@ pub fn eval_arr(expr: Expr, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         .field_by_name("*"))])
 }
 
+/// Number of the non-null array elements
+///
+/// The replicate counts differ between the samples and the excluded
+/// replicates are null.
+pub fn count_arr(expr: Expr) -> Expr {
+    expr.arr()
+        .eval(element().is_not_null().cast(DataType::Float64), false)
+        .arr()
+        .sum()
+}
+
+/// Width of the array data type
+pub fn width(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Array(_, width) => Some(*width),
+        _ => None,
+    }
+}
+
+/// Standard error of the mean of the array elements
+pub fn standard_error(expr: Expr, ddof: u8) -> Expr {
+    let count = count_arr(expr.clone());
+    expr.arr().std(ddof) / count.sqrt()
+}
+
+/// Half-width of the two-sided 95% confidence interval of the mean of the
+/// array elements
+pub fn confidence_interval(expr: Expr, ddof: u8) -> Expr {
+    let count = count_arr(expr.clone());
+    student_t(count - lit(1)) * standard_error(expr, ddof)
+}
+
+/// Two-sided 95% critical value of Student's t distribution
+///
+/// Tabulated for small degrees of freedom, Cornish-Fisher expansion above.
+pub fn student_t(degrees_of_freedom: Expr) -> Expr {
+    let first = (Z_975.powi(3) + Z_975) / 4.0;
+    let second = (5.0 * Z_975.powi(5) + 16.0 * Z_975.powi(3) + 3.0 * Z_975) / 96.0;
+    let expansion = lit(Z_975)
+        + lit(first) / degrees_of_freedom.clone()
+        + lit(second) / degrees_of_freedom.clone().pow(2);
+    let value =
+        STUDENT_T_975
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(expansion, |otherwise, (index, &value)| {
+                ternary_expr(
+                    degrees_of_freedom.clone().eq(lit(index as f64 + 1.0)),
+                    lit(value),
+                    otherwise,
+                )
+            });
+    ternary_expr(degrees_of_freedom.gt_eq(lit(1)), value, lit(NULL))
+}
+
 // /// Extension methods for [`AnyValue`]
 // pub trait AnyValueExt {
 //     fn display(&self) -> String;
@@ -0,0 +1,478 @@
+/// Two-sided p-value of Welch's t-test
+///
+/// Undefined for less than two values in a sample or for zero variance in
+/// both samples.
+pub fn welch_t_test(first: &[f64], second: &[f64]) -> Option<f64> {
+    let (first_mean, first_variance) = mean_and_variance(first)?;
+    let (second_mean, second_variance) = mean_and_variance(second)?;
+    let first_error = first_variance / first.len() as f64;
+    let second_error = second_variance / second.len() as f64;
+    let error = first_error + second_error;
+    if error == 0.0 {
+        return None;
+    }
+    let t = (second_mean - first_mean) / error.sqrt();
+    // Welch-Satterthwaite degrees of freedom
+    let degrees_of_freedom = error.powi(2)
+        / (first_error.powi(2) / (first.len() - 1) as f64
+            + second_error.powi(2) / (second.len() - 1) as f64);
+    Some(student_t_test(t, degrees_of_freedom))
+}
+
+/// Two-sided p-value of the Student's t statistic
+pub fn student_t_test(t: f64, degrees_of_freedom: f64) -> f64 {
+    let x = degrees_of_freedom / (degrees_of_freedom + t * t);
+    incomplete_beta(degrees_of_freedom / 2.0, 0.5, x).clamp(0.0, 1.0)
+}
+
+/// Cumulative distribution function of the standard normal distribution
+///
+/// The complementary error function approximation (Numerical Recipes, 6.2),
+/// the relative error is below 1.2e-7.
+pub fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let erfc = t
+        * (-z * z - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77)))))))))
+            .exp();
+    if x >= 0.0 {
+        1.0 - erfc / 2.0
+    } else {
+        erfc / 2.0
+    }
+}
+
+/// Quantile function of the standard normal distribution
+///
+/// The rational approximation (Acklam, 2003), the relative error is below
+/// 1.2e-9.
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Sample mean and unbiased variance
+fn mean_and_variance(values: &[f64]) -> Option<(f64, f64)> {
+    if values.len() < 2 {
+        return None;
+    }
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (count - 1.0);
+    Some((mean, variance))
+}
+
+/// Regularized incomplete beta function I_x(a, b)
+///
+/// Continued fraction (Numerical Recipes, 6.4).
+pub fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+fn continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const ITERATIONS: usize = 200;
+    const EPSILON: f64 = 1e-14;
+    const TINY: f64 = 1e-300;
+    let clamp = |value: f64| if value.abs() < TINY { TINY } else { value };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..=ITERATIONS {
+        let m = m as f64;
+        // Even step
+        let numerator = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / clamp(1.0 + numerator * d);
+        c = clamp(1.0 + numerator / c);
+        h *= d * c;
+        // Odd step
+        let numerator = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / clamp(1.0 + numerator * d);
+        c = clamp(1.0 + numerator / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Natural logarithm of the gamma function
+///
+/// Lanczos approximation (g = 7, n = 9).
+pub fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (index, coefficient) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + index as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// PERMANOVA result
+#[derive(Clone, Copy, Debug)]
+pub struct Permanova {
+    pub pseudo_f: f64,
+    pub r_squared: f64,
+    pub p_value: f64,
+}
+
+/// Permutational multivariate analysis of variance (Anderson, 2001)
+///
+/// The distances are the square matrix, the groups are the group indices of
+/// the observations. Undefined for less than two groups or without the
+/// within-group replication.
+pub fn permanova(
+    distances: &[Vec<f64>],
+    groups: &[usize],
+    permutations: u32,
+    seed: u64,
+) -> Option<Permanova> {
+    let count = groups.len();
+    let levels = levels(groups)?;
+    if count <= levels {
+        return None;
+    }
+    let squares = distances
+        .iter()
+        .map(|row| row.iter().map(|distance| distance * distance).collect())
+        .collect::<Vec<Vec<_>>>();
+    let total = pairs(count).map(|(i, j)| squares[i][j]).sum::<f64>() / count as f64;
+    if total == 0.0 {
+        return None;
+    }
+    let pseudo_f = |groups: &[usize]| {
+        let mut sizes = vec![0usize; levels];
+        for &group in groups {
+            sizes[group] += 1;
+        }
+        let within = pairs(count)
+            .filter(|&(i, j)| groups[i] == groups[j])
+            .map(|(i, j)| squares[i][j] / sizes[groups[i]] as f64)
+            .sum::<f64>();
+        let among = total - within;
+        let pseudo_f = (among / (levels - 1) as f64) / (within / (count - levels) as f64);
+        (pseudo_f, among / total)
+    };
+    let (statistic, r_squared) = pseudo_f(groups);
+    let p_value = permutation_test(groups, permutations, seed, |groups| {
+        pseudo_f(groups).0 >= statistic
+    });
+    Some(Permanova {
+        pseudo_f: statistic,
+        r_squared,
+        p_value,
+    })
+}
+
+/// ANOSIM result
+#[derive(Clone, Copy, Debug)]
+pub struct Anosim {
+    pub r: f64,
+    pub p_value: f64,
+}
+
+/// Analysis of similarities (Clarke, 1993)
+///
+/// R is the difference of the mean between-group and within-group ranks of
+/// the distances, scaled to [-1, 1].
+pub fn anosim(
+    distances: &[Vec<f64>],
+    groups: &[usize],
+    permutations: u32,
+    seed: u64,
+) -> Option<Anosim> {
+    let count = groups.len();
+    levels(groups)?;
+    let pairs = pairs(count).collect::<Vec<_>>();
+    let ranks = ranks(
+        &pairs
+            .iter()
+            .map(|&(i, j)| distances[i][j])
+            .collect::<Vec<_>>(),
+    );
+    let half = pairs.len() as f64 / 2.0;
+    let r = |groups: &[usize]| {
+        let (mut within, mut within_count, mut between, mut between_count) = (0.0, 0, 0.0, 0);
+        for (&(i, j), rank) in pairs.iter().zip(&ranks) {
+            if groups[i] == groups[j] {
+                within += rank;
+                within_count += 1;
+            } else {
+                between += rank;
+                between_count += 1;
+            }
+        }
+        if within_count == 0 || between_count == 0 {
+            return None;
+        }
+        Some((between / between_count as f64 - within / within_count as f64) / half)
+    };
+    let statistic = r(groups)?;
+    let p_value = permutation_test(groups, permutations, seed, |groups| {
+        r(groups).is_some_and(|r| r >= statistic)
+    });
+    Some(Anosim {
+        r: statistic,
+        p_value,
+    })
+}
+
+/// Number of the group levels, at least two
+fn levels(groups: &[usize]) -> Option<usize> {
+    let levels = groups.iter().max()? + 1;
+    (levels > 1).then_some(levels)
+}
+
+/// Index pairs of the upper triangle
+fn pairs(count: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..count).flat_map(move |i| (i + 1..count).map(move |j| (i, j)))
+}
+
+/// Ranks, the average rank for the ties
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_by(|&left, &right| values[left].total_cmp(&values[right]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < indices.len() {
+        let mut end = start + 1;
+        while end < indices.len() && values[indices[end]] == values[indices[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &index in &indices[start..end] {
+            ranks[index] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Permutation p-value: the share of the group label permutations at least
+/// as extreme as the observed ones
+fn permutation_test(
+    groups: &[usize],
+    permutations: u32,
+    seed: u64,
+    extreme: impl Fn(&[usize]) -> bool,
+) -> f64 {
+    let mut random = SplitMix64(seed);
+    let mut permuted = groups.to_vec();
+    let mut count = 0;
+    for _ in 0..permutations {
+        random.shuffle(&mut permuted);
+        if extreme(&permuted) {
+            count += 1;
+        }
+    }
+    (count + 1) as f64 / (permutations + 1) as f64
+}
+
+/// SplitMix64 pseudorandom number generator
+///
+/// Small and seedable, the same seed gives the same permutations.
+pub struct SplitMix64(pub u64);
+
+impl SplitMix64 {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for index in (1..values.len()).rev() {
+            let other = (self.next_u64() % (index as u64 + 1)) as usize;
+            values.swap(index, other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EPSILON: f64 = 1e-6;
+
+    /// Euclidean distances of the points 0, 1, 5, 6 in two groups
+    fn distances() -> Vec<Vec<f64>> {
+        let points = [0.0f64, 1.0, 5.0, 6.0];
+        points
+            .iter()
+            .map(|first| points.iter().map(|second| (first - second).abs()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn permanova() {
+        // vegan::adonis2(dist(c(0, 1, 5, 6)) ~ factor(c(1, 1, 2, 2))): F = 50,
+        // R² = 25 / 26
+        let permanova = super::permanova(&distances(), &[0, 0, 1, 1], 9999, 0).unwrap();
+        assert!((permanova.pseudo_f - 50.0).abs() < EPSILON);
+        assert!((permanova.r_squared - 25.0 / 26.0).abs() < EPSILON);
+        // Two of the six labelings are the observed partition
+        assert!((permanova.p_value - 1.0 / 3.0).abs() < 0.02);
+        // Without the replication
+        assert!(super::permanova(&distances(), &[0, 1, 2, 3], 99, 0).is_none());
+        assert!(super::permanova(&distances(), &[0, 0, 0, 0], 99, 0).is_none());
+    }
+
+    #[test]
+    fn anosim() {
+        // vegan::anosim(dist(c(0, 1, 5, 6)), c(1, 1, 2, 2)): R = 1
+        let anosim = super::anosim(&distances(), &[0, 0, 1, 1], 9999, 0).unwrap();
+        assert!((anosim.r - 1.0).abs() < EPSILON);
+        assert!((anosim.p_value - 1.0 / 3.0).abs() < 0.02);
+        // The groups are mixed: the within-group distances are 6 and 4
+        let anosim = super::anosim(&distances(), &[0, 1, 1, 0], 99, 0).unwrap();
+        assert!((anosim.r - (-0.5)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn incomplete_beta() {
+        assert_eq!(super::incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(super::incomplete_beta(2.0, 3.0, 1.0), 1.0);
+        // I_x(1, 1) = x
+        assert!((super::incomplete_beta(1.0, 1.0, 0.3) - 0.3).abs() < EPSILON);
+        // Symmetric
+        assert!((super::incomplete_beta(4.5, 4.5, 0.5) - 0.5).abs() < EPSILON);
+        // I_0.3(2, 3) = 6·0.3²·0.7² + 4·0.3³·0.7 + 0.3⁴
+        assert!((super::incomplete_beta(2.0, 3.0, 0.3) - 0.3483).abs() < EPSILON);
+        assert!((super::incomplete_beta(3.0, 2.0, 0.7) - 0.6517).abs() < EPSILON);
+    }
+
+    #[test]
+    fn ln_gamma() {
+        assert!(super::ln_gamma(1.0).abs() < EPSILON);
+        assert!(super::ln_gamma(2.0).abs() < EPSILON);
+        assert!((super::ln_gamma(5.0) - 24f64.ln()).abs() < EPSILON);
+        assert!((super::ln_gamma(10.0) - 362_880f64.ln()).abs() < EPSILON);
+        // Γ(1/2) = √π
+        assert!((super::ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < EPSILON);
+        // Γ(1/4) = 3.625609908221908
+        assert!((super::ln_gamma(0.25) - 3.625_609_908_221_908f64.ln()).abs() < EPSILON);
+    }
+
+    #[test]
+    fn student_t_test() {
+        // The two-sided 0.05 quantiles of the t distribution: qt(0.975, df)
+        assert!((super::student_t_test(12.706_204_736_174_7, 1.0) - 0.05).abs() < EPSILON);
+        assert!((super::student_t_test(2.228_138_851_986_27, 10.0) - 0.05).abs() < EPSILON);
+        assert!((super::student_t_test(2.042_272_456_301_24, 30.0) - 0.05).abs() < EPSILON);
+        // qt(0.995, 5)
+        assert!((super::student_t_test(4.032_142_983_557_54, 5.0) - 0.01).abs() < EPSILON);
+        assert!((super::student_t_test(0.0, 5.0) - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn normal_quantile() {
+        assert!(super::normal_quantile(0.5).abs() < EPSILON);
+        assert!((super::normal_quantile(0.975) - 1.959_963_984_540_05).abs() < EPSILON);
+        assert!((super::normal_quantile(0.01) + 2.326_347_874_040_84).abs() < EPSILON);
+        assert!((super::normal_quantile(0.999) - 3.090_232_306_167_81).abs() < EPSILON);
+        assert_eq!(super::normal_quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(super::normal_quantile(1.0), f64::INFINITY);
+        // The inverse of the cumulative distribution function
+        for p in [0.001, 0.02, 0.1, 0.3, 0.7, 0.9, 0.98] {
+            assert!((normal_cdf(super::normal_quantile(p)) - p).abs() < 1e-6);
+        }
+    }
+}